@@ -9,20 +9,18 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
 
+use crate::derive_model::{get_column_name, get_column_override, has_orm_key};
 use crate::types::rust_type_to_sql;
 
 /// Extracts the inner type `T` from `Option<T>`.
 fn get_inner_type(ty: &Type) -> Option<&Type> {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Option" {
-                if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.args.first() {
-                        return Some(inner_ty);
-                    }
-                }
-            }
-        }
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+    {
+        return Some(inner_ty);
     }
     None
 }
@@ -45,20 +43,11 @@ pub fn expand(input: DeriveInput) -> TokenStream {
     let ext_logic = fields.iter().map(|f| {
         let field_name = &f.ident;
         let field_type = &f.ty;
-        let column_name = field_name.as_ref().unwrap().to_string().to_snake_case();
+        let column_name = get_column_override(f).unwrap_or_else(|| field_name.as_ref().unwrap().to_string().to_snake_case());
         let alias_name = format!("{}__{}", table_name, column_name);
         
-        let mut is_enum = false;
-        for attr in &f.attrs {
-            if attr.path().is_ident("orm") {
-                let _ = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("enum") {
-                        is_enum = true;
-                    }
-                    Ok(())
-                });
-            }
-        }
+        let is_enum = has_orm_key(f, &["enum", "pg_enum"]);
+        let is_int_enum = has_orm_key(f, &["int_enum"]);
 
         let getter = quote! {
             {
@@ -74,7 +63,30 @@ pub fn expand(input: DeriveInput) -> TokenStream {
             }
         };
 
-        if is_enum {
+        if is_int_enum {
+            let (_, is_nullable) = rust_type_to_sql(field_type);
+            let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+            let parse = quote! { <#inner_type as TryFrom<i32>>::try_from(v).map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))? };
+            let value = if is_nullable {
+                quote! { if row.try_get_raw(index)?.is_null() { None } else { let v = row.try_get::<i32, _>(index)?; Some(#parse) } }
+            } else {
+                quote! { { let v = row.try_get::<i32, _>(index)?; #parse } }
+            };
+            quote! {
+                let #field_name: #field_type = {
+                    use sqlx::{Row, Column, ValueRef};
+                    let col = row.try_column(#alias_name)
+                        .or_else(|_| row.try_column(#column_name))
+                        .or_else(|_| {
+                            row.columns().iter()
+                                .find(|c| c.name().to_lowercase().ends_with(&format!("__{}", #column_name)))
+                                .ok_or_else(|| sqlx::Error::ColumnNotFound(#column_name.to_string()))
+                        })?;
+                    let index = col.ordinal();
+                    #value
+                };
+            }
+        } else if is_enum {
             let (_, is_nullable) = rust_type_to_sql(field_type);
             if is_nullable {
                 let inner_type = get_inner_type(field_type).unwrap_or(field_type);
@@ -105,7 +117,7 @@ pub fn expand(input: DeriveInput) -> TokenStream {
                                     .map(|c| row.try_get::<String, _>(c.name()))
                                     .unwrap_or(Err(sqlx::Error::ColumnNotFound(#column_name.to_string())))
                             })?;
-                        s.parse().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to parse enum: {}", e)))))?
+                        s.parse().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?
                     };
                 }
             }
@@ -126,7 +138,7 @@ pub fn expand(input: DeriveInput) -> TokenStream {
                             }).map_err(|e| sqlx::Error::ColumnDecode { index: #column_name.to_string(), source: Box::new(e) })?;
                         
                         match s {
-                            Some(v) => Some(v.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(e)))?),
+                            Some(v) => Some(v.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(e.to_string()))))?),
                             None => None,
                         }
                     };
@@ -147,6 +159,37 @@ pub fn expand(input: DeriveInput) -> TokenStream {
                     };
                 }
             }
+        } else if decodes_from_text(field_type) {
+            quote! {
+                let #field_name: #field_type = {
+                    use sqlx::{Row, Column};
+                    let col = row.try_column(#alias_name)
+                        .or_else(|_| row.try_column(#column_name))
+                        .or_else(|_| {
+                            row.columns().iter()
+                                .find(|c| c.name().to_lowercase().ends_with(&format!("__{}", #column_name)))
+                                .ok_or_else(|| sqlx::Error::ColumnNotFound(#column_name.to_string()))
+                        })?;
+                    let mut index = col.ordinal();
+                    bottle_orm::any_struct::FromAnyRow::from_any_row_at(row, &mut index)?
+                };
+            }
+        } else if let Some(inner_type) = get_inner_type(field_type) {
+            // Check for NULL before decoding so every driver yields `None` the same way
+            quote! {
+                let #field_name: #field_type = {
+                    use sqlx::{Row, Column, ValueRef};
+                    let col = row.try_column(#alias_name)
+                        .or_else(|_| row.try_column(#column_name))
+                        .or_else(|_| {
+                            row.columns().iter()
+                                .find(|c| c.name().to_lowercase().ends_with(&format!("__{}", #column_name)))
+                                .ok_or_else(|| sqlx::Error::ColumnNotFound(#column_name.to_string()))
+                        })?;
+                    let index = col.ordinal();
+                    if row.try_get_raw(index)?.is_null() { None } else { Some(row.try_get::<#inner_type, _>(index)?) }
+                };
+            }
         } else {
             quote! {
                 let #field_name: #field_type = #getter?;
@@ -159,17 +202,26 @@ pub fn expand(input: DeriveInput) -> TokenStream {
         let field_name = &f.ident;
         let field_type = &f.ty;
 
-        let mut is_enum = false;
-        for attr in &f.attrs {
-            if attr.path().is_ident("orm") {
-                let _ = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("enum") { is_enum = true; }
-                    Ok(())
-                });
-            }
-        }
+        let is_enum = has_orm_key(f, &["enum", "pg_enum"]);
 
-        if is_enum || is_datetime(field_type) || is_uuid(field_type) {
+        if has_orm_key(f, &["int_enum"]) {
+            let (_, is_nullable) = rust_type_to_sql(field_type);
+            let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+            let parse = quote! { <#inner_type as TryFrom<i32>>::try_from(v).map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))? };
+            let value = if is_nullable {
+                quote! { match row.try_get::<Option<i32>, _>(*index)? { Some(v) => Some(#parse), None => None } }
+            } else {
+                quote! { { let v = row.try_get::<i32, _>(*index)?; #parse } }
+            };
+            quote! {
+                let #field_name: #field_type = {
+                    use sqlx::Row;
+                    let val = #value;
+                    *index += 1;
+                    val
+                };
+            }
+        } else if is_enum || is_datetime(field_type) || is_uuid(field_type) {
             let (_, is_nullable) = rust_type_to_sql(field_type);
             if is_nullable {
                 let inner_type = get_inner_type(field_type).unwrap_or(field_type);
@@ -179,7 +231,7 @@ pub fn expand(input: DeriveInput) -> TokenStream {
                         let s: Option<String> = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
                         *index += 1;
                         match s {
-                            Some(v) => Some(v.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(e)))?),
+                            Some(v) => Some(v.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(e.to_string()))))?),
                             None => None,
                         }
                     };
@@ -190,10 +242,23 @@ pub fn expand(input: DeriveInput) -> TokenStream {
                         use sqlx::Row;
                         let s: String = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
                         *index += 1;
-                        s.parse::<#field_type>().map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+                        s.parse::<#field_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(e.to_string()))))?
                     };
                 }
             }
+        } else if decodes_from_text(field_type) {
+            quote! {
+                let #field_name: #field_type = bottle_orm::any_struct::FromAnyRow::from_any_row_at(row, index)?;
+            }
+        } else if let Some(inner_type) = get_inner_type(field_type) {
+            quote! {
+                let #field_name: #field_type = {
+                    use sqlx::{Row, ValueRef};
+                    let val = if row.try_get_raw(*index)?.is_null() { None } else { Some(row.try_get::<#inner_type, _>(*index)?) };
+                    *index += 1;
+                    val
+                };
+            }
         } else {
             quote! {
                 let #field_name: #field_type = { use sqlx::Row; let val = row.try_get(*index)?; *index += 1; val };
@@ -202,14 +267,22 @@ pub fn expand(input: DeriveInput) -> TokenStream {
     });
 
     let col_query = fields.iter().map(|f| {
-        let field_name = &f.ident;
         let field_type = &f.ty;
-        let (sql_type, _) = rust_type_to_sql(field_type);
+        let column_name = get_column_name(f);
+        let (mut sql_type, is_nullable) = rust_type_to_sql(field_type);
+        if has_orm_key(f, &["int_enum"]) { sql_type = "INTEGER".to_string(); }
+        let sql_type = if has_orm_key(f, &["pg_enum"]) {
+            let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+            quote! { <#inner_type as bottle_orm::PgEnum>::SQL_TYPE }
+        } else {
+            quote! { #sql_type }
+        };
         quote! {
             bottle_orm::AnyInfo {
-                column: stringify!(#field_name),
+                column: #column_name,
                 sql_type: #sql_type,
-                table: #table_name
+                table: #table_name,
+                is_nullable: #is_nullable
             }
         }
     });
@@ -222,11 +295,18 @@ pub fn expand(input: DeriveInput) -> TokenStream {
     let map_inserts = fields.iter().map(|f| {
         let field_name = &f.ident;
         let field_type = &f.ty;
+        let column_name = get_column_name(f);
         let (_, is_nullable) = rust_type_to_sql(field_type);
-        if is_nullable {
-            quote! { map.insert(stringify!(#field_name).to_string(), self.#field_name.as_ref().map(|v| v.to_string())); }
+        if has_orm_key(f, &["int_enum"]) {
+            if is_nullable {
+                quote! { map.insert(#column_name.to_string(), self.#field_name.as_ref().map(|v| i32::from(v).to_string())); }
+            } else {
+                quote! { map.insert(#column_name.to_string(), Some(i32::from(&self.#field_name).to_string())); }
+            }
+        } else if is_nullable {
+            quote! { map.insert(#column_name.to_string(), self.#field_name.as_ref().map(|v| v.to_string())); }
         } else {
-            quote! { map.insert(stringify!(#field_name).to_string(), Some(self.#field_name.to_string())); }
+            quote! { map.insert(#column_name.to_string(), Some(self.#field_name.to_string())); }
         }
     });
 
@@ -261,29 +341,37 @@ pub fn expand(input: DeriveInput) -> TokenStream {
 }
 
 fn is_datetime(ty: &Type) -> bool {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "DateTime" { return true; }
-            if segment.ident == "Option" {
-                if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.args.first() { return is_datetime(inner_ty); }
-                }
-            }
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        if segment.ident == "DateTime" { return true; }
+        if segment.ident == "Option"
+            && let PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+        {
+            return is_datetime(inner_ty);
         }
     }
     false
 }
 
 fn is_uuid(ty: &Type) -> bool {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Uuid" { return true; }
-            if segment.ident == "Option" {
-                if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.args.first() { return is_uuid(inner_ty); }
-                }
-            }
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+    {
+        if segment.ident == "Uuid" { return true; }
+        if segment.ident == "Option"
+            && let PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+        {
+            return is_uuid(inner_ty);
         }
     }
     false
 }
+
+/// Types whose `FromAnyRow` impl parses the column's text (decimals, range and geometric types).
+fn decodes_from_text(ty: &Type) -> bool {
+    let (sql_type, _) = rust_type_to_sql(ty);
+    matches!(sql_type.as_str(), "NUMERIC" | "TSTZRANGE" | "POINT")
+}
@@ -2,12 +2,76 @@
 //!
 //! This module implements the procedural macro expansion for `#[derive(BottleEnum)]`.
 //! It automatically generates `Display` and `FromStr` implementations for enums,
-//! using the variant names as the string representation.
+//! using the variant names as the string representation. It also implements
+//! `#[derive(BottleIntEnum)]`, which maps variants to their integer discriminants.
+//!
+//! Variants are snake_cased by default. `#[bottle(rename_all = "...")]` on the enum
+//! picks another casing and `#[bottle(rename = "...")]` on a variant sets its string
+//! explicitly. `#[bottle(sqlx_type)]` additionally implements `sqlx::Type`, `Encode`
+//! and `Decode` for `sqlx::Any` on top of those strings. `#[bottle(pg_enum)]` implements
+//! `PgEnum`, exposing the type name and variant strings used to create a native
+//! PostgreSQL `ENUM` type.
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput};
-use heck::ToSnakeCase;
+use syn::{Attribute, Data, DeriveInput, Fields};
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+
+/// Extracts the value of `key = "..."` from the `#[bottle(...)]` attributes.
+fn get_bottle_value(attrs: &[Attribute], key: &str) -> Option<String> {
+    let mut value = None;
+    for attr in attrs {
+        if attr.path().is_ident("bottle") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(key) && meta.input.peek(syn::Token![=]) {
+                    let lit: syn::LitStr = meta.value()?.parse()?;
+                    value = Some(lit.value());
+                } else if meta.input.peek(syn::Token![=]) {
+                    let _: syn::Expr = meta.value()?.parse()?;
+                }
+                Ok(())
+            })
+            .unwrap_or_else(|e| panic!("Invalid #[bottle(...)] attribute: {}", e));
+        }
+    }
+    value
+}
+
+/// Returns whether a bare `flag` appears in the `#[bottle(...)]` attributes.
+fn has_bottle_flag(attrs: &[Attribute], flag: &str) -> bool {
+    let mut found = false;
+    for attr in attrs {
+        if attr.path().is_ident("bottle") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(flag) {
+                    found = true;
+                }
+                if meta.input.peek(syn::Token![=]) {
+                    let _: syn::Expr = meta.value()?.parse()?;
+                }
+                Ok(())
+            });
+        }
+    }
+    found
+}
+
+/// Converts a variant name to the casing named by `rename_all`.
+fn apply_casing(name: &str, casing: &str) -> String {
+    match casing {
+        "snake_case" => name.to_snake_case(),
+        "SCREAMING_SNAKE_CASE" => name.to_shouty_snake_case(),
+        "kebab-case" => name.to_kebab_case(),
+        "camelCase" => name.to_lower_camel_case(),
+        "PascalCase" => name.to_upper_camel_case(),
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        other => panic!(
+            "Unsupported rename_all casing \"{}\"; expected one of snake_case, SCREAMING_SNAKE_CASE, kebab-case, camelCase, PascalCase, lowercase, UPPERCASE",
+            other
+        ),
+    }
+}
 
 /// Expands the `#[derive(BottleEnum)]` macro.
 pub fn expand(ast: DeriveInput) -> TokenStream {
@@ -19,26 +83,99 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         _ => panic!("BottleEnum can only be derived for enums"),
     };
 
+    let casing = get_bottle_value(&ast.attrs, "rename_all").unwrap_or_else(|| "snake_case".to_string());
+
+    // Resolve each variant's string once so Display and FromStr use the same value
+    let names: Vec<(&syn::Ident, String)> = variants
+        .iter()
+        .map(|variant| {
+            let value = get_bottle_value(&variant.attrs, "rename")
+                .unwrap_or_else(|| apply_casing(&variant.ident.to_string(), &casing));
+            (&variant.ident, value)
+        })
+        .collect();
+
+    for (i, (ident, value)) in names.iter().enumerate() {
+        if let Some((other, _)) = names[..i].iter().find(|(_, v)| v == value) {
+            panic!("BottleEnum variants `{}` and `{}` both map to \"{}\"", other, ident, value);
+        }
+    }
+
     // Generate Display arms: Self::Variant => "variant"
-    let display_arms = variants.iter().map(|variant| {
-        let variant_ident = &variant.ident;
-        let variant_name_snake = variant_ident.to_string().to_snake_case();
+    let display_arms = names.iter().map(|(variant_ident, value)| {
         quote! {
-            Self::#variant_ident => write!(f, #variant_name_snake),
+            Self::#variant_ident => f.write_str(#value),
         }
     });
 
     // Generate FromStr arms: "variant" => Ok(Self::Variant)
-    let from_str_arms = variants.iter().map(|variant| {
-        let variant_ident = &variant.ident;
-        let variant_name_snake = variant_ident.to_string().to_snake_case();
+    let from_str_arms = names.iter().map(|(variant_ident, value)| {
         quote! {
-            #variant_name_snake => Ok(Self::#variant_ident),
+            #value => Ok(Self::#variant_ident),
         }
     });
 
+    // Bind and decode through the same strings as Display/FromStr
+    let sqlx_impls = if has_bottle_flag(&ast.attrs, "sqlx_type") {
+        quote! {
+            impl sqlx::Type<sqlx::Any> for #name {
+                fn type_info() -> sqlx::any::AnyTypeInfo {
+                    <String as sqlx::Type<sqlx::Any>>::type_info()
+                }
+
+                fn compatible(ty: &sqlx::any::AnyTypeInfo) -> bool {
+                    <String as sqlx::Type<sqlx::Any>>::compatible(ty)
+                }
+            }
+
+            impl<'q> sqlx::Encode<'q, sqlx::Any> for #name {
+                fn encode_by_ref(
+                    &self,
+                    buf: &mut <sqlx::Any as sqlx::Database>::ArgumentBuffer<'q>,
+                ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                    <String as sqlx::Encode<'q, sqlx::Any>>::encode(self.to_string(), buf)
+                }
+            }
+
+            impl<'r> sqlx::Decode<'r, sqlx::Any> for #name {
+                fn decode(value: sqlx::any::AnyValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
+                    let s = <String as sqlx::Decode<'r, sqlx::Any>>::decode(value)?;
+                    Ok(s.parse::<Self>()?)
+                }
+            }
+
+            impl bottle_orm::IntoBindValue for #name {
+                type Value = Self;
+
+                fn into_bind_value(self) -> Self {
+                    self
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Expose the type name and variant strings for native Postgres enums
+    let pg_enum_impl = if has_bottle_flag(&ast.attrs, "pg_enum") {
+        let type_name = get_bottle_value(&ast.attrs, "pg_enum").unwrap_or_else(|| name.to_string().to_snake_case());
+        let sql_type = format!("\"{}\"", type_name);
+        let values = names.iter().map(|(_, value)| value);
+        quote! {
+            impl bottle_orm::PgEnum for #name {
+                const SQL_TYPE: &'static str = #sql_type;
+                const VARIANTS: &'static [&'static str] = &[#(#values),*];
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Output the generated implementations
     quote! {
+        #sqlx_impls
+        #pg_enum_impl
+
         impl std::fmt::Display for #name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
@@ -59,3 +196,58 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         }
     }
 }
+
+/// Expands the `#[derive(BottleIntEnum)]` macro.
+pub fn expand_int(ast: DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+
+    let variants = match &ast.data {
+        Data::Enum(data_enum) => &data_enum.variants,
+        _ => panic!("BottleIntEnum can only be derived for enums"),
+    };
+    if variants.iter().any(|variant| !matches!(variant.fields, Fields::Unit)) {
+        panic!("BottleIntEnum variants cannot have fields");
+    }
+
+    // Discriminants are read with `as i32`, so explicit values like `Admin = 10` are honored
+    let to_int_arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        quote! {
+            #name::#variant_ident => #name::#variant_ident as i32,
+        }
+    });
+
+    let from_int_arms = variants.iter().map(|variant| {
+        let variant_ident = &variant.ident;
+        quote! {
+            v if v == #name::#variant_ident as i32 => Ok(#name::#variant_ident),
+        }
+    });
+
+    quote! {
+        impl From<&#name> for i32 {
+            fn from(value: &#name) -> i32 {
+                match value {
+                    #(#to_int_arms)*
+                }
+            }
+        }
+
+        impl From<#name> for i32 {
+            fn from(value: #name) -> i32 {
+                i32::from(&value)
+            }
+        }
+
+        impl TryFrom<i32> for #name {
+            type Error = String;
+
+            fn try_from(value: i32) -> Result<Self, Self::Error> {
+                match value {
+                    #(#from_int_arms)*
+                    _ => Err(format!("Unknown discriminant: {}", value)),
+                }
+            }
+        }
+    }
+}
@@ -25,18 +25,140 @@ use crate::types::rust_type_to_sql;
 
 /// Extracts the inner type `T` from `Option<T>`.
 fn get_inner_type(ty: &Type) -> Option<&Type> {
-    if let Type::Path(type_path) = ty {
-        if let Some(segment) = type_path.path.segments.last() {
-            if segment.ident == "Option" {
-                if let PathArguments::AngleBracketed(args) = &segment.arguments {
-                    if let Some(GenericArgument::Type(inner_ty)) = args.args.first() {
-                        return Some(inner_ty);
-                    }
+    if let Type::Path(type_path) = ty
+        && let Some(segment) = type_path.path.segments.last()
+        && segment.ident == "Option"
+        && let PathArguments::AngleBracketed(args) = &segment.arguments
+        && let Some(GenericArgument::Type(inner_ty)) = args.args.first()
+    {
+        return Some(inner_ty);
+    }
+    None
+}
+
+/// `#[orm(...)]` keys that mark a field as a relation rather than a column.
+const RELATION_KEYS: &[&str] = &["has_many", "has_one", "belongs_to"];
+
+/// Returns the database column name for a field.
+///
+/// Uses `#[orm(column = "...")]` verbatim when present, otherwise the field name.
+pub(crate) fn get_column_name(field: &syn::Field) -> String {
+    get_column_override(field).unwrap_or_else(|| field.ident.as_ref().unwrap().to_string())
+}
+
+/// Returns whether any `#[orm(...)]` key on the field matches one of `names`.
+///
+/// Values of every other key are consumed, so the flag is found regardless of the
+/// order it is combined with keys like `column = "..."` or `default = "..."`.
+pub(crate) fn has_orm_key(field: &syn::Field, names: &[&str]) -> bool {
+    let mut found = false;
+    for attr in &field.attrs {
+        if attr.path().is_ident("orm") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if names.iter().any(|name| meta.path.is_ident(name)) {
+                    found = true;
                 }
-            }
+                if meta.input.peek(syn::Token![=]) {
+                    let _: syn::Expr = meta.value()?.parse()?;
+                }
+                Ok(())
+            });
         }
     }
-    None
+    found
+}
+
+/// Extracts the value of `#[orm(column = "...")]`, if any.
+pub(crate) fn get_column_override(field: &syn::Field) -> Option<String> {
+    let mut column = None;
+    for attr in &field.attrs {
+        if attr.path().is_ident("orm") {
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("column") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    column = Some(value.value());
+                } else if meta.input.peek(syn::Token![=]) {
+                    let _: syn::Expr = meta.value()?.parse()?;
+                }
+                Ok(())
+            });
+        }
+    }
+    column
+}
+
+/// Decodes one field at `*index` for `from_any_row_at`, advancing the index.
+fn positional_decode(f: &syn::Field) -> TokenStream {
+    let field_name = &f.ident;
+    let field_type = &f.ty;
+    let (sql_type, is_nullable) = rust_type_to_sql(field_type);
+    let is_enum = has_orm_key(f, &["enum", "pg_enum"]);
+    if has_orm_key(f, &["int_enum"]) {
+        let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+        let parse = quote! { <#inner_type as TryFrom<i32>>::try_from(v).map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))? };
+        if is_nullable {
+            quote! {
+                let #field_name: #field_type = {
+                    let v: Option<i32> = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
+                    *index += 1;
+                    match v { Some(v) => Some(#parse), None => None }
+                };
+            }
+        } else {
+            quote! {
+                let #field_name: #field_type = {
+                    let v: i32 = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
+                    *index += 1; #parse
+                };
+            }
+        }
+    } else if is_enum {
+        if is_nullable {
+            if let Some(inner_type) = get_inner_type(field_type) {
+                quote! {
+                    let #field_name: #field_type = {
+                        let s: Option<String> = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
+                        *index += 1;
+                        match s { Some(s_val) => Some(s_val.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?), None => None, }
+                    };
+                }
+            } else { quote! { let #field_name: #field_type = row.try_get(*index)?; *index += 1; } }
+        } else {
+            quote! {
+                let #field_name: #field_type = {
+                    let s: String = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
+                    *index += 1; s.parse().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?
+                };
+            }
+        }
+    } else if sql_type == "TIMESTAMPTZ" || sql_type == "TIMESTAMP" || sql_type == "DATE" || sql_type == "TIME" || sql_type == "UUID" {
+        if is_nullable {
+            if let Some(inner_type) = get_inner_type(field_type) {
+                quote! {
+                    let #field_name: #field_type = {
+                        let s: Option<String> = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
+                        *index += 1;
+                        match s { Some(s_val) => Some(s_val.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(e)))?), None => None, }
+                    };
+                }
+            } else { quote! { let #field_name: #field_type = row.try_get(*index)?; *index += 1; } }
+        } else {
+            quote! {
+                let #field_name: #field_type = {
+                    let s: String = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
+                    *index += 1; s.parse().map_err(|e| sqlx::Error::Decode(Box::new(e)))?
+                };
+            }
+        }
+    } else { quote! { let #field_name: #field_type = bottle_orm::any_struct::FromAnyRow::from_any_row_at(row, index)?; } }
+}
+
+/// Value of an unselected `#[orm(omit)]` field: its `Default`, or a missing-column error.
+fn omitted_fallback(field_type: &Type, column_name: &str) -> TokenStream {
+    quote! {{
+        use bottle_orm::any_struct::{OmittedDefault as _, OmittedMissing as _};
+        (&bottle_orm::any_struct::OmittedColumn::<#field_type>(#column_name, std::marker::PhantomData)).omitted_value()?
+    }}
 }
 
 // ============================================================================
@@ -58,6 +180,8 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
     };
 
     let mut table_name_str = struct_name.to_string().to_snake_case();
+    let mut composite_indexes = Vec::new();
+    let mut index_errors = Vec::new();
     for attr in &ast.attrs {
         if attr.path().is_ident("orm") {
             let _ = attr.parse_nested_meta(|meta| {
@@ -65,19 +189,59 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                     let value: syn::LitStr = meta.value()?.parse()?;
                     table_name_str = value.value();
                 }
+                if meta.path.is_ident("index") {
+                    let mut columns = Vec::new();
+                    let mut unique = false;
+                    meta.parse_nested_meta(|inner| {
+                        if inner.path.is_ident("columns") {
+                            let value: syn::LitStr = inner.value()?.parse()?;
+                            // Entries name a field or its column; both map to the column name
+                            for entry in value.value().split(',').map(str::trim).filter(|c| !c.is_empty()) {
+                                let column = fields.named.iter().find_map(|f| {
+                                    let field = f.ident.as_ref()?.to_string();
+                                    let column = get_column_name(f).trim_start_matches("r#").to_snake_case();
+                                    (field.trim_start_matches("r#") == entry || column == entry).then_some(column)
+                                });
+                                match column {
+                                    Some(column) => columns.push(column),
+                                    None => index_errors.push(
+                                        syn::Error::new(
+                                            value.span(),
+                                            format!("#[orm(index(...))] on `{}` names unknown field `{}`", struct_name, entry),
+                                        )
+                                        .to_compile_error(),
+                                    ),
+                                }
+                            }
+                        }
+                        if inner.path.is_ident("unique") { unique = true; }
+                        Ok(())
+                    })?;
+                    if columns.is_empty() && index_errors.is_empty() {
+                        panic!("#[orm(index(...))] on `{}` needs a non-empty `columns = \"...\"` list", struct_name);
+                    }
+                    composite_indexes.push(quote! {
+                        bottle_orm::IndexInfo { columns: vec![#(#columns),*], unique: #unique }
+                    });
+                }
                 Ok(())
             });
         }
     }
+    if !index_errors.is_empty() {
+        return quote! { #(#index_errors)* };
+    }
 
     let mut relations = Vec::new();
 
     let column_defs_iter = fields.named.iter().filter_map(|f| {
         let field_name = &f.ident;
         let field_type = &f.ty;
+        let column_name = get_column_name(f);
         let (mut sql_type, is_nullable) = rust_type_to_sql(field_type);
 
         let mut is_primary_key = false;
+        let mut auto_increment = false;
         let mut size = None;
         let mut create_time = false;
         let mut update_time = false;
@@ -86,8 +250,13 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         let mut omit = false;
         let mut soft_delete = false;
         let mut is_enum = false;
+        let mut is_int_enum = false;
+        let mut is_pg_enum = false;
+        let mut default_tokens = quote! { None };
         let mut foreign_table_tokens = quote! { None };
         let mut foreign_key_tokens = quote! { None };
+        let mut on_delete_tokens = quote! { None };
+        let mut on_update_tokens = quote! { None };
 
         let mut rel_type = None;
         let mut rel_target = None;
@@ -98,6 +267,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
             if attr.path().is_ident("orm") {
                 let _ = attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("primary_key") { is_primary_key = true; }
+                    if meta.path.is_ident("auto_increment") { auto_increment = true; }
                     if meta.path.is_ident("size") {
                         let value: syn::LitInt = meta.value()?.parse()?;
                         size = Some(value.base10_parse::<usize>()?);
@@ -114,11 +284,28 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                             if parts.len() == 2 {
                                 let table = parts[0];
                                 let col = parts[1];
-                                foreign_table_tokens = quote! { Some(#table) };
+                                // A model type resolves to its table name, honouring `#[orm(table = ...)]`;
+                                // anything else is taken as the table name itself
+                                foreign_table_tokens = match syn::parse_str::<syn::Ident>(table) {
+                                    Ok(ident) if table.starts_with(|c: char| c.is_ascii_uppercase()) => {
+                                        quote! { Some(<#ident as bottle_orm::Model>::table_name()) }
+                                    }
+                                    _ => quote! { Some(#table) },
+                                };
                                 foreign_key_tokens = quote! { Some(#col) };
                             }
                         } else { rel_fk = Some(fk_string); }
                     }
+                    if meta.path.is_ident("on_delete") {
+                        let value: syn::LitStr = meta.value()?.parse()?;
+                        let action = value.value();
+                        on_delete_tokens = quote! { Some(#action) };
+                    }
+                    if meta.path.is_ident("on_update") {
+                        let value: syn::LitStr = meta.value()?.parse()?;
+                        let action = value.value();
+                        on_update_tokens = quote! { Some(#action) };
+                    }
                     if meta.path.is_ident("local_key") {
                         let value: syn::LitStr = meta.value()?.parse()?;
                         rel_lk = Some(value.value());
@@ -141,6 +328,16 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                     if meta.path.is_ident("omit") { omit = true; }
                     if meta.path.is_ident("soft_delete") { soft_delete = true; }
                     if meta.path.is_ident("enum") { is_enum = true; }
+                    if meta.path.is_ident("int_enum") { is_int_enum = true; }
+                    if meta.path.is_ident("pg_enum") { is_pg_enum = true; }
+                    if meta.path.is_ident("column") { let _: syn::LitStr = meta.value()?.parse()?; }
+                    if meta.path.is_ident("default") {
+                        let value: syn::LitStr = meta.value()?.parse()?;
+                        let default_expr = value.value();
+                        default_tokens = quote! { Some(#default_expr) };
+                    }
+                    // Consume the value of any key not handled above so later keys are still seen
+                    if meta.input.peek(syn::Token![=]) { let _: syn::Expr = meta.value()?.parse()?; }
                     Ok(())
                 });
             }
@@ -163,13 +360,25 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
             return None;
         }
 
-        if let Some(s) = size { if sql_type == "TEXT" { sql_type = format!("VARCHAR({})", s); } }
+        if let Some(s) = size && sql_type == "TEXT" { sql_type = format!("VARCHAR({})", s); }
         if is_enum && (sql_type == "TEXT" || sql_type == "VARCHAR(255)") { sql_type = "TEXT".to_string(); }
+        if is_int_enum { sql_type = "INTEGER".to_string(); }
+
+        // Native PostgreSQL enums take their type name and labels from the field's enum
+        let (sql_type_tokens, pg_enum_tokens) = if is_pg_enum {
+            let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+            (
+                quote! { <#inner_type as bottle_orm::PgEnum>::SQL_TYPE },
+                quote! { Some(<#inner_type as bottle_orm::PgEnum>::VARIANTS) },
+            )
+        } else {
+            (quote! { #sql_type }, quote! { None })
+        };
 
         Some(quote! {
             bottle_orm::ColumnInfo {
-                 name: stringify!(#field_name),
-                 sql_type: #sql_type,
+                 name: #column_name,
+                 sql_type: #sql_type_tokens,
                  is_primary_key: #is_primary_key,
                  is_nullable: #is_nullable,
                  create_time: #create_time,
@@ -178,8 +387,13 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                  index: #index,
                  foreign_table: #foreign_table_tokens,
                  foreign_key: #foreign_key_tokens,
+                 on_delete: #on_delete_tokens,
+                 on_update: #on_update_tokens,
                  omit: #omit,
                  soft_delete: #soft_delete,
+                 default: #default_tokens,
+                 auto_increment: #auto_increment,
+                 pg_enum: #pg_enum_tokens,
             }
         })
     });
@@ -213,6 +427,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                     if meta.path.is_ident("local_key") {
                         let value: syn::LitStr = meta.value()?.parse()?; rel_lk = Some(value.value());
                     }
+                    if meta.input.peek(syn::Token![=]) { let _: syn::Expr = meta.value()?.parse()?; }
                     Ok(())
                 });
             }
@@ -220,7 +435,6 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
 
         if let Some(rtype) = rel_type {
             let target_ident = format_ident!("{}", rel_target.unwrap());
-            let target_table_name = target_ident.to_string().to_snake_case();
             let fk = rel_fk.unwrap_or_else(|| "id".to_string());
             let lk = rel_lk.unwrap_or_else(|| "id".to_string());
             let lk_ident = format_ident!("{}", lk);
@@ -395,89 +609,96 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         None
     }).collect::<Vec<_>>();
 
-    let field_names_iter: Vec<_> = fields.named.iter().filter(|f| {
-        !f.attrs.iter().any(|attr| {
-            if attr.path().is_ident("orm") {
-                let mut is_rel = false;
-                let _ = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("has_many") || meta.path.is_ident("has_one") || meta.path.is_ident("belongs_to") { is_rel = true; }
-                    Ok(())
-                });
-                is_rel
-            } else { false }
-        })
-    }).map(|f| &f.ident).collect();
+    let field_names_iter: Vec<_> = fields.named.iter()
+        .filter(|f| !has_orm_key(f, RELATION_KEYS))
+        .map(get_column_name)
+        .collect();
 
-    let map_inserts = fields.named.iter().filter_map(|f| {
+    let map_values: Vec<_> = fields.named.iter().filter_map(|f| {
         let field_name = &f.ident;
         let field_type = &f.ty;
-        if f.attrs.iter().any(|attr| {
-            if attr.path().is_ident("orm") {
-                let mut is_rel = false;
-                let _ = attr.parse_nested_meta(|meta| { if meta.path.is_ident("has_many") || meta.path.is_ident("has_one") || meta.path.is_ident("belongs_to") { is_rel = true; } Ok(()) });
-                is_rel
-            } else { false }
-        }) { return None; }
+        let column_name = get_column_name(f);
+        if has_orm_key(f, RELATION_KEYS) { return None; }
         let (sql_type, is_nullable) = rust_type_to_sql(field_type);
         let is_complex = sql_type.ends_with("[]") || sql_type == "JSONB" || sql_type == "JSON";
-        if is_nullable {
-            return Some(quote! {
-                map.insert(stringify!(#field_name).to_string(), self.#field_name.as_ref().map(|v| { if #is_complex { serde_json::to_string(v).unwrap_or_else(|_| "".to_string()) } else { v.to_string() } }));
-            });
-        }
-        if is_complex {
-            Some(quote! { map.insert(stringify!(#field_name).to_string(), Some(serde_json::to_string(&self.#field_name).unwrap_or_else(|_| "".to_string()))); })
+        let value = if has_orm_key(f, &["int_enum"]) {
+            if is_nullable {
+                quote! { self.#field_name.as_ref().map(|v| i32::from(v).to_string()) }
+            } else {
+                quote! { Some(i32::from(&self.#field_name).to_string()) }
+            }
+        } else if is_nullable && is_complex {
+            quote! { self.#field_name.as_ref().map(|v| serde_json::to_string(v).unwrap_or_else(|_| "".to_string())) }
+        } else if is_nullable {
+            quote! { self.#field_name.as_ref().map(|v| v.to_string()) }
+        } else if is_complex {
+            quote! { Some(serde_json::to_string(&self.#field_name).unwrap_or_else(|_| "".to_string())) }
         } else {
-            Some(quote! { map.insert(stringify!(#field_name).to_string(), Some(self.#field_name.to_string())); })
-        }
+            quote! { Some(self.#field_name.to_string()) }
+        };
+        Some((column_name, value))
+    }).collect();
+    let map_inserts = map_values.iter().map(|(column_name, value)| quote! { map.insert(#column_name.to_string(), #value); });
+    let changed_inserts = fields.named.iter().filter(|f| !has_orm_key(f, RELATION_KEYS)).zip(&map_values).map(|(f, (column_name, value))| {
+        let field_name = f.ident.as_ref().unwrap().to_string();
+        let field_name = field_name.strip_prefix("r#").unwrap_or(&field_name).to_string();
+        quote! { if changed.iter().any(|c| *c == #field_name || *c == #column_name) { map.insert(#column_name.to_string(), #value); } }
     });
+    let pair_pushes = map_values.iter().map(|(column_name, value)| quote! { pairs.push((#column_name.to_string(), #value)); });
+    let pair_count = map_values.len();
 
     let any_column_defs = fields.named.iter().filter_map(|f| {
-        let field_name = &f.ident;
         let field_type = &f.ty;
-        if f.attrs.iter().any(|attr| {
-            if attr.path().is_ident("orm") {
-                let mut is_rel = false;
-                let _ = attr.parse_nested_meta(|meta| { if meta.path.is_ident("has_many") || meta.path.is_ident("has_one") || meta.path.is_ident("belongs_to") { is_rel = true; } Ok(()) });
-                is_rel
-            } else { false }
-        }) { return None; }
-        let (sql_type, _) = rust_type_to_sql(field_type);
+        let column_name = get_column_name(f);
+        if has_orm_key(f, RELATION_KEYS) { return None; }
+        let (mut sql_type, is_nullable) = rust_type_to_sql(field_type);
+        if has_orm_key(f, &["int_enum"]) { sql_type = "INTEGER".to_string(); }
+        let sql_type = if has_orm_key(f, &["pg_enum"]) {
+            let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+            quote! { <#inner_type as bottle_orm::PgEnum>::SQL_TYPE }
+        } else {
+            quote! { #sql_type }
+        };
         let table_name_const = table_name_str.clone();
         Some(quote! {
-            bottle_orm::AnyInfo { column: stringify!(#field_name), sql_type: #sql_type, table: #table_name_const, }
+            bottle_orm::AnyInfo { column: #column_name, sql_type: #sql_type, table: #table_name_const, is_nullable: #is_nullable, }
         })
     });
 
     let from_row_logic = fields.named.iter().map(|f| {
         let field_name = &f.ident;
         let field_type = &f.ty;
-        let mut is_rel = false; let mut rel_type = None;
-        for attr in &f.attrs {
-            if attr.path().is_ident("orm") {
-                let _ = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("has_many") { is_rel = true; rel_type = Some("HasMany"); }
-                    else if meta.path.is_ident("has_one") { is_rel = true; rel_type = Some("HasOne"); }
-                    else if meta.path.is_ident("belongs_to") { is_rel = true; rel_type = Some("BelongsTo"); }
-                    Ok(())
-                });
-            }
-        }
-        if is_rel {
-            if rel_type == Some("HasMany") { return quote! { let #field_name: #field_type = Vec::new(); }; }
+        if has_orm_key(f, RELATION_KEYS) {
+            if has_orm_key(f, &["has_many"]) { return quote! { let #field_name: #field_type = Vec::new(); }; }
             else { return quote! { let #field_name: #field_type = None; }; }
         }
-        let column_name = field_name.as_ref().unwrap().to_string();
+        let column_name = get_column_name(f);
         let alias_name = format!("{}__{}", table_name_str, column_name);
         let (sql_type, is_nullable) = rust_type_to_sql(field_type);
-        let mut is_enum = false;
-        for attr in &f.attrs { if attr.path().is_ident("orm") { let _ = attr.parse_nested_meta(|meta| { if meta.path.is_ident("enum") { is_enum = true; } Ok(()) }); } }
-        if is_enum {
+        let is_enum = has_orm_key(f, &["enum", "pg_enum"]);
+        let decode = if has_orm_key(f, &["int_enum"]) {
+            if is_nullable {
+                let inner_type = get_inner_type(field_type).unwrap_or(field_type);
+                quote! {
+                    let #field_name: #field_type = match row.try_get::<Option<i32>, _>(#alias_name).or_else(|_| row.try_get::<Option<i32>, _>(#column_name))? {
+                        Some(v) => Some(<#inner_type as TryFrom<i32>>::try_from(v).map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?),
+                        None => None,
+                    };
+                }
+            } else {
+                quote! {
+                    let #field_name: #field_type = {
+                        let v: i32 = row.try_get(#alias_name).or_else(|_| row.try_get(#column_name))?;
+                        <#field_type as TryFrom<i32>>::try_from(v).map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?
+                    };
+                }
+            }
+        } else if is_enum {
             if is_nullable {
                 if let Some(inner_type) = get_inner_type(field_type) {
                     quote! {
                         let #field_name: #field_type = match row.try_get::<Option<String>, _>(#alias_name).or_else(|_| row.try_get::<Option<String>, _>(#column_name))? {
-                            Some(s) => Some(s.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to parse enum: {}", e)))))?),
+                            Some(s) => Some(s.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?),
                             None => None,
                         };
                     }
@@ -486,29 +707,11 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                 quote! {
                     let #field_name: #field_type = {
                         let s: String = row.try_get(#alias_name).or_else(|_| row.try_get(#column_name))?;
-                        s.parse().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to parse enum: {}", e)))))?
+                        s.parse().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::other(format!("Failed to parse enum: {}", e)))))?
                     };
                 }
             }
-        } else if sql_type == "TIMESTAMPTZ" || sql_type == "TIMESTAMP" || sql_type == "DATE" || sql_type == "TIME" {
-             if is_nullable {
-                 if let Some(inner_type) = get_inner_type(field_type) {
-                     quote! {
-                        let #field_name: #field_type = match row.try_get::<Option<String>, _>(#alias_name).or_else(|_| row.try_get::<Option<String>, _>(#column_name))? {
-                            Some(s) => Some(s.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(e)))?),
-                            None => None,
-                        };
-                     }
-                 } else { quote! { let #field_name: #field_type = row.try_get(#alias_name).or_else(|_| row.try_get(#column_name))?; } }
-             } else {
-                 quote! {
-                    let #field_name: #field_type = {
-                        let s: String = row.try_get(#alias_name).or_else(|_| row.try_get(#column_name))?;
-                        s.parse().map_err(|e| sqlx::Error::Decode(Box::new(e)))?
-                    };
-                 }
-             }
-        } else if sql_type == "UUID" {
+        } else if sql_type == "TIMESTAMPTZ" || sql_type == "TIMESTAMP" || sql_type == "DATE" || sql_type == "TIME" || sql_type == "UUID" {
              if is_nullable {
                  if let Some(inner_type) = get_inner_type(field_type) {
                      quote! {
@@ -526,7 +729,7 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                     };
                  }
              }
-        } else if sql_type.ends_with("[]") || sql_type == "JSONB" || sql_type == "JSON" {
+        } else if sql_type.ends_with("[]") || matches!(sql_type.as_str(), "JSONB" | "JSON" | "NUMERIC" | "TSTZRANGE" | "POINT") {
             quote! {
                 let #field_name: #field_type = {
                     let mut index = 0;
@@ -536,80 +739,63 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                     }
                 };
             }
-        } else { quote! { let #field_name: #field_type = row.try_get(#alias_name).or_else(|_| row.try_get(#column_name))?; } }
+        } else { quote! { let #field_name: #field_type = row.try_get(#alias_name).or_else(|_| row.try_get(#column_name))?; } };
+
+        // Omitted-by-default columns are absent unless selected; fall back to the type's default
+        if has_orm_key(f, &["omit"]) {
+            let fallback = omitted_fallback(field_type, &column_name);
+            quote! {
+                let #field_name: #field_type = if row.try_column(#alias_name).is_err() && row.try_column(#column_name).is_err() {
+                    #fallback
+                } else {
+                    #decode
+                    #field_name
+                };
+            }
+        } else {
+            decode
+        }
     });
 
     let from_row_logic_clone = from_row_logic.clone();
     let from_row_logic_positional = fields.named.iter().map(|f| {
         let field_name = &f.ident; let field_type = &f.ty;
-        let mut is_rel = false; let mut rel_type = None;
-        for attr in &f.attrs {
-            if attr.path().is_ident("orm") {
-                let _ = attr.parse_nested_meta(|meta| {
-                    if meta.path.is_ident("has_many") { is_rel = true; rel_type = Some("HasMany"); }
-                    else if meta.path.is_ident("has_one") { is_rel = true; rel_type = Some("HasOne"); }
-                    else if meta.path.is_ident("belongs_to") { is_rel = true; rel_type = Some("BelongsTo"); }
-                    Ok(())
-                });
-            }
-        }
-        if is_rel {
-            if rel_type == Some("HasMany") { return quote! { let #field_name: #field_type = Vec::new(); }; }
+        if has_orm_key(f, RELATION_KEYS) {
+            if has_orm_key(f, &["has_many"]) { return quote! { let #field_name: #field_type = Vec::new(); }; }
             else { return quote! { let #field_name: #field_type = None; }; }
         }
-        let (sql_type, is_nullable) = rust_type_to_sql(field_type);
-        let mut is_enum = false;
-        for attr in &f.attrs { if attr.path().is_ident("orm") { let _ = attr.parse_nested_meta(|meta| { if meta.path.is_ident("enum") { is_enum = true; } Ok(()) }); } }
-        if is_enum {
-            if is_nullable {
-                if let Some(inner_type) = get_inner_type(field_type) {
-                    quote! {
-                        let #field_name: #field_type = {
-                            let s: Option<String> = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
-                            *index += 1;
-                            match s { Some(s_val) => Some(s_val.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to parse enum: {}", e)))))?), None => None, }
-                        };
-                    }
-                } else { quote! { let #field_name: #field_type = row.try_get(*index)?; *index += 1; } }
-            } else {
-                quote! {
-                    let #field_name: #field_type = {
-                        let s: String = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
-                        *index += 1; s.parse().map_err(|e| sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to parse enum: {}", e)))))?
-                    };
-                }
-            }
-        } else if sql_type == "TIMESTAMPTZ" || sql_type == "TIMESTAMP" || sql_type == "DATE" || sql_type == "TIME" || sql_type == "UUID" {
-            if is_nullable {
-                if let Some(inner_type) = get_inner_type(field_type) {
-                    quote! {
-                        let #field_name: #field_type = {
-                            let s: Option<String> = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
-                            *index += 1;
-                            match s { Some(s_val) => Some(s_val.parse::<#inner_type>().map_err(|e| sqlx::Error::Decode(Box::new(e)))?), None => None, }
-                        };
-                    }
-                } else { quote! { let #field_name: #field_type = row.try_get(*index)?; *index += 1; } }
-            } else {
-                quote! {
-                    let #field_name: #field_type = {
-                        let s: String = row.try_get(*index).map_err(|e| sqlx::Error::ColumnDecode { index: index.to_string(), source: Box::new(e) })?;
-                        *index += 1; s.parse().map_err(|e| sqlx::Error::Decode(Box::new(e)))?
-                    };
-                }
+        let decode = positional_decode(f);
+
+        // An unselected omitted column has no slot, so the index is left where it is
+        if has_orm_key(f, &["omit"]) {
+            let column_name = get_column_name(f);
+            let alias_name = format!("{}__{}", table_name_str, column_name);
+            let fallback = omitted_fallback(field_type, &column_name);
+            quote! {
+                let #field_name: #field_type = if row.columns().get(*index).is_some_and(|c| {
+                    let name = sqlx::Column::name(c);
+                    name.eq_ignore_ascii_case(#alias_name) || name.eq_ignore_ascii_case(#column_name)
+                }) {
+                    #decode
+                    #field_name
+                } else {
+                    #fallback
+                };
             }
-        } else { quote! { let #field_name: #field_type = bottle_orm::any_struct::FromAnyRow::from_any_row_at(row, index)?; } }
+        } else {
+            decode
+        }
     });
 
     let field_names_construct = fields.named.iter().map(|f| &f.ident);
     let field_names_construct_clone = field_names_construct.clone();
     let field_names_construct_positional = field_names_construct.clone();
     let module_name = format_ident!("{}_fields", struct_name.to_string().to_snake_case());
-    let field_constants = fields.named.iter().filter_map(|f| {
+    let field_constants = fields.named.iter().map(|f| {
         let field_name = &f.ident;
         let const_name = format_ident!("{}", field_name.as_ref().unwrap().to_string().to_uppercase());
-        let name_str = field_name.as_ref().unwrap().to_string();
-        Some(quote! { pub const #const_name: &'static str = #name_str; })
+        let name_str = get_column_name(f);
+        quote! { pub const #const_name: &'static str = #name_str; }
     });
 
     quote! {
@@ -617,9 +803,10 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
         impl bottle_orm::Model for #struct_name {
             fn table_name() -> &'static str { #table_name_str }
             fn columns() -> Vec<bottle_orm::ColumnInfo> { vec![#(#column_defs),*] }
-            fn column_names() -> Vec<String> { vec![#(stringify!(#field_names_iter).to_string() ),*] }
-            fn active_columns() -> Vec<&'static str> { vec![#(stringify!(#field_names_iter) ),*] }
+            fn column_names() -> Vec<String> { vec![#(#field_names_iter.to_string() ),*] }
+            fn active_columns() -> Vec<&'static str> { vec![#(#field_names_iter ),*] }
             fn relations() -> Vec<bottle_orm::RelationInfo> { vec![#(#relations),*] }
+            fn indexes() -> Vec<bottle_orm::IndexInfo> { vec![#(#composite_indexes),*] }
             fn load_relations<'a>(
                 relation_name: &'a str, models: &'a mut [Self], tx: &'a dyn bottle_orm::database::Connection,
                 query_modifier: Option<std::sync::Arc<dyn std::any::Any + Send + Sync>>,
@@ -640,6 +827,8 @@ pub fn expand(ast: DeriveInput) -> TokenStream {
                 })
             }
             fn to_map(&self) -> std::collections::HashMap<String, Option<String>> { let mut map = std::collections::HashMap::new(); #(#map_inserts)* map }
+            fn to_ordered_pairs(&self) -> Vec<(String, Option<String>)> { let mut pairs = Vec::with_capacity(#pair_count); #(#pair_pushes)* pairs }
+            fn update_map(&self, changed: &[&str]) -> std::collections::HashMap<String, Option<String>> { let mut map = std::collections::HashMap::new(); #(#changed_inserts)* map }
         }
         impl bottle_orm::AnyImpl for #struct_name { fn columns() -> Vec<bottle_orm::AnyInfo> { vec![#(#any_column_defs),*] } fn to_map(&self) -> std::collections::HashMap<String, Option<String>> { bottle_orm::Model::to_map(self) } }
         impl<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> for #struct_name { fn from_row(row: &'r sqlx::any::AnyRow) -> Result<Self, sqlx::Error> { use sqlx::Row; #(#from_row_logic)* Ok(#struct_name { #(#field_names_construct),* }) } }
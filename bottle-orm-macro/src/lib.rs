@@ -51,6 +51,15 @@
 //! ```
 //! Marks the field as the table's primary key. Generates `PRIMARY KEY` constraint.
 //!
+//! ### Auto Increment
+//! ```rust,ignore
+//! #[orm(primary_key, auto_increment)]
+//! id: i32,
+//! ```
+//! Lets the database generate an integer primary key (`SERIAL`/`BIGSERIAL` on PostgreSQL,
+//! `AUTO_INCREMENT` on MySQL, `AUTOINCREMENT` on SQLite). Inserts skip the field while it
+//! is `0`; use `insert_get_id` to read the generated value back.
+//!
 //! ### Unique Constraint
 //! ```rust,ignore
 //! #[orm(unique)]
@@ -77,14 +86,14 @@
 //! #[orm(create_time)]
 //! created_at: DateTime<Utc>,
 //! ```
-//! Adds `DEFAULT CURRENT_TIMESTAMP` to auto-populate on INSERT.
+//! Filled with the current time on INSERT when the value is unset.
 //!
 //! ### Auto-Timestamp (Update)
 //! ```rust,ignore
 //! #[orm(update_time)]
 //! updated_at: DateTime<Utc>,
 //! ```
-//! Auto-updates timestamp on UPDATE (future feature).
+//! Set to the current time on every UPDATE unless assigned explicitly.
 //!
 //! ### Foreign Key
 //! ```rust,ignore
@@ -93,13 +102,51 @@
 //! ```
 //! Creates a foreign key relationship. Format: `"TargetTable::target_column"`.
 //!
+//! ```rust,ignore
+//! #[orm(foreign_key = "User::id", on_delete = "cascade", on_update = "restrict")]
+//! user_id: i32,
+//! ```
+//! `on_delete` / `on_update` add referential actions to the constraint. Accepted values:
+//! `cascade`, `restrict`, `set null`, `no action`.
+//!
 //! ### Omit Field
 //! ```rust,ignore
 //! #[orm(omit)]
 //! password: String,
 //! ```
-//! Excludes this field from query results by default. Returns a placeholder value
-//! instead of the actual data (`"omited"` for strings, `1970-01-01T00:00:00Z` for dates, etc.).
+//! Excludes this field from query results by default. When the column is not
+//! selected, the field is filled with `Default::default()` (an empty string, zero,
+//! `1970-01-01T00:00:00Z` for dates, etc.), so its type must implement `Default`.
+//! Full-model `updates()` leave it untouched. To read the column, name it in
+//! `select(...)` (or use `select("*")`), or scan into a DTO that declares the field.
+//!
+//! ### Custom Table Name
+//! ```rust,ignore
+//! #[derive(Model)]
+//! #[orm(table = "tbl_app_users")]
+//! struct User { /* ... */ }
+//! ```
+//! Struct-level attribute that overrides the table name. The value is used verbatim
+//! (no snake_case conversion) everywhere the table is referenced.
+//!
+//! ### Column Name
+//! ```rust,ignore
+//! #[orm(column = "created_ts")]
+//! created_at: DateTime<Utc>,
+//! ```
+//! Maps the field onto an existing column with a different name. The override is used
+//! for `ColumnInfo.name`, `to_map()` keys, result aliases, and the generated field constants.
+//!
+//! ### Default Value
+//! ```rust,ignore
+//! #[orm(default = "pending")]
+//! status: String,
+//! #[orm(default = "now()")]
+//! created_at: DateTime<Utc>,
+//! ```
+//! Emits `DEFAULT <expr>` in `CREATE TABLE` and when `sync_table` adds the column.
+//! Numbers, booleans, `NULL`, `CURRENT_TIMESTAMP` and function calls are used as-is;
+//! any other value is quoted as a string literal.
 //!
 //! ### Combining Attributes
 //! ```rust,ignore
@@ -255,13 +302,20 @@ mod derive_anyrow;
 /// The macro recognizes the following `#[orm(...)]` attributes on struct fields:
 ///
 /// * `primary_key` - Marks the field as a primary key
+/// * `auto_increment` - Lets the database generate an integer primary key
 /// * `unique` - Adds a UNIQUE constraint
 /// * `index` - Creates a database index
-/// * `create_time` - Sets default value to CURRENT_TIMESTAMP
-/// * `update_time` - Auto-updates timestamp (future feature)
+/// * `create_time` - Fills the column with the current time on insert when unset
+/// * `update_time` - Sets the column to the current time on every update
 /// * `size = N` - Sets column size (VARCHAR(N))
 /// * `foreign_key = "Table::Column"` - Defines a Foreign Key relationship
-/// * `omit` - Excludes field from queries (returns placeholder value)
+/// * `on_delete = "action"` / `on_update = "action"` - Referential actions for the foreign key
+/// * `omit` - Excludes field from default selects (decoded as `Default::default()`, or a
+///   `ColumnNotFound` error for types without `Default`)
+/// * `column = "name"` - Maps the field onto a differently named column
+/// * `default = "expr"` - Adds a `DEFAULT` clause to the column definition
+///
+/// On the struct itself, `#[orm(table = "name")]` overrides the table name.
 ///
 /// # Type Mapping
 ///
@@ -281,11 +335,16 @@ mod derive_anyrow;
 ///
 /// The macro generates an implementation of the `Model` trait with four methods:
 ///
-/// 1. `table_name()` - Returns the struct name as a static string
+/// 1. `table_name()` - Returns the snake_case struct name, or the `#[orm(table = "...")]` value verbatim
 /// 2. `columns()` - Returns column metadata as `Vec<ColumnInfo>`
 /// 3. `active_columns()` - Returns column names as `Vec<&'static str>`
 /// 4. `to_map()` - Serializes the instance to `HashMap<String, String>`
 ///
+/// It also implements the row mapping traits that [`FromAnyRow`](derive@FromAnyRow)
+/// provides: `FromAnyRow`, `AnyImpl` and `sqlx::FromRow` for `AnyRow`. A model can
+/// therefore be used with `scan`, `first` or `RawQuery::fetch_all` as-is. Do not add
+/// `#[derive(FromAnyRow)]` next to `#[derive(Model)]`: the impls would conflict.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -340,25 +399,77 @@ pub fn model_derive(input: TokenStream) -> TokenStream {
 ///
 /// This macro generates implementations that allow the enum to be easily saved
 /// as a string (via `to_string()`) and loaded back from a string (via `parse()`).
-/// It uses the variant names as the string representation.
+/// It uses the snake_cased variant names as the string representation.
+///
+/// # Attributes
+///
+/// - `#[bottle(rename_all = "...")]` on the enum: casing for every variant. One of
+///   `snake_case` (default), `SCREAMING_SNAKE_CASE`, `kebab-case`, `camelCase`,
+///   `PascalCase`, `lowercase` or `UPPERCASE`.
+/// - `#[bottle(rename = "...")]` on a variant: exact string for that variant,
+///   overriding `rename_all`.
+/// - `#[bottle(sqlx_type)]` on the enum: also implements `sqlx::Type`, `Encode` and
+///   `Decode` for `sqlx::Any`, so values can be bound directly (for example in
+///   `filter` or `RawQuery::bind`) and decoded with `row.try_get`.
+/// - `#[bottle(pg_enum)]` or `#[bottle(pg_enum = "type_name")]` on the enum: implements
+///   `PgEnum` so a model field marked `#[orm(pg_enum)]` is stored in a native
+///   PostgreSQL `ENUM` type (named after the snake_cased enum by default). SQLite and
+///   MySQL keep using `TEXT`.
 ///
 /// # Example
 ///
 /// ```rust,ignore
 /// #[derive(BottleEnum, Debug, Clone, PartialEq)]
+/// #[bottle(rename_all = "SCREAMING_SNAKE_CASE")]
 /// enum UserStatus {
-///     Active,
-///     Inactive,
-///     Pending,
+///     Active,   // "ACTIVE"
+///     Inactive, // "INACTIVE"
+///     #[bottle(rename = "waiting")]
+///     Pending,  // "waiting"
 /// }
 /// ```
-#[proc_macro_derive(BottleEnum)]
+#[proc_macro_derive(BottleEnum, attributes(bottle))]
 pub fn enum_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let expanded = derive_enum::expand(ast);
     TokenStream::from(expanded)
 }
 
+/// Derives integer conversions for an enum stored as an `INTEGER` column.
+///
+/// This macro generates `From<Enum> for i32`, `From<&Enum> for i32` and
+/// `TryFrom<i32> for Enum`, using each variant's discriminant (explicit values
+/// such as `Admin = 10` are honored). Mark the model field with `#[orm(int_enum)]`
+/// so the column is created as `INTEGER` and decoded from an `i32`. Integer
+/// storage keeps the column compact and lets queries compare variants by order.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(BottleIntEnum, Debug, Clone, Copy, PartialEq)]
+/// enum Role {
+///     Admin,     // 0
+///     Moderator, // 1
+///     Member,    // 2
+/// }
+///
+/// #[derive(Model)]
+/// struct Account {
+///     #[orm(primary_key)]
+///     id: i32,
+///     #[orm(int_enum)]
+///     role: Role,
+/// }
+///
+/// let staff = db.model::<Account>().filter("role", Op::Lt, i32::from(Role::Member)).scan().await?;
+/// ```
+#[proc_macro_derive(BottleIntEnum)]
+pub fn int_enum_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let expanded = derive_enum::expand_int(ast);
+    TokenStream::from(expanded)
+}
+
 /// Derives the `FromRow` trait for `AnyRow` and the `AnyImpl` trait.
 ///
 /// This procedural macro generates an implementation of `sqlx::FromRow<'r, sqlx::any::AnyRow>`
@@ -368,12 +479,16 @@ pub fn enum_derive(input: TokenStream) -> TokenStream {
 /// It also implements the `AnyImpl` trait, which provides necessary column metadata used
 /// by the `QueryBuilder` for dynamic query construction.
 ///
+/// Use it for DTOs and projections only. `#[derive(Model)]` already generates these
+/// impls, so models must not derive `FromAnyRow` as well.
+///
 /// # Features
 ///
 /// - **Automatic Field Mapping**: Maps database columns to struct fields by name.
 /// - **DateTime Handling**: Includes special logic to handle `DateTime` types, often required
 ///   when dealing with the `Any` driver's type erasure or JSON serialization fallback.
 /// - **Metadata Generation**: Automatically generates `AnyInfo` for each field.
+/// - **Column Renames**: `#[orm(column = "...")]` reads the field from a differently named column.
 ///
 /// # Requirements
 ///
@@ -394,7 +509,7 @@ pub fn enum_derive(input: TokenStream) -> TokenStream {
 /// // Usage with QueryBuilder:
 /// // let stats: UserCount = db.model::<User>().select("count(*), last_active").first().await?;
 /// ```
-#[proc_macro_derive(FromAnyRow)]
+#[proc_macro_derive(FromAnyRow, attributes(orm))]
 pub fn any_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let expanded = derive_anyrow::expand(ast);
@@ -21,6 +21,13 @@
 //! ### UUID Types
 //! - `Uuid` → `UUID` (supports all versions 1-7)
 //!
+//! ### Decimal Types (requires the `decimal` feature)
+//! - `Decimal` → `NUMERIC`
+//!
+//! ### PostgreSQL Types (requires the `postgres-types` feature)
+//! - `TsRange` → `TSTZRANGE`
+//! - `Point` → `POINT`
+//!
 //! ### Nullable Types
 //! - `Option<T>` → SQL type of `T` with `NULL` allowed
 //!
@@ -178,6 +185,24 @@ pub fn rust_type_to_sql(ty: &Type) -> (String, bool) {
                 // f32 → REAL (4-byte floating-point)
                 "f32" => ("REAL".to_string(), false),
 
+                // ------------------------------------------------------------
+                // Exact Decimal Types (rust_decimal)
+                // ------------------------------------------------------------
+                // Decimal → NUMERIC (arbitrary precision, no rounding)
+                // Only mapped when the `decimal` feature is enabled; otherwise
+                // it falls through to TEXT like any other custom type.
+                #[cfg(feature = "decimal")]
+                "Decimal" => ("NUMERIC".to_string(), false),
+
+                // ------------------------------------------------------------
+                // PostgreSQL Range and Geometric Types (bottle_orm::pg_types)
+                // ------------------------------------------------------------
+                // Only mapped when the `postgres-types` feature is enabled.
+                #[cfg(feature = "postgres-types")]
+                "TsRange" => ("TSTZRANGE".to_string(), false),
+                #[cfg(feature = "postgres-types")]
+                "Point" => ("POINT".to_string(), false),
+
                 // ------------------------------------------------------------
                 // JSON Types
                 // ------------------------------------------------------------
@@ -21,6 +21,9 @@ pub struct AnyInfo {
 
     /// The name of the table this column belongs to (empty for un-associated columns).
     pub table: &'static str,
+
+    /// Whether the field is an `Option<_>` and decodes a NULL as `None`.
+    pub is_nullable: bool,
 }
 
 /// A generic placeholder struct that implements AnyImpl.
@@ -55,6 +58,42 @@ impl crate::model::Model for AnyImplStruct {
     fn to_map(&self) -> HashMap<String, Option<String>> { HashMap::new() }
 }
 
+// ============================================================================
+// Omitted Column Fallback
+// ============================================================================
+
+/// The value of an `#[orm(omit)]` field whose column was not selected.
+///
+/// Used by the `Model` derive: types implementing `Default` get their default
+/// through [`OmittedDefault`], any other type reports the missing column through
+/// [`OmittedMissing`], so the derive does not require `Default` on omitted fields.
+#[doc(hidden)]
+pub struct OmittedColumn<T>(pub &'static str, pub std::marker::PhantomData<T>);
+
+/// Preferred by method resolution when the field type implements `Default`.
+#[doc(hidden)]
+pub trait OmittedDefault<T> {
+    fn omitted_value(&self) -> Result<T, Error>;
+}
+
+impl<T: Default> OmittedDefault<T> for OmittedColumn<T> {
+    fn omitted_value(&self) -> Result<T, Error> {
+        Ok(T::default())
+    }
+}
+
+/// Fallback for field types without `Default`.
+#[doc(hidden)]
+pub trait OmittedMissing<T> {
+    fn omitted_value(&self) -> Result<T, Error>;
+}
+
+impl<T> OmittedMissing<T> for &OmittedColumn<T> {
+    fn omitted_value(&self) -> Result<T, Error> {
+        Err(Error::ColumnNotFound(self.0.to_string()))
+    }
+}
+
 /// A trait for types that can be mapped from an `AnyRow` and provide column metadata.
 ///
 /// This trait is the backbone of the ORM's reflection capabilities. It allows the
@@ -382,6 +421,53 @@ impl FromAnyRow for chrono::DateTime<chrono::Utc> {
     }
 }
 
+#[cfg(feature = "decimal")]
+impl AnyImpl for rust_decimal::Decimal {
+    fn columns() -> Vec<AnyInfo> {
+        Vec::new()
+    }
+    fn to_map(&self) -> HashMap<String, Option<String>> {
+        HashMap::new()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl FromAnyRow for rust_decimal::Decimal {
+    fn from_any_row(row: &AnyRow) -> Result<Self, Error> {
+        let mut index = 0;
+        Self::from_any_row_at(row, &mut index)
+    }
+
+    fn from_any_row_at(row: &AnyRow, index: &mut usize) -> Result<Self, Error> {
+        if *index >= row.len() {
+            return Err(Error::ColumnIndexOutOfBounds { index: *index, len: row.len() });
+        }
+        // NUMERIC values arrive as text on most drivers, but SQLite stores them
+        // with numeric affinity and hands back an INTEGER or REAL instead.
+        let text = match row.try_get::<String, _>(*index) {
+            Ok(s) => s,
+            Err(e) => {
+                if let Ok(i) = row.try_get::<i64, _>(*index) {
+                    i.to_string()
+                } else if let Ok(f) = row.try_get::<f64, _>(*index) {
+                    // A REAL only keeps 15 significant digits, so read it back with
+                    // those digits, as SQLite's own CAST(... AS TEXT) does, rather
+                    // than through the shortest f64 form (0.30000000000000004)
+                    *index += 1;
+                    return rust_decimal::Decimal::from_scientific(&format!("{:.14e}", f))
+                        .map(|d| d.normalize())
+                        .map_err(|e| Error::Decode(Box::new(e)));
+                } else {
+                    *index += 1;
+                    return Err(Error::Decode(Box::new(e)));
+                }
+            }
+        };
+        *index += 1;
+        text.trim().parse().map_err(|e| Error::Decode(Box::new(e)))
+    }
+}
+
 // ============================================================================
 // Option Implementation
 // ============================================================================
@@ -0,0 +1,95 @@
+//! # Query Cache Module
+//!
+//! This module provides the in-memory result cache used by `QueryBuilder::cache`.
+//! Entries are keyed by the generated SQL and its bound arguments, expire after the
+//! TTL given when they were stored, and are dropped whenever the ORM writes to one
+//! of the tables they were read from.
+
+// ============================================================================
+// External Crate Imports
+// ============================================================================
+
+use sqlx::any::AnyRow;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+// ============================================================================
+// QueryCache Struct
+// ============================================================================
+
+/// A cache of query results shared by a `Database` and its transactions.
+///
+/// Cloning is cheap; clones share the same entries.
+#[derive(Clone, Default)]
+pub struct QueryCache {
+    entries: Arc<Mutex<HashMap<String, CacheEntry>>>,
+}
+
+/// Rows cached for one SQL statement and argument list.
+struct CacheEntry {
+    tables: Vec<String>,
+    rows: Vec<AnyRow>,
+    expires_at: Instant,
+}
+
+impl std::fmt::Debug for QueryCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryCache").field("entries", &self.len()).finish()
+    }
+}
+
+// ============================================================================
+// QueryCache Implementation
+// ============================================================================
+
+impl QueryCache {
+    /// Returns the cached rows for `key` if they have not expired.
+    pub(crate) fn get(&self, key: &str) -> Option<Vec<AnyRow>> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(key) {
+            Some(entry) if entry.expires_at > Instant::now() => Some(entry.rows.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores rows read from `tables` under `key` for `ttl`.
+    ///
+    /// Expired entries are swept first, so keys that are never read again do not
+    /// keep their rows alive.
+    pub(crate) fn insert(&self, tables: Vec<String>, key: String, rows: Vec<AnyRow>, ttl: Duration) {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.retain(|_, entry| entry.expires_at > now);
+        entries.insert(key, CacheEntry { tables, rows, expires_at: now + ttl });
+    }
+
+    /// Drops every entry read from `table`, alone or joined with other tables.
+    pub fn invalidate(&self, table: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|_, entry| !entry.tables.iter().any(|t| t == table));
+    }
+
+    /// Drops every entry.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    /// Returns the number of stored entries, including expired ones not yet evicted.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns whether the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -8,16 +8,27 @@
 // External Crate Imports
 // ============================================================================
 
-use futures::future::BoxFuture;
+use futures::{channel::mpsc, future::BoxFuture, stream::BoxStream, FutureExt, SinkExt, StreamExt};
 use heck::ToSnakeCase;
-use sqlx::{any::AnyArguments, AnyPool, Row, Arguments};
-use std::sync::Arc;
+use sqlx::{any::AnyArguments, AnyPool, Arguments, Encode, Row, Type};
+use std::{
+    borrow::Cow,
+    future::Future,
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 // ============================================================================
 // Internal Crate Imports
 // ============================================================================
 
-use crate::{migration::Migrator, Error, Model, QueryBuilder};
+use crate::{
+    any_struct::FromAnyRow, cache::QueryCache, dynamic::DynamicQuery, migration::Migrator, Error, Model, QueryBuilder,
+};
 
 // ============================================================================
 // Database Driver Enum
@@ -34,6 +45,57 @@ pub enum Drivers {
     SQLite,
 }
 
+impl Drivers {
+    /// Quotes an identifier, quoting each part of a dotted name.
+    ///
+    /// Every driver gets double quotes, the same quoting the rest of the generated
+    /// SQL uses, so one statement never mixes styles. Callers are expected to have
+    /// validated the identifier first.
+    pub(crate) fn quote_identifier(&self, name: &str) -> String {
+        name.split('.').map(|part| format!("\"{part}\"")).collect::<Vec<_>>().join(".")
+    }
+
+    /// Picks the driver from a connection URL's scheme, defaulting to SQLite.
+    fn from_url(url: &str) -> Self {
+        if url.starts_with("postgres") {
+            Drivers::Postgres
+        } else if url.starts_with("mysql") {
+            Drivers::MySQL
+        } else {
+            Drivers::SQLite
+        }
+    }
+
+    /// Largest number of bind parameters a single statement may carry.
+    ///
+    /// SQLite's limit is `SQLITE_MAX_VARIABLE_NUMBER`, 999 in builds before 3.32;
+    /// PostgreSQL and MySQL encode the count in 16 bits.
+    pub(crate) fn max_bind_params(&self) -> usize {
+        match self {
+            Drivers::SQLite => 999,
+            Drivers::Postgres | Drivers::MySQL => 65535,
+        }
+    }
+
+    /// Name of the crate feature that compiles this driver in.
+    pub(crate) fn feature_name(&self) -> &'static str {
+        match self {
+            Drivers::Postgres => "postgres",
+            Drivers::MySQL => "mysql",
+            Drivers::SQLite => "sqlite",
+        }
+    }
+
+    /// Returns whether this driver was compiled into the build.
+    fn is_compiled(&self) -> bool {
+        match self {
+            Drivers::Postgres => cfg!(feature = "postgres"),
+            Drivers::MySQL => cfg!(feature = "mysql"),
+            Drivers::SQLite => cfg!(feature = "sqlite"),
+        }
+    }
+}
+
 // ============================================================================
 // Database Struct
 // ============================================================================
@@ -51,6 +113,14 @@ pub struct Database {
     pub(crate) pool: AnyPool,
     /// The detected database driver
     pub(crate) driver: Drivers,
+    /// Results stored by `QueryBuilder::cache`
+    pub(crate) cache: QueryCache,
+    /// Executed statement counter, present when enabled with `DatabaseBuilder::count_queries`
+    pub(crate) query_counter: Option<Arc<AtomicU64>>,
+    /// Time limit applied to every statement, set with `DatabaseBuilder::default_query_timeout`
+    pub(crate) query_timeout: Option<Duration>,
+    /// Statement callback, set with `DatabaseBuilder::on_query`
+    pub(crate) on_query: Option<QueryHook>,
 }
 
 // ============================================================================
@@ -95,10 +165,48 @@ impl Database {
         QueryBuilder::new(self.clone(), self.driver, T::table_name(), <T as Model>::columns(), columns)
     }
 
+    /// Returns the row where `column = value`, inserting `default` if there is none.
+    ///
+    /// The boolean is `true` when the row was created by this call. The returned
+    /// row is read back after the insert, so database-generated values such as
+    /// auto-increment keys are filled in. `default` should carry `value` in
+    /// `column`. With a UNIQUE constraint on `column`, concurrent callers settle
+    /// on a single row: the insert that loses the race reads the winner's row
+    /// instead of failing. Without one, two callers may both insert.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let (tag, created) = db
+    ///     .get_or_create("name", "rust".to_string(), &Tag { id: 0, name: "rust".to_string() })
+    ///     .await?;
+    /// ```
+    pub async fn get_or_create<T, V>(&self, column: &'static str, value: V, default: &T) -> Result<(T, bool), Error>
+    where
+        T: Model + FromAnyRow + crate::AnyImpl + Send + Sync + Unpin,
+        V: 'static + for<'q> Encode<'q, sqlx::Any> + Type<sqlx::Any> + Send + Sync + Clone,
+    {
+        Ok(crate::query_builder::get_or_create(|| self.model::<T>(), column, value, default).await?)
+    }
+
     /// Creates a raw SQL query builder.
     pub fn raw<'a>(&self, sql: &'a str) -> RawQuery<'a, Self> {
         RawQuery::new(self.clone(), sql)
     }
+
+    /// Starts a query on a table known only by name at runtime.
+    ///
+    /// Columns are addressed by string and rows are returned as JSON value maps
+    /// by `scan_dynamic()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let rows = db.table("users").filter("active", Op::Eq, 1).scan_dynamic().await?;
+    /// ```
+    pub fn table(&self, name: &str) -> DynamicQuery<Self> {
+        DynamicQuery::new(self.clone(), self.driver, name)
+    }
     
     /// This function should have been here a long time ago.
     /// Retrieve the connection pool.
@@ -106,53 +214,296 @@ impl Database {
     	self.pool.clone()
     }
 
+    /// Returns the driver detected from the connection URL.
+    ///
+    /// Lets raw SQL pick the dialect of the database it runs against.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sql = match db.driver() {
+    ///     Drivers::Postgres => "SELECT now()",
+    ///     Drivers::MySQL => "SELECT NOW()",
+    ///     Drivers::SQLite => "SELECT datetime('now')",
+    /// };
+    /// ```
+    pub fn driver(&self) -> Drivers {
+        self.driver
+    }
+
+    /// Checks that the database is reachable.
+    ///
+    /// Acquires a connection from the pool and pings the server over it, honouring
+    /// the default query timeout. Fails when the pool is closed, exhausted past its
+    /// acquire timeout, or the server does not answer. The ping is not counted by
+    /// [`query_count`](Self::query_count).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// async fn readiness(db: &Database) -> StatusCode {
+    ///     if db.is_healthy().await { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE }
+    /// }
+    /// ```
+    pub async fn ping(&self) -> Result<(), Error> {
+        with_timeout(self.query_timeout, async {
+            let mut conn = self.pool.acquire().await?;
+            sqlx::Connection::ping(&mut *conn).await
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Returns whether [`ping`](Self::ping) succeeds.
+    pub async fn is_healthy(&self) -> bool {
+        self.ping().await.is_ok()
+    }
+
+    /// Closes the connection pool, waiting for checked-out connections to be returned.
+    ///
+    /// New acquires fail immediately, idle connections are closed, and the call
+    /// resolves once every connection in use (including open transactions) has
+    /// finished and been closed. Clones of the database share the pool, so every
+    /// operation through any of them errors afterwards with `sqlx::Error::PoolClosed`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// axum::serve(listener, app).with_graceful_shutdown(shutdown_signal()).await?;
+    /// db.close().await;
+    /// ```
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+
+    /// Returns whether [`close`](Self::close) has been called on this pool.
+    pub fn is_closed(&self) -> bool {
+        self.pool.is_closed()
+    }
+
+    /// Returns the number of statements executed through this database so far.
+    ///
+    /// Counting is off by default and this returns `0` unless the database was
+    /// built with [`DatabaseBuilder::count_queries`]. Clones of the database and
+    /// transactions started from it share the same counter. Results served from
+    /// the query cache do not count. The schema reads that `sync_table`,
+    /// `diff_table`, the `Migrator` and `DynamicQuery` issue before their own
+    /// statements do count.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder().count_queries(true).connect("sqlite::memory:").await?;
+    /// let users: Vec<User> = db.model::<User>().with("posts").scan().await?;
+    /// assert_eq!(db.query_count(), 2);
+    /// ```
+    pub fn query_count(&self) -> u64 {
+        self.query_counter.as_ref().map_or(0, |counter| counter.load(Ordering::Relaxed))
+    }
+
+    /// Resets the statement counter returned by [`query_count`](Self::query_count) to zero.
+    pub fn reset_query_count(&self) {
+        if let Some(counter) = &self.query_counter {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
     /// Starts a new database transaction.
     pub async fn begin(&self) -> Result<crate::transaction::Transaction<'_>, Error> {
+        self.begin_detached().await
+    }
+
+    /// Starts a transaction whose lifetime is not tied to `self`.
+    ///
+    /// The underlying pool connection is owned, so the caller may pick `'static`.
+    pub(crate) async fn begin_detached<'t>(&self) -> Result<crate::transaction::Transaction<'t>, Error> {
         let tx = self.pool.begin().await?;
         Ok(crate::transaction::Transaction {
-            tx: Arc::new(tokio::sync::Mutex::new(Some(tx))),
+            tx: Arc::new(crate::transaction::SharedTransaction::new(tx)),
             pool: self.pool.clone(),
             driver: self.driver,
+            cache: self.cache.clone(),
+            pending_invalidations: Arc::default(),
+            query_counter: self.query_counter.clone(),
+            query_timeout: self.query_timeout,
+            on_query: self.on_query.clone(),
         })
     }
 
-    /// Checks if a table exists in the database.
-    pub async fn table_exists(&self, table_name: &str) -> Result<bool, Error> {
-        let table_name_snake = table_name.to_snake_case();
-        let query = match self.driver {
-            Drivers::Postgres => {
-                "SELECT EXISTS (SELECT FROM information_schema.tables WHERE table_name = $1 AND table_schema = 'public')".to_string()
+    /// Runs `f` inside a transaction, committing on `Ok` and rolling back otherwise.
+    ///
+    /// The closure receives the open transaction and its result is returned as-is.
+    /// If it returns `Err` or panics, the transaction is rolled back before the error
+    /// is returned (or the panic is resumed), so no partial writes persist and the
+    /// connection goes back to the pool clean. Panics are only caught when the
+    /// binary is built with `panic = "unwind"` (the default).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let user_id = db.transaction(async |tx| {
+    ///     tx.model::<User>().insert(&user).await?;
+    ///     tx.model::<Profile>().insert(&profile).await?;
+    ///     Ok::<_, bottle_orm::Error>(user.id)
+    /// }).await?;
+    /// ```
+    pub async fn transaction<'db, F, T, E>(&'db self, f: F) -> Result<T, E>
+    where
+        F: AsyncFnOnce(&crate::transaction::Transaction<'db>) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let tx = self.begin().await?;
+        let outcome = AssertUnwindSafe(f(&tx)).catch_unwind().await;
+
+        match outcome {
+            Ok(Ok(value)) => {
+                tx.commit().await.map_err(Error::from)?;
+                Ok(value)
             }
-            Drivers::MySQL => {
-                "SELECT EXISTS (SELECT FROM information_schema.tables WHERE table_name = ? AND table_schema = DATABASE())".to_string()
+            Ok(Err(e)) => {
+                let _ = tx.rollback().await;
+                Err(e)
             }
-            Drivers::SQLite => {
-                "SELECT count(*) FROM sqlite_master WHERE type='table' AND name=?".to_string()
+            Err(panic) => {
+                let _ = tx.rollback().await;
+                std::panic::resume_unwind(panic)
             }
-        };
-
-        let row = sqlx::query(&query).bind(&table_name_snake).fetch_one(&self.pool).await?;
+        }
+    }
 
-        match self.driver {
-            Drivers::SQLite => {
-                let count: i64 = row.try_get(0)?;
-                Ok(count > 0)
-            }
-            _ => {
-                let exists: bool = row.try_get(0)?;
-                Ok(exists)
+    /// Runs `f` in a transaction like [`transaction`](Self::transaction), retrying
+    /// it when the database aborts it with a serialization failure or deadlock.
+    ///
+    /// Under `SERIALIZABLE` isolation, or when rows are locked in different orders,
+    /// concurrent transactions can be aborted with errors that are expected to
+    /// succeed on a second run (see [`Error::is_retryable`]). Such a failure, whether
+    /// raised by a statement or by the commit, rolls the attempt back and runs `f`
+    /// again in a new transaction, up to `retries` more times, waiting 10ms, 20ms,
+    /// 40ms, ... (capped at 1s) between attempts. Any other error is returned at once.
+    /// `f` may run several times, so it must not have side effects outside the
+    /// transaction.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.transaction_with_retry(3, async |tx| {
+    ///     let account: Account = tx.model::<Account>().find(1).await?;
+    ///     tx.model::<Account>().filter("id", Op::Eq, 1).update("balance", account.balance - 10).await?;
+    ///     Ok(())
+    /// }).await?;
+    /// ```
+    pub async fn transaction_with_retry<'db, F, T>(&'db self, retries: u32, f: F) -> Result<T, Error>
+    where
+        F: AsyncFn(&crate::transaction::Transaction<'db>) -> Result<T, Error>,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.transaction(&f).await {
+                Err(e) if attempt < retries && e.is_retryable() => {
+                    let backoff = Duration::from_millis(10 << attempt.min(6)).min(Duration::from_secs(1));
+                    log::warn!("Transaction aborted ({}); retrying in {:?}", e, backoff);
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+                outcome => return outcome,
             }
         }
     }
 
+    /// Checks if a table exists in the database.
+    ///
+    /// `table_name` is matched exactly first, so custom `#[orm(table = "...")]` names
+    /// work as stored. When no such table exists, its snake_case form is tried, as
+    /// earlier versions did.
+    pub async fn table_exists(&self, table_name: &str) -> Result<bool, Error> {
+        if table_exists_on(self, table_name).await? {
+            return Ok(true);
+        }
+        match snake_case_fallback(table_name) {
+            Some(snake) => table_exists_on(self, &snake).await,
+            None => Ok(false),
+        }
+    }
+
     /// Creates a table based on the provided Model metadata.
     pub async fn create_table<T: Model>(&self) -> Result<(), Error> {
-        let table_name = T::table_name().to_snake_case();
+        for statement in self.create_table_statements::<T>(false)? {
+            sqlx::query(&statement).execute(&self.pool).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Drops the table of `T` if it exists.
+    ///
+    /// Indexes on the table go with it. Fails if other tables still reference it
+    /// through foreign keys; drop those first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.drop_table::<Session>().await?;
+    /// ```
+    pub async fn drop_table<T: Model>(&self) -> Result<(), Error> {
+        let sql = format!("DROP TABLE IF EXISTS \"{}\"", T::table_name());
+        self.execute(&sql, AnyArguments::default()).await?;
+        self.cache.invalidate(T::table_name());
+        Ok(())
+    }
+
+    /// Deletes every row of the table of `T`.
+    ///
+    /// Uses `TRUNCATE TABLE` on PostgreSQL and MySQL; SQLite has no `TRUNCATE`, so
+    /// it runs `DELETE FROM` and resets the table's auto-increment counter. See
+    /// [`QueryBuilder::truncate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.truncate::<AuditLog>().await?;
+    /// ```
+    pub async fn truncate<T: Model + Send + Sync + Unpin + crate::AnyImpl>(&self) -> Result<(), Error> {
+        Ok(self.model::<T>().truncate().await?)
+    }
+
+    /// Creates a temporary table for `T` and returns the transaction that can see it.
+    ///
+    /// A temporary table belongs to the connection that created it, so it is
+    /// created inside a new transaction, which keeps one pooled connection pinned
+    /// for every query run through it. Other connections of the pool never see
+    /// the table. It is dropped when the connection closes; since the pool reuses
+    /// connections, drop it explicitly before committing when it is no longer needed.
+    /// Foreign keys are not declared on temporary tables.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let tx = db.create_temp_table::<ImportRow>().await?;
+    /// tx.model::<ImportRow>().batch_insert(&rows).await?;
+    /// // ... validate and copy into the real tables ...
+    /// tx.raw("DROP TABLE import_row").execute().await?;
+    /// tx.commit().await?;
+    /// ```
+    pub async fn create_temp_table<T: Model>(&self) -> Result<crate::transaction::Transaction<'_>, Error> {
+        let tx = self.begin().await?;
+        tx.create_temp_table::<T>().await?;
+        Ok(tx)
+    }
+
+    /// Builds the `CREATE TABLE` statement for `T`, followed by its `CREATE INDEX` statements.
+    pub(crate) fn create_table_statements<T: Model>(&self, temporary: bool) -> Result<Vec<String>, Error> {
+        let table_name = T::table_name();
         let columns = T::columns();
 
-        let mut query = format!("CREATE TABLE IF NOT EXISTS \"{}\" (", table_name);
+        let create = match (temporary, self.driver) {
+            (false, _) => "CREATE TABLE",
+            (true, Drivers::SQLite) => "CREATE TEMP TABLE",
+            (true, _) => "CREATE TEMPORARY TABLE",
+        };
+        let mut query = format!("{} IF NOT EXISTS \"{}\" (", create, table_name);
         let mut column_defs = Vec::new();
         let mut indexes = Vec::new();
+        let mut enum_types = Vec::new();
 
         // Identify primary key columns
         let pk_columns: Vec<String> = columns.iter()
@@ -162,21 +513,65 @@ impl Database {
 
         for col in columns {
             let col_name_clean = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
-            let mut def = format!("\"{}\" {}", col_name_clean, col.sql_type);
+            let mut def = format!("\"{}\" {}", col_name_clean, col.sql_type_for(self.driver));
+
+            // Native enum types have to exist before the table that uses them
+            if matches!(self.driver, Drivers::Postgres)
+                && let Some(create_type) = col.create_enum_type_sql()
+                && !enum_types.contains(&create_type)
+            {
+                enum_types.push(create_type);
+            }
 
             // If it's a single primary key, we can keep it inline for simplicity
             // If it's composite, we MUST define it as a table constraint
-            if col.is_primary_key && pk_columns.len() == 1 {
+            if col.is_primary_key && pk_columns.len() == 1 && col.auto_increment {
+                def = match self.driver {
+                    Drivers::Postgres => {
+                        let serial = match col.sql_type {
+                            "BIGINT" => "BIGSERIAL",
+                            "SMALLINT" => "SMALLSERIAL",
+                            _ => "SERIAL",
+                        };
+                        format!("\"{}\" {} PRIMARY KEY", col_name_clean, serial)
+                    }
+                    Drivers::MySQL => format!("\"{}\" {} AUTO_INCREMENT PRIMARY KEY", col_name_clean, col.sql_type),
+                    // SQLite only allows AUTOINCREMENT on a column declared exactly as INTEGER
+                    Drivers::SQLite => format!("\"{}\" INTEGER PRIMARY KEY AUTOINCREMENT", col_name_clean),
+                };
+            } else if col.is_primary_key && pk_columns.len() == 1 {
                 def.push_str(" PRIMARY KEY");
             } else if !col.is_nullable || col.is_primary_key {
                 def.push_str(" NOT NULL");
             }
 
-            if col.unique && !col.is_primary_key {
-                def.push_str(" UNIQUE");
+            if let Some(default) = col.default {
+                def.push_str(" DEFAULT ");
+                def.push_str(&self.format_default(default));
             }
 
-            if col.index && !col.is_primary_key && !col.unique {
+            // SQLite cannot add constraints to an existing table, so foreign keys are
+            // emitted inline here instead of in assign_foreign_keys.
+            if matches!(self.driver, Drivers::SQLite)
+                && !temporary
+                && let (Some(f_table), Some(f_key)) = (col.foreign_table, col.foreign_key)
+            {
+                def.push_str(&format!(
+                    " REFERENCES \"{}\"(\"{}\"){}",
+                    f_table,
+                    f_key.to_snake_case(),
+                    Self::referential_actions(&col)?
+                ));
+            }
+
+            // Unique columns get a named index rather than an inline UNIQUE, so that
+            // sync_table and diff_table find it under the name they expect
+            if col.unique && !col.is_primary_key {
+                indexes.push(format!(
+                    "CREATE UNIQUE INDEX IF NOT EXISTS \"unique_{}_{}\" ON \"{}\" (\"{}\")",
+                    table_name, col_name_clean, table_name, col_name_clean
+                ));
+            } else if col.index && !col.is_primary_key {
                 indexes.push(format!(
                     "CREATE INDEX IF NOT EXISTS \"idx_{}_{}\" ON \"{}\" (\"{}\")",
                     table_name, col_name_clean, table_name, col_name_clean
@@ -194,132 +589,326 @@ impl Database {
         query.push_str(&column_defs.join(", "));
         query.push(')');
 
-        sqlx::query(&query).execute(&self.pool).await?;
-
-        for idx_query in indexes {
-            sqlx::query(&idx_query).execute(&self.pool).await?;
+        for index in T::indexes() {
+            indexes.push(format!(
+                "CREATE {}INDEX IF NOT EXISTS \"{}\" ON \"{}\" ({})",
+                if index.unique { "UNIQUE " } else { "" },
+                index.name(table_name),
+                table_name,
+                index.columns.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", ")
+            ));
         }
 
-        Ok(())
+        let mut statements = enum_types;
+        statements.push(query);
+        statements.extend(indexes);
+        Ok(statements)
+    }
+
+    /// Returns the `CREATE TABLE` and `CREATE INDEX` statements for `T` without running them.
+    ///
+    /// These are the statements [`create_table`](Self::create_table) executes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// for statement in db.create_table_sql::<User>()? {
+    ///     println!("{};", statement);
+    /// }
+    /// ```
+    pub fn create_table_sql<T: Model>(&self) -> Result<Vec<String>, Error> {
+        self.create_table_statements::<T>(false)
     }
 
     /// Synchronizes a table schema by adding missing columns or indexes.
+    ///
+    /// On PostgreSQL, variants added to a native enum are appended to its existing
+    /// type with `ALTER TYPE ... ADD VALUE`.
     pub async fn sync_table<T: Model>(&self) -> Result<(), Error> {
-        if !self.table_exists(T::table_name()).await? {
-            return self.create_table::<T>().await;
-        }
+        sync_table_on::<T, _>(self).await
+    }
 
-        let table_name = T::table_name().to_snake_case();
-        let model_columns = T::columns();
-        let existing_columns = self.get_table_columns(&table_name).await?;
+    /// Returns the statements [`sync_table`](Self::sync_table) would run for `T`, without running them.
+    ///
+    /// The current schema is read to compute the difference, but nothing is changed:
+    /// a missing table yields its `CREATE TABLE` statements, an existing one the
+    /// `ALTER TABLE ... ADD COLUMN` and `CREATE INDEX` statements it lacks. An
+    /// up-to-date table yields an empty list.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let pending = db.sync_table_sql::<User>().await?;
+    /// assert!(pending.is_empty(), "unapplied schema changes: {:#?}", pending);
+    /// ```
+    pub async fn sync_table_sql<T: Model>(&self) -> Result<Vec<String>, Error> {
+        sync_table_sql_on::<T, _>(self).await
+    }
 
-        for col in model_columns {
-            let col_name_clean = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
-            if !existing_columns.contains(&col_name_clean) {
-                let mut alter_query = format!("ALTER TABLE \"{}\" ADD COLUMN \"{}\" {}", table_name, col_name_clean, col.sql_type);
-                if !col.is_nullable {
-                    alter_query.push_str(" DEFAULT ");
-                    match col.sql_type {
-                        "INTEGER" | "INT" | "BIGINT" => alter_query.push('0'),
-                        "BOOLEAN" | "BOOL" => alter_query.push_str("FALSE"),
-                        _ => alter_query.push_str("''"),
-                    }
-                }
-                sqlx::query(&alter_query).execute(&self.pool).await?;
-            }
+    /// Compares `T` with the live schema of its table, without changing anything.
+    ///
+    /// This is the read-only companion to [`sync_table`](Self::sync_table): it reports
+    /// the columns the model declares but the table lacks, the columns the table has
+    /// but the model no longer declares, and the same for the indexes Bottle manages
+    /// (`idx_*` and `unique_*`). Indexes the database creates on its own for primary
+    /// keys and inline constraints are not reported. A missing table yields every
+    /// column and index as missing.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let diff = db.diff_table::<User>().await?;
+    /// for column in &diff.extra_columns {
+    ///     println!("not mapped by User: {}", column);
+    /// }
+    /// assert!(diff.missing_columns.is_empty());
+    /// ```
+    pub async fn diff_table<T: Model>(&self) -> Result<SchemaDiff, Error> {
+        diff_table_on::<T, _>(self).await
+    }
 
-            if col.index || col.unique {
-                let existing_indexes = self.get_table_indexes(&table_name).await?;
-                let idx_name = format!("idx_{}_{}", table_name, col_name_clean);
-                let uniq_name = format!("unique_{}_{}", table_name, col_name_clean);
+    /// Synchronizes a table like [`sync_table`](Self::sync_table), then drops the
+    /// columns that `T` no longer declares.
+    ///
+    /// The dropped columns and their data are lost, which is why this is a separate,
+    /// opt-in method. PostgreSQL and MySQL use `ALTER TABLE ... DROP COLUMN`. SQLite
+    /// rebuilds the table instead: a new table is created from `T`, the kept columns
+    /// are copied over, the old table is dropped and the new one renamed in its place,
+    /// all in one transaction with foreign key enforcement paused on that connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // `legacy_flag` was removed from `User`; its column goes too.
+    /// db.sync_table_strict::<User>().await?;
+    /// ```
+    pub async fn sync_table_strict<T: Model>(&self) -> Result<(), Error> {
+        self.sync_table::<T>().await?;
+
+        let table_name = T::table_name();
+        let model_columns: Vec<String> =
+            T::columns().iter().map(|c| c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case()).collect();
+        let existing_columns = self.get_table_columns(table_name).await?;
+        let stale: Vec<&String> = existing_columns.iter().filter(|c| !model_columns.contains(c)).collect();
+        if stale.is_empty() {
+            return Ok(());
+        }
 
-                if col.unique && !existing_indexes.contains(&uniq_name) {
-                    let mut query = format!("CREATE UNIQUE INDEX \"{}\" ON \"{}\" (\"{}\")", uniq_name, table_name, col_name_clean);
-                    if matches!(self.driver, Drivers::SQLite) {
-                        query = format!("CREATE UNIQUE INDEX IF NOT EXISTS \"{}\" ON \"{}\" (\"{}\")", uniq_name, table_name, col_name_clean);
-                    }
-                    sqlx::query(&query).execute(&self.pool).await?;
-                } else if col.index && !existing_indexes.contains(&idx_name) && !col.unique {
-                    let mut query = format!("CREATE INDEX \"{}\" ON \"{}\" (\"{}\")", idx_name, table_name, col_name_clean);
-                    if matches!(self.driver, Drivers::SQLite) {
-                        query = format!("CREATE INDEX IF NOT EXISTS \"{}\" ON \"{}\" (\"{}\")", idx_name, table_name, col_name_clean);
-                    }
+        match self.driver {
+            Drivers::SQLite => {
+                let kept: Vec<&String> = existing_columns.iter().filter(|c| model_columns.contains(c)).collect();
+                self.rebuild_sqlite_table::<T>(&kept).await?;
+            }
+            _ => {
+                for column in stale {
+                    let query = format!(
+                        "ALTER TABLE {} DROP COLUMN {}",
+                        self.driver.quote_identifier(table_name),
+                        self.driver.quote_identifier(column)
+                    );
                     sqlx::query(&query).execute(&self.pool).await?;
                 }
             }
         }
 
+        self.cache.invalidate(table_name);
         Ok(())
     }
 
-    /// Returns the current columns of a table.
-    pub async fn get_table_columns(&self, table_name: &str) -> Result<Vec<String>, Error> {
-        let table_name_snake = table_name.to_snake_case();
-        let query = match self.driver {
-            Drivers::Postgres => "SELECT column_name::TEXT FROM information_schema.columns WHERE table_name = $1 AND table_schema = 'public'".to_string(),
-            Drivers::MySQL => "SELECT column_name FROM information_schema.columns WHERE table_name = ? AND table_schema = DATABASE()".to_string(),
-            Drivers::SQLite => format!("PRAGMA table_info(\"{}\")", table_name_snake),
-        };
+    /// Recreates the table of `T` on SQLite, keeping the data of the `kept` columns.
+    async fn rebuild_sqlite_table<T: Model>(&self, kept: &[&String]) -> Result<(), Error> {
+        let table_name = T::table_name();
+        let temp_name = format!("_bottle_rebuild_{}", table_name);
+        let mut statements = self.create_table_statements::<T>(false)?;
+        let create = statements.remove(0).replacen(&format!("\"{}\"", table_name), &format!("\"{}\"", temp_name), 1);
+        let column_list = kept.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", ");
+
+        // foreign_keys cannot change inside a transaction, and dropping a referenced
+        // table with enforcement on would delete or reject the child rows.
+        let mut conn = self.pool.acquire().await?;
+        let enforced: i64 = sqlx::query("PRAGMA foreign_keys").fetch_one(&mut *conn).await?.try_get(0)?;
+        sqlx::query("PRAGMA foreign_keys = OFF").execute(&mut *conn).await?;
+
+        let result = async {
+            sqlx::query("BEGIN").execute(&mut *conn).await?;
+            let rebuilt = async {
+                sqlx::query(&format!("DROP TABLE IF EXISTS \"{}\"", temp_name)).execute(&mut *conn).await?;
+                sqlx::query(&create).execute(&mut *conn).await?;
+                sqlx::query(&format!(
+                    "INSERT INTO \"{}\" ({}) SELECT {} FROM \"{}\"",
+                    temp_name, column_list, column_list, table_name
+                ))
+                .execute(&mut *conn)
+                .await?;
+                sqlx::query(&format!("DROP TABLE \"{}\"", table_name)).execute(&mut *conn).await?;
+                sqlx::query(&format!("ALTER TABLE \"{}\" RENAME TO \"{}\"", temp_name, table_name))
+                    .execute(&mut *conn)
+                    .await?;
+                for statement in &statements {
+                    sqlx::query(statement).execute(&mut *conn).await?;
+                }
+                Ok::<(), sqlx::Error>(())
+            }
+            .await;
+            match rebuilt {
+                Ok(()) => sqlx::query("COMMIT").execute(&mut *conn).await.map(|_| ()),
+                Err(e) => {
+                    let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+                    Err(e)
+                }
+            }
+        }
+        .await;
 
-        let rows = if let Drivers::SQLite = self.driver {
-            sqlx::query(&query).fetch_all(&self.pool).await?
-        } else {
-            sqlx::query(&query).bind(&table_name_snake).fetch_all(&self.pool).await?
-        };
+        if enforced != 0 {
+            sqlx::query("PRAGMA foreign_keys = ON").execute(&mut *conn).await?;
+        }
+        Ok(result?)
+    }
 
-        let mut columns = Vec::new();
-        for row in rows {
-            let col_name: String = if let Drivers::SQLite = self.driver {
-                row.try_get("name")?
-            } else {
-                row.try_get(0)?
+    /// Renders the `ON DELETE` / `ON UPDATE` clauses declared on a foreign key column.
+    ///
+    /// Returns an empty string when no action is declared, and `Error::InvalidArgument`
+    /// for anything other than `cascade`, `restrict`, `set null` or `no action`.
+    fn referential_actions(col: &crate::model::ColumnInfo) -> Result<String, Error> {
+        let mut clause = String::new();
+
+        for (keyword, action) in [("ON DELETE", col.on_delete), ("ON UPDATE", col.on_update)] {
+            let Some(action) = action else { continue };
+            let normalized = action.trim().replace('_', " ").to_uppercase();
+            if !matches!(normalized.as_str(), "CASCADE" | "RESTRICT" | "SET NULL" | "NO ACTION") {
+                return Err(Error::InvalidArgument(format!(
+                    "Unsupported referential action '{}' on column '{}' (expected cascade, restrict, set null or no action)",
+                    action, col.name
+                )));
+            }
+            clause.push_str(&format!(" {} {}", keyword, normalized));
+        }
+
+        Ok(clause)
+    }
+
+    /// Renders a `#[orm(default = "...")]` expression for use after `DEFAULT`.
+    ///
+    /// Numbers, booleans, `NULL`, the `CURRENT_*` keywords, quoted literals and
+    /// function calls are emitted as-is (function calls are parenthesized on SQLite,
+    /// which requires it); anything else is quoted as a string literal.
+    fn format_default(&self, expr: &str) -> String {
+        let trimmed = expr.trim();
+        let upper = trimmed.to_uppercase();
+
+        let is_literal = is_numeric_literal(trimmed)
+            || matches!(
+                upper.as_str(),
+                "TRUE" | "FALSE" | "NULL" | "CURRENT_TIMESTAMP" | "CURRENT_DATE" | "CURRENT_TIME" | "LOCALTIMESTAMP"
+            )
+            || (trimmed.len() >= 2 && trimmed.starts_with('\'') && trimmed.ends_with('\''));
+        if is_literal {
+            return trimmed.to_string();
+        }
+
+        let is_function_call = trimmed.ends_with(')')
+            && trimmed
+                .split_once('(')
+                .is_some_and(|(name, _)| !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.'));
+        if is_function_call {
+            return match self.driver {
+                Drivers::SQLite => format!("({})", trimmed),
+                _ => trimmed.to_string(),
             };
-            columns.push(col_name);
         }
-        Ok(columns)
+
+        format!("'{}'", trimmed.replace('\'', "''"))
+    }
+
+    /// Returns the current columns of a table.
+    ///
+    /// `table_name` is resolved like in [`table_exists`](Self::table_exists): the exact
+    /// name first, then its snake_case form.
+    pub async fn get_table_columns(&self, table_name: &str) -> Result<Vec<String>, Error> {
+        let columns = table_columns_on(self, table_name).await?;
+        match snake_case_fallback(table_name) {
+            Some(snake) if columns.is_empty() => table_columns_on(self, &snake).await,
+            _ => Ok(columns),
+        }
     }
 
     /// Returns the current indexes of a table.
+    ///
+    /// `table_name` is resolved like in [`table_exists`](Self::table_exists): the exact
+    /// name first, then its snake_case form.
     pub async fn get_table_indexes(&self, table_name: &str) -> Result<Vec<String>, Error> {
-        let table_name_snake = table_name.to_snake_case();
-        let query = match self.driver {
-            Drivers::Postgres => "SELECT indexname::TEXT FROM pg_indexes WHERE tablename = $1 AND schemaname = 'public'".to_string(),
-            Drivers::MySQL => "SELECT INDEX_NAME FROM information_schema.STATISTICS WHERE TABLE_NAME = ? AND TABLE_SCHEMA = DATABASE()".to_string(),
-            Drivers::SQLite => format!("PRAGMA index_list(\"{}\")", table_name_snake),
-        };
-
-        let rows = if let Drivers::SQLite = self.driver {
-            sqlx::query(&query).fetch_all(&self.pool).await?
-        } else {
-            sqlx::query(&query).bind(&table_name_snake).fetch_all(&self.pool).await?
-        };
-
-        let mut indexes = Vec::new();
-        for row in rows {
-            let idx_name: String = if let Drivers::SQLite = self.driver {
-                row.try_get("name")?
-            } else {
-                row.try_get(0)?
-            };
-            indexes.push(idx_name);
+        if let Some(snake) = snake_case_fallback(table_name)
+            && !table_exists_on(self, table_name).await?
+        {
+            return table_indexes_on(self, &snake).await;
         }
-        Ok(indexes)
+        table_indexes_on(self, table_name).await
     }
 
     /// Assigns foreign keys to a table.
+    ///
+    /// Every referenced table and column is checked first; a missing target returns
+    /// `Error::InvalidForeignKey` before any DDL is attempted. Constraints that already
+    /// exist are skipped; any other failure, such as existing rows that violate the
+    /// constraint, is returned.
     pub async fn assign_foreign_keys<T: Model>(&self) -> Result<(), Error> {
-        let table_name = T::table_name().to_snake_case();
-        let columns = T::columns();
+        assign_foreign_keys_on::<T, _>(self).await
+    }
 
-        for col in columns {
+    /// Builds the `ALTER TABLE ... ADD CONSTRAINT` statements for the foreign keys of `T`.
+    ///
+    /// Empty on SQLite, where foreign keys are declared with the table.
+    pub(crate) fn foreign_key_sql<T: Model>(&self) -> Result<Vec<String>, Error> {
+        Ok(self.foreign_key_constraints::<T>()?.into_iter().map(|(_, statement)| statement).collect())
+    }
+
+    /// Returns the name and `ADD CONSTRAINT` statement of each foreign key of `T`.
+    fn foreign_key_constraints<T: Model>(&self) -> Result<Vec<(String, String)>, Error> {
+        let table_name = T::table_name();
+        let mut constraints = Vec::new();
+
+        for col in T::columns() {
             if let (Some(f_table), Some(f_key)) = (col.foreign_table, col.foreign_key) {
+                let actions = Self::referential_actions(&col)?;
                 if matches!(self.driver, Drivers::SQLite) { continue; }
-                let constraint_name = format!("fk_{}_{}_{}", table_name, f_table.to_snake_case(), col.name.to_snake_case());
-                let query = format!(
-                    "ALTER TABLE \"{}\" ADD CONSTRAINT \"{}\" FOREIGN KEY (\"{}\") REFERENCES \"{}\"(\"{}\")",
-                    table_name, constraint_name, col.name.to_snake_case(), f_table.to_snake_case(), f_key.to_snake_case()
+                let constraint_name = format!("fk_{}_{}_{}", table_name, f_table, col.name.to_snake_case());
+                let statement = format!(
+                    "ALTER TABLE \"{}\" ADD CONSTRAINT \"{}\" FOREIGN KEY (\"{}\") REFERENCES \"{}\"(\"{}\"){}",
+                    table_name, constraint_name, col.name.to_snake_case(), f_table, f_key.to_snake_case(), actions
                 );
-                let _ = sqlx::query(&query).execute(&self.pool).await;
+                constraints.push((constraint_name, statement));
+            }
+        }
+        Ok(constraints)
+    }
+
+    /// Drops the foreign key constraints created by [`assign_foreign_keys`](Self::assign_foreign_keys).
+    ///
+    /// Does nothing on SQLite, where foreign keys are part of the table definition,
+    /// or when the table does not exist. Constraints that are already gone are skipped.
+    pub async fn drop_foreign_keys<T: Model>(&self) -> Result<(), Error> {
+        let table_name = T::table_name();
+        if matches!(self.driver, Drivers::SQLite) || !self.table_exists(table_name).await? {
+            return Ok(());
+        }
+
+        for col in T::columns() {
+            if let Some(f_table) = col.foreign_table {
+                let constraint_name = format!("fk_{}_{}_{}", table_name, f_table, col.name.to_snake_case());
+                match self.driver {
+                    Drivers::Postgres => {
+                        let query = format!("ALTER TABLE \"{}\" DROP CONSTRAINT IF EXISTS \"{}\"", table_name, constraint_name);
+                        sqlx::query(&query).execute(&self.pool).await?;
+                    }
+                    // MySQL has no IF EXISTS for foreign keys
+                    _ => {
+                        if foreign_key_exists_on(self, table_name, &constraint_name).await? {
+                            let query = format!("ALTER TABLE \"{}\" DROP FOREIGN KEY \"{}\"", table_name, constraint_name);
+                            sqlx::query(&query).execute(&self.pool).await?;
+                        }
+                    }
+                }
             }
         }
         Ok(())
@@ -332,6 +921,19 @@ impl Database {
 
 pub struct DatabaseBuilder {
     max_connections: u32,
+    min_connections: u32,
+    acquire_timeout: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    count_queries: bool,
+    query_timeout: Option<Duration>,
+    statement_cache_capacity: Option<usize>,
+    on_query: Option<QueryHook>,
+}
+
+impl Default for DatabaseBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl DatabaseBuilder {
@@ -342,7 +944,18 @@ impl DatabaseBuilder {
     /// ```rust,ignore
     /// let builder = DatabaseBuilder::new();
     /// ```
-    pub fn new() -> Self { Self { max_connections: 5 } }
+    pub fn new() -> Self {
+        Self {
+            max_connections: 5,
+            min_connections: 0,
+            acquire_timeout: None,
+            idle_timeout: None,
+            count_queries: false,
+            query_timeout: None,
+            statement_cache_capacity: None,
+            on_query: None,
+        }
+    }
 
     /// Sets the maximum number of connections for the database pool.
     ///
@@ -360,7 +973,122 @@ impl DatabaseBuilder {
     /// ```
     pub fn max_connections(mut self, max: u32) -> Self { self.max_connections = max; self }
 
-    /// Connects to the database using the configured settings.
+    /// Sets how many idle connections the pool keeps open.
+    ///
+    /// The pool opens connections in the background until it holds at least `min`,
+    /// so the first requests after a quiet period do not pay the connection cost.
+    /// Capped at `max_connections` by the pool. Defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder()
+    ///     .min_connections(2)
+    ///     .max_connections(10)
+    ///     .connect("postgres://localhost/app")
+    ///     .await?;
+    /// ```
+    pub fn min_connections(mut self, min: u32) -> Self { self.min_connections = min; self }
+
+    /// Sets how long a query waits for a free pool connection before failing.
+    ///
+    /// When every connection is busy for longer than `timeout`, the query fails
+    /// with `sqlx::Error::PoolTimedOut` instead of queueing. sqlx waits 30 seconds
+    /// by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder()
+    ///     .acquire_timeout(Duration::from_secs(2))
+    ///     .connect("postgres://localhost/app")
+    ///     .await?;
+    /// ```
+    pub fn acquire_timeout(mut self, timeout: Duration) -> Self { self.acquire_timeout = Some(timeout); self }
+
+    /// Sets how long a connection may sit idle in the pool before it is closed.
+    ///
+    /// Connections kept by [`min_connections`](Self::min_connections) are replaced
+    /// rather than left below the minimum. sqlx closes idle connections after 10
+    /// minutes by default.
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self { self.idle_timeout = Some(timeout); self }
+
+    /// Enables counting of executed statements, read back with [`Database::query_count`].
+    ///
+    /// Useful in tests to catch N+1 query patterns.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder()
+    ///     .count_queries(true)
+    ///     .connect("sqlite::memory:")
+    ///     .await?;
+    /// ```
+    pub fn count_queries(mut self, enabled: bool) -> Self { self.count_queries = enabled; self }
+
+    /// Sets a time limit for every statement executed through the database.
+    ///
+    /// A statement that runs longer fails with an `std::io::ErrorKind::TimedOut`
+    /// I/O error. Transactions started from the database inherit the limit, and a
+    /// single query can override it with `QueryBuilder::timeout` or `RawQuery::timeout`.
+    /// The limit is enforced on the client: the statement is abandoned, but the
+    /// server may keep working on it until it notices the dropped connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder()
+    ///     .default_query_timeout(Duration::from_secs(5))
+    ///     .connect("postgres://localhost/app")
+    ///     .await?;
+    /// ```
+    pub fn default_query_timeout(mut self, timeout: Duration) -> Self { self.query_timeout = Some(timeout); self }
+
+    /// Sets how many prepared statements each connection keeps cached.
+    ///
+    /// sqlx caches prepared statements per connection (100 by default). Raise the
+    /// capacity for applications that run many distinct queries, or lower it to save
+    /// memory when they run few. Applied on PostgreSQL and MySQL by adding
+    /// `statement-cache-capacity` to the connection URL; SQLite URLs cannot carry the
+    /// option through the `Any` driver, so it is ignored there with a warning.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder()
+    ///     .statement_cache_capacity(500)
+    ///     .connect("postgres://localhost/app")
+    ///     .await?;
+    /// ```
+    pub fn statement_cache_capacity(mut self, capacity: usize) -> Self { self.statement_cache_capacity = Some(capacity); self }
+
+    /// Registers a callback invoked after every statement with its SQL and duration.
+    ///
+    /// Covers the statements run by `QueryBuilder`, `RawQuery`, `DynamicQuery` and
+    /// transactions started from the database, whether they succeed or fail.
+    /// Streamed queries are reported once the stream is exhausted. Schema reads made
+    /// internally, such as the `information_schema` or `PRAGMA` lookups run by
+    /// `sync_table`, `diff_table` and the `Migrator`, are reported too. The callback
+    /// runs on the query's task, so it should be cheap, e.g. forwarding to a logger.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let db = Database::builder()
+    ///     .on_query(|sql, elapsed| log::info!("{:?} {}", elapsed, sql))
+    ///     .connect("postgres://localhost/app")
+    ///     .await?;
+    /// ```
+    pub fn on_query<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&str, Duration) + Send + Sync + 'static,
+    {
+        self.on_query = Some(QueryHook(Arc::new(callback)));
+        self
+    }
+
+    /// Connects to the database using the configured settings.
     ///
     /// # Arguments
     ///
@@ -373,22 +1101,497 @@ impl DatabaseBuilder {
     ///     .connect("sqlite::memory:")
     ///     .await?;
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::DriverNotCompiled` when the URL names a driver whose crate
+    /// feature (`postgres`, `mysql` or `sqlite`) is disabled.
     pub async fn connect(self, url: &str) -> Result<Database, Error> {
+        let driver = Drivers::from_url(url);
+        if !driver.is_compiled() {
+            return Err(Error::DriverNotCompiled { driver });
+        }
+
+        let url = match (self.statement_cache_capacity, driver) {
+            (Some(capacity), Drivers::Postgres | Drivers::MySQL) => {
+                let separator = if url.contains('?') { '&' } else { '?' };
+                format!("{}{}statement-cache-capacity={}", url, separator, capacity)
+            }
+            (Some(_), Drivers::SQLite) => {
+                log::warn!("statement_cache_capacity is not supported for SQLite connections; using the default");
+                url.to_string()
+            }
+            (None, _) => url.to_string(),
+        };
+
         // Ensure sqlx drivers are registered for Any driver support
-        let _ = sqlx::any::install_default_drivers();
+        sqlx::any::install_default_drivers();
+
+        let mut options = sqlx::any::AnyPoolOptions::new()
+            .max_connections(self.max_connections)
+            .min_connections(self.min_connections);
+        if let Some(timeout) = self.acquire_timeout {
+            options = options.acquire_timeout(timeout);
+        }
+        if let Some(timeout) = self.idle_timeout {
+            options = options.idle_timeout(timeout);
+        }
+
+        let pool = options.connect(&url).await?;
+        let query_counter = self.count_queries.then(|| Arc::new(AtomicU64::new(0)));
+        Ok(Database {
+            pool,
+            driver,
+            cache: QueryCache::default(),
+            query_counter,
+            query_timeout: self.query_timeout,
+            on_query: self.on_query,
+        })
+    }
+}
+
+// ============================================================================
+// Schema Helpers
+// ============================================================================
+//
+// Shared by the `Database` methods and by `Migrator::run`, which runs them on a
+// transaction so a failed migration leaves the schema untouched.
+
+/// Checks if a table exists, on any connection.
+pub(crate) async fn table_exists_on<C: Connection>(conn: &C, table_name: &str) -> Result<bool, Error> {
+    let query = match conn.driver() {
+        Drivers::Postgres => {
+            "SELECT EXISTS (SELECT FROM information_schema.tables WHERE table_name = $1 AND table_schema = 'public')"
+        }
+        Drivers::MySQL => {
+            "SELECT EXISTS (SELECT FROM information_schema.tables WHERE table_name = ? AND table_schema = DATABASE())"
+        }
+        Drivers::SQLite => "SELECT count(*) FROM sqlite_master WHERE type='table' AND name=?",
+    };
+
+    let mut args = AnyArguments::default();
+    let _ = args.add(table_name.to_string());
+    let row = conn.fetch_one(query, args).await?;
+
+    match conn.driver() {
+        Drivers::SQLite => {
+            let count: i64 = row.try_get(0)?;
+            Ok(count > 0)
+        }
+        _ => {
+            let exists: bool = row.try_get(0)?;
+            Ok(exists)
+        }
+    }
+}
+
+/// Returns the current columns of a table, on any connection.
+pub(crate) async fn table_columns_on<C: Connection>(conn: &C, table_name: &str) -> Result<Vec<String>, Error> {
+    let query = match conn.driver() {
+        Drivers::Postgres => "SELECT column_name::TEXT FROM information_schema.columns WHERE table_name = $1 AND table_schema = 'public'".to_string(),
+        Drivers::MySQL => "SELECT column_name FROM information_schema.columns WHERE table_name = ? AND table_schema = DATABASE()".to_string(),
+        Drivers::SQLite => format!("PRAGMA table_info(\"{}\")", table_name),
+    };
+
+    let mut args = AnyArguments::default();
+    if conn.driver() != Drivers::SQLite {
+        let _ = args.add(table_name.to_string());
+    }
+    let rows = conn.fetch_all(&query, args).await?;
+
+    let mut columns = Vec::new();
+    for row in rows {
+        let col_name: String = if let Drivers::SQLite = conn.driver() {
+            row.try_get("name")?
+        } else {
+            row.try_get(0)?
+        };
+        columns.push(col_name);
+    }
+    Ok(columns)
+}
+
+/// Returns the current indexes of a table, on any connection.
+pub(crate) async fn table_indexes_on<C: Connection>(conn: &C, table_name: &str) -> Result<Vec<String>, Error> {
+    let query = match conn.driver() {
+        Drivers::Postgres => "SELECT indexname::TEXT FROM pg_indexes WHERE tablename = $1 AND schemaname = 'public'".to_string(),
+        Drivers::MySQL => "SELECT INDEX_NAME FROM information_schema.STATISTICS WHERE TABLE_NAME = ? AND TABLE_SCHEMA = DATABASE()".to_string(),
+        Drivers::SQLite => format!("PRAGMA index_list(\"{}\")", table_name),
+    };
+
+    let mut args = AnyArguments::default();
+    if conn.driver() != Drivers::SQLite {
+        let _ = args.add(table_name.to_string());
+    }
+    let rows = conn.fetch_all(&query, args).await?;
+
+    let mut indexes = Vec::new();
+    for row in rows {
+        let idx_name: String = if let Drivers::SQLite = conn.driver() {
+            row.try_get("name")?
+        } else {
+            row.try_get(0)?
+        };
+        indexes.push(idx_name);
+    }
+    Ok(indexes)
+}
+
+/// Returns the labels of a PostgreSQL enum type in declaration order, empty when the type does not exist.
+pub(crate) async fn enum_labels_on<C: Connection>(conn: &C, type_name: &str) -> Result<Vec<String>, Error> {
+    let query = "SELECT e.enumlabel::TEXT FROM pg_enum e JOIN pg_type t ON t.oid = e.enumtypid \
+                 WHERE t.typname = $1 ORDER BY e.enumsortorder";
+    let mut args = AnyArguments::default();
+    let _ = args.add(type_name.trim_matches('"').to_string());
+    let rows = conn.fetch_all(query, args).await?;
+    rows.iter().map(|row| row.try_get::<String, _>(0).map_err(Error::from)).collect()
+}
+
+/// Builds the `ALTER TYPE ... ADD VALUE` statements for the labels of `T`'s native enums
+/// that their existing PostgreSQL types lack, keeping the declared order.
+///
+/// Types that do not exist yet are left to `CREATE TYPE`.
+async fn enum_type_updates_on<T: Model, C: Connection>(conn: &C) -> Result<Vec<String>, Error> {
+    let mut statements = Vec::new();
+    if conn.driver() != Drivers::Postgres {
+        return Ok(statements);
+    }
+
+    for col in T::columns() {
+        let Some(variants) = col.pg_enum else { continue };
+        let existing = enum_labels_on(conn, col.sql_type).await?;
+        if existing.is_empty() {
+            continue;
+        }
+        for (i, label) in variants.iter().enumerate() {
+            if existing.iter().any(|e| e == label) {
+                continue;
+            }
+            let mut statement =
+                format!("ALTER TYPE {} ADD VALUE IF NOT EXISTS '{}'", col.sql_type, label.replace('\'', "''"));
+            if let Some(previous) = i.checked_sub(1).map(|p| variants[p]) {
+                statement.push_str(&format!(" AFTER '{}'", previous.replace('\'', "''")));
+            }
+            statements.push(statement);
+        }
+    }
+    Ok(statements)
+}
+
+/// Returns the snake_case form of `table_name` when it differs from the name itself.
+fn snake_case_fallback(table_name: &str) -> Option<String> {
+    let snake = table_name.to_snake_case();
+    (snake != table_name).then_some(snake)
+}
+
+/// Returns whether `s` is a plain SQL number: an optional sign, digits, an optional
+/// fraction and an optional exponent. Words such as `nan` or `infinity` are not numbers.
+fn is_numeric_literal(s: &str) -> bool {
+    let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((m, e)) => (m, Some(e.strip_prefix(['+', '-']).unwrap_or(e))),
+        None => (unsigned, None),
+    };
+    let (int_part, frac_part) = mantissa.split_once('.').map_or((mantissa, None), |(i, f)| (i, Some(f)));
+    let digits = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+
+    digits(int_part) && frac_part.is_none_or(digits) && exponent.is_none_or(digits)
+}
+
+/// Returns the indexes Bottle creates for `T`, named as `sync_table` names them.
+fn model_index_names<T: Model>() -> Vec<String> {
+    let table_name = T::table_name();
+    let mut names = Vec::new();
+    for col in T::columns() {
+        let col_name_clean = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
+        if col.unique && !col.is_primary_key {
+            names.push(format!("unique_{}_{}", table_name, col_name_clean));
+        } else if col.index && !col.is_primary_key {
+            names.push(format!("idx_{}_{}", table_name, col_name_clean));
+        }
+    }
+    names.extend(T::indexes().iter().map(|index| index.name(table_name)));
+    names
+}
+
+/// Computes the [`SchemaDiff`] between `T` and its table, reading the schema through `conn`.
+pub(crate) async fn diff_table_on<T: Model, C: Connection>(conn: &C) -> Result<SchemaDiff, Error> {
+    let table_name = T::table_name();
+    let model_columns: Vec<String> =
+        T::columns().iter().map(|c| c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case()).collect();
+    let model_indexes = model_index_names::<T>();
+
+    if !table_exists_on(conn, table_name).await? {
+        return Ok(SchemaDiff {
+            table_exists: false,
+            missing_columns: model_columns,
+            extra_columns: Vec::new(),
+            missing_indexes: model_indexes,
+            extra_indexes: Vec::new(),
+        });
+    }
+
+    let existing_columns = table_columns_on(conn, table_name).await?;
+    let existing_indexes = table_indexes_on(conn, table_name).await?;
+    // Only indexes following Bottle's naming are ours to report as extra
+    let managed_prefixes = [format!("idx_{}_", table_name), format!("unique_{}_", table_name)];
+
+    Ok(SchemaDiff {
+        table_exists: true,
+        missing_columns: model_columns.iter().filter(|c| !existing_columns.contains(c)).cloned().collect(),
+        extra_columns: existing_columns.iter().filter(|c| !model_columns.contains(c)).cloned().collect(),
+        missing_indexes: model_indexes.iter().filter(|i| !existing_indexes.contains(i)).cloned().collect(),
+        extra_indexes: existing_indexes
+            .iter()
+            .filter(|i| managed_prefixes.iter().any(|p| i.starts_with(p.as_str())) && !model_indexes.contains(i))
+            .cloned()
+            .collect(),
+    })
+}
+
+/// Runs the statements of [`sync_table_sql_on`] on `conn`.
+pub(crate) async fn sync_table_on<T: Model, C: Connection>(conn: &C) -> Result<(), Error> {
+    for statement in sync_table_sql_on::<T, C>(conn).await? {
+        conn.execute(&statement, AnyArguments::default()).await?;
+    }
+
+    Ok(())
+}
+
+/// Computes the statements that bring the table of `T` up to date, reading the schema through `conn`.
+pub(crate) async fn sync_table_sql_on<T: Model, C: Connection>(conn: &C) -> Result<Vec<String>, Error> {
+    let db = conn.clone_db();
+    // CREATE TYPE is skipped for a type that already exists, so new labels are added separately
+    let mut statements = enum_type_updates_on::<T, C>(conn).await?;
+    if !table_exists_on(conn, T::table_name()).await? {
+        statements.extend(db.create_table_sql::<T>()?);
+        return Ok(statements);
+    }
+
+    let table_name = T::table_name();
+    let model_columns = T::columns();
+    let existing_columns = table_columns_on(conn, table_name).await?;
+    let existing_indexes = table_indexes_on(conn, table_name).await?;
+
+    for col in model_columns {
+        let col_name_clean = col.name.strip_prefix("r#").unwrap_or(col.name).to_snake_case();
+        if !existing_columns.contains(&col_name_clean) {
+            if matches!(db.driver, Drivers::Postgres)
+                && let Some(create_type) = col.create_enum_type_sql()
+            {
+                statements.push(create_type);
+            }
+
+            let mut alter_query = format!(
+                "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {}",
+                table_name,
+                col_name_clean,
+                col.sql_type_for(db.driver)
+            );
+            if let Some(default) = col.default {
+                alter_query.push_str(" DEFAULT ");
+                alter_query.push_str(&db.format_default(default));
+            } else if !col.is_nullable {
+                alter_query.push_str(" DEFAULT ");
+                match (col.sql_type, col.pg_enum) {
+                    // '' is not a label of the enum type, so fall back to its first one
+                    (_, Some([first, ..])) => alter_query.push_str(&format!("'{}'", first.replace('\'', "''"))),
+                    ("INTEGER" | "INT" | "BIGINT", _) => alter_query.push('0'),
+                    ("BOOLEAN" | "BOOL", _) => alter_query.push_str("FALSE"),
+                    _ => alter_query.push_str("''"),
+                }
+            }
+            statements.push(alter_query);
+        }
+
+        if col.index || col.unique {
+            let idx_name = format!("idx_{}_{}", table_name, col_name_clean);
+            let uniq_name = format!("unique_{}_{}", table_name, col_name_clean);
+
+            if col.unique && !existing_indexes.contains(&uniq_name) {
+                let mut query = format!("CREATE UNIQUE INDEX \"{}\" ON \"{}\" (\"{}\")", uniq_name, table_name, col_name_clean);
+                if matches!(db.driver, Drivers::SQLite) {
+                    query = format!("CREATE UNIQUE INDEX IF NOT EXISTS \"{}\" ON \"{}\" (\"{}\")", uniq_name, table_name, col_name_clean);
+                }
+                statements.push(query);
+            } else if col.index && !existing_indexes.contains(&idx_name) && !col.unique {
+                let mut query = format!("CREATE INDEX \"{}\" ON \"{}\" (\"{}\")", idx_name, table_name, col_name_clean);
+                if matches!(db.driver, Drivers::SQLite) {
+                    query = format!("CREATE INDEX IF NOT EXISTS \"{}\" ON \"{}\" (\"{}\")", idx_name, table_name, col_name_clean);
+                }
+                statements.push(query);
+            }
+        }
+    }
 
-        let pool = sqlx::any::AnyPoolOptions::new().max_connections(self.max_connections).connect(url).await?;
-        let driver = if url.starts_with("postgres") { Drivers::Postgres }
-                    else if url.starts_with("mysql") { Drivers::MySQL }
-                    else { Drivers::SQLite };
-        Ok(Database { pool, driver })
+    for index in T::indexes() {
+        let name = index.name(table_name);
+        if !existing_indexes.contains(&name) {
+            statements.push(format!(
+                "CREATE {}INDEX {}\"{}\" ON \"{}\" ({})",
+                if index.unique { "UNIQUE " } else { "" },
+                if matches!(db.driver, Drivers::SQLite) { "IF NOT EXISTS " } else { "" },
+                name,
+                table_name,
+                index.columns.iter().map(|c| format!("\"{}\"", c)).collect::<Vec<_>>().join(", ")
+            ));
+        }
+    }
+
+    Ok(statements)
+}
+
+/// Validates and adds the foreign keys of `T` on `conn`.
+///
+/// Constraints that cannot be added (usually because they already exist) are
+/// skipped. Inside a PostgreSQL transaction each one runs under a savepoint, since
+/// a failed statement would otherwise abort the whole transaction.
+pub(crate) async fn assign_foreign_keys_on<T: Model, C: Connection>(conn: &C) -> Result<(), Error> {
+    for col in T::columns() {
+        if let (Some(f_table), Some(f_key)) = (col.foreign_table, col.foreign_key) {
+            let target_table = f_table.to_string();
+            let target_column = f_key.to_snake_case();
+            if !table_columns_on(conn, &target_table).await?.contains(&target_column) {
+                return Err(Error::InvalidForeignKey { table: target_table, column: target_column });
+            }
+        }
+    }
+
+    for (constraint_name, query) in conn.clone_db().foreign_key_constraints::<T>()? {
+        if foreign_key_exists_on(conn, T::table_name(), &constraint_name).await? {
+            continue;
+        }
+        conn.execute(&query, AnyArguments::default()).await?;
     }
+    Ok(())
+}
+
+/// Returns whether `table_name` already has a foreign key constraint named `constraint_name`.
+async fn foreign_key_exists_on<C: Connection>(conn: &C, table_name: &str, constraint_name: &str) -> Result<bool, Error> {
+    let query = match conn.driver() {
+        Drivers::Postgres => {
+            "SELECT COUNT(*) FROM information_schema.table_constraints WHERE table_name = $1 AND constraint_name = $2 AND constraint_type = 'FOREIGN KEY' AND table_schema = 'public'"
+        }
+        _ => {
+            "SELECT COUNT(*) FROM information_schema.table_constraints WHERE table_name = ? AND constraint_name = ? AND constraint_type = 'FOREIGN KEY' AND table_schema = DATABASE()"
+        }
+    };
+
+    let mut args = AnyArguments::default();
+    let _ = args.add(table_name.to_string());
+    let _ = args.add(constraint_name.to_string());
+    let row = conn.fetch_one(query, args).await?;
+    Ok(row.try_get::<i64, _>(0)? > 0)
 }
 
 // ============================================================================
 // Connection Trait
 // ============================================================================
 
+/// Bumps the executed statement counter when counting is enabled.
+pub(crate) fn record_query(counter: &Option<Arc<AtomicU64>>) {
+    if let Some(counter) = counter {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Signature of the callback registered with [`DatabaseBuilder::on_query`].
+type QueryCallback = dyn Fn(&str, Duration) + Send + Sync;
+
+/// Callback registered with [`DatabaseBuilder::on_query`].
+#[derive(Clone)]
+pub(crate) struct QueryHook(Arc<QueryCallback>);
+
+impl std::fmt::Debug for QueryHook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("QueryHook")
+    }
+}
+
+/// Runs a statement future and reports its SQL and duration to the hook, if any.
+pub(crate) async fn observe<F, T>(hook: &Option<QueryHook>, sql: &str, statement: F) -> Result<T, sqlx::Error>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    let Some(hook) = hook else {
+        return statement.await;
+    };
+    let started = Instant::now();
+    let result = statement.await;
+    (hook.0)(sql, started.elapsed());
+    result
+}
+
+/// Wraps a row stream so the hook, if any, is called once the stream ends.
+pub(crate) fn observe_stream<'a>(
+    hook: Option<QueryHook>,
+    sql: &'a str,
+    rows: BoxStream<'a, Result<sqlx::any::AnyRow, sqlx::Error>>,
+) -> BoxStream<'a, Result<sqlx::any::AnyRow, sqlx::Error>> {
+    let Some(hook) = hook else {
+        return rows;
+    };
+    let started = Instant::now();
+    let finished = futures::stream::once(async move {
+        (hook.0)(sql, started.elapsed());
+        None
+    });
+    rows.map(Some).chain(finished).filter_map(futures::future::ready).boxed()
+}
+
+/// Runs a statement future, failing it once `timeout` elapses.
+pub(crate) async fn with_timeout<F, T>(timeout: Option<Duration>, statement: F) -> Result<T, sqlx::Error>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    let Some(timeout) = timeout else {
+        return statement.await;
+    };
+    tokio::time::timeout(timeout, statement).await.unwrap_or_else(|_| {
+        Err(sqlx::Error::Io(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            format!("query timed out after {:?}", timeout),
+        )))
+    })
+}
+
+/// Runs a statement on one SQLite connection and reads `last_insert_rowid()` after it.
+///
+/// `last_insert_rowid()` keeps the value of the connection's last successful insert,
+/// so it is read before and after the statement and only reported when it moved. This
+/// covers any statement that inserts rows (`INSERT`, `REPLACE`, `WITH ... INSERT`) without
+/// reporting a stale id after an `UPDATE` or `DELETE`. An insert that happens to reuse the
+/// previous rowid (for example into another table) is reported as `None`.
+pub(crate) async fn sqlite_execute_with_rowid(
+    conn: &mut sqlx::AnyConnection,
+    sql: &str,
+    args: AnyArguments<'_>,
+) -> Result<(sqlx::any::AnyQueryResult, Option<i64>), sqlx::Error> {
+    let before: i64 = sqlx::query_scalar("SELECT last_insert_rowid()").fetch_one(&mut *conn).await?;
+    let result = sqlx::query_with(sql, args).execute(&mut *conn).await?;
+    if result.rows_affected() == 0 {
+        return Ok((result, None));
+    }
+    let rowid: i64 = sqlx::query_scalar("SELECT last_insert_rowid()").fetch_one(&mut *conn).await?;
+    Ok((result, (rowid != before).then_some(rowid)))
+}
+
+/// Turns a producer future into a stream of the items it sends.
+///
+/// Lets a stream own state that the rows borrow from, such as a locked
+/// transaction: the producer keeps that state alive and pushes items through a
+/// one-slot channel, and it is polled only while the stream is polled, so at most
+/// one item is buffered. Dropping the stream drops the producer.
+pub(crate) fn channel_stream<'a, T, F>(produce: impl FnOnce(mpsc::Sender<T>) -> F) -> BoxStream<'a, T>
+where
+    T: Send + 'a,
+    F: Future<Output = ()> + Send + 'a,
+{
+    let (sender, receiver) = mpsc::channel(0);
+    let producer = produce(sender).into_stream().map(|()| None);
+    futures::stream::select(receiver.map(Some), producer).filter_map(futures::future::ready).boxed()
+}
+
 pub trait Connection: Send + Sync {
     fn driver(&self) -> Drivers;
     fn execute<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<sqlx::any::AnyQueryResult, sqlx::Error>>;
@@ -396,38 +1599,159 @@ pub trait Connection: Send + Sync {
     fn fetch_one<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<sqlx::any::AnyRow, sqlx::Error>>;
     fn fetch_optional<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<Option<sqlx::any::AnyRow>, sqlx::Error>>;
     fn clone_db(&self) -> Database;
+
+    /// Returns the result cache used by `QueryBuilder::cache`, if this connection has one.
+    fn query_cache(&self) -> Option<&QueryCache> {
+        None
+    }
+
+    /// Drops cached reads of `table` after a write made through this connection.
+    ///
+    /// Transactions defer this until they commit.
+    fn invalidate_cached(&self, table: &str) {
+        if let Some(cache) = self.query_cache() {
+            cache.invalidate(table);
+        }
+    }
+
+    /// Returns whether statements run inside an open transaction.
+    fn in_transaction(&self) -> bool {
+        false
+    }
+
+    /// Runs a query and yields its rows one at a time as they arrive.
+    ///
+    /// The default fetches every row first; `Database` and `Transaction` stream them.
+    fn fetch_stream<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxStream<'a, Result<sqlx::any::AnyRow, sqlx::Error>> {
+        futures::stream::once(self.fetch_all(sql, args))
+            .flat_map(|rows| match rows {
+                Ok(rows) => futures::stream::iter(rows.into_iter().map(Ok)).left_stream(),
+                Err(e) => futures::stream::once(futures::future::ready(Err(e))).right_stream(),
+            })
+            .boxed()
+    }
+
+    /// Executes a statement and returns its result with the id generated by the last insert.
+    ///
+    /// The Any driver drops SQLite's rowid, so the SQLite implementations read
+    /// `last_insert_rowid()` on the same connection right after the statement.
+    fn execute_with_insert_id<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<(sqlx::any::AnyQueryResult, Option<i64>), sqlx::Error>> {
+        Box::pin(async move {
+            let result = self.execute(sql, args).await?;
+            let id = result.last_insert_id();
+            Ok((result, id))
+        })
+    }
+
+    /// Replaces the statement time limit on this handle; `None` disables it.
+    ///
+    /// Used for per-query overrides, so it only affects the handle it is called on.
+    fn set_query_timeout(&mut self, _timeout: Option<Duration>) {}
 }
 
 impl Connection for Database {
     fn driver(&self) -> Drivers { self.driver }
     fn execute<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<sqlx::any::AnyQueryResult, sqlx::Error>> {
-        Box::pin(async move { sqlx::query_with(sql, args).execute(&self.pool).await })
+        Box::pin(async move {
+            record_query(&self.query_counter);
+            observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).execute(&self.pool))).await
+        })
     }
     fn fetch_all<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<Vec<sqlx::any::AnyRow>, sqlx::Error>> {
-        Box::pin(async move { sqlx::query_with(sql, args).fetch_all(&self.pool).await })
+        Box::pin(async move {
+            record_query(&self.query_counter);
+            observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).fetch_all(&self.pool))).await
+        })
     }
     fn fetch_one<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<sqlx::any::AnyRow, sqlx::Error>> {
-        Box::pin(async move { sqlx::query_with(sql, args).fetch_one(&self.pool).await })
+        Box::pin(async move {
+            record_query(&self.query_counter);
+            observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).fetch_one(&self.pool))).await
+        })
     }
     fn fetch_optional<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<Option<sqlx::any::AnyRow>, sqlx::Error>> {
-        Box::pin(async move { sqlx::query_with(sql, args).fetch_optional(&self.pool).await })
+        Box::pin(async move {
+            record_query(&self.query_counter);
+            observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).fetch_optional(&self.pool))).await
+        })
+    }
+    fn fetch_stream<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxStream<'a, Result<sqlx::any::AnyRow, sqlx::Error>> {
+        record_query(&self.query_counter);
+        observe_stream(self.on_query.clone(), sql, sqlx::query_with(sql, args).fetch(&self.pool))
+    }
+    fn execute_with_insert_id<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<(sqlx::any::AnyQueryResult, Option<i64>), sqlx::Error>> {
+        Box::pin(async move {
+            if self.driver != Drivers::SQLite {
+                let result = self.execute(sql, args).await?;
+                let id = result.last_insert_id();
+                return Ok((result, id));
+            }
+            record_query(&self.query_counter);
+            let mut conn = self.pool.acquire().await?;
+            observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlite_execute_with_rowid(&mut conn, sql, args))).await
+        })
     }
     fn clone_db(&self) -> Database { self.clone() }
+    fn query_cache(&self) -> Option<&QueryCache> { Some(&self.cache) }
+    fn set_query_timeout(&mut self, timeout: Option<Duration>) { self.query_timeout = timeout; }
 }
 
 // ============================================================================
 // Raw SQL Query Builder
 // ============================================================================
 
+/// The difference between a model and the live schema of its table.
+///
+/// Returned by [`Database::diff_table`]. Column names are in their snake_cased
+/// database form; index names follow the `idx_{table}_{column}` and
+/// `unique_{table}_{column}` scheme used by `sync_table`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDiff {
+    /// Whether the table exists at all
+    pub table_exists: bool,
+    /// Columns declared by the model but absent from the table
+    pub missing_columns: Vec<String>,
+    /// Columns of the table the model does not declare
+    pub extra_columns: Vec<String>,
+    /// Indexes declared by the model but absent from the table
+    pub missing_indexes: Vec<String>,
+    /// Bottle-named indexes on the table the model no longer declares
+    pub extra_indexes: Vec<String>,
+}
+
+impl SchemaDiff {
+    /// Returns whether the table matches the model.
+    pub fn is_empty(&self) -> bool {
+        self.table_exists
+            && self.missing_columns.is_empty()
+            && self.extra_columns.is_empty()
+            && self.missing_indexes.is_empty()
+            && self.extra_indexes.is_empty()
+    }
+}
+
+/// Metadata returned by [`RawQuery::execute_result`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecuteResult {
+    /// Number of rows inserted, updated or deleted
+    pub rows_affected: u64,
+    /// Key generated by the last `INSERT`, on drivers that report it (MySQL and SQLite)
+    pub last_insert_id: Option<i64>,
+}
+
+/// Adds a named value to the arguments, once per placeholder that references it.
+type NamedBind<'a> = Box<dyn Fn(&mut AnyArguments<'a>) + Send + Sync + 'a>;
+
 pub struct RawQuery<'a, C> {
     conn: C,
     sql: &'a str,
     args: AnyArguments<'a>,
+    named: Vec<(String, NamedBind<'a>)>,
 }
 
 impl<'a, C> RawQuery<'a, C> where C: Connection {
     pub(crate) fn new(conn: C, sql: &'a str) -> Self {
-        Self { conn, sql, args: AnyArguments::default() }
+        Self { conn, sql, args: AnyArguments::default(), named: Vec::new() }
     }
 
     /// Binds a value to the SQL query.
@@ -449,6 +1773,69 @@ impl<'a, C> RawQuery<'a, C> where C: Connection {
         self
     }
 
+    /// Binds a value to every `:name` placeholder in the SQL query.
+    ///
+    /// Placeholders are rewritten to the driver's positional form in order of
+    /// appearance, so a name used several times is bound once. `::` casts and text
+    /// inside quotes are left alone. Named and positional binds cannot be mixed, and
+    /// a placeholder without a value (or a value without a placeholder) is reported
+    /// as `Error::InvalidArgument` when the query runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let posts: Vec<Post> = db.raw("SELECT * FROM posts WHERE author_id = :id OR reviewer_id = :id")
+    ///     .bind_named("id", 7)
+    ///     .fetch_all()
+    ///     .await?;
+    /// ```
+    pub fn bind_named<T>(mut self, name: &str, value: T) -> Self
+    where
+        T: 'a + sqlx::Encode<'a, sqlx::Any> + sqlx::Type<sqlx::Any> + Clone + Send + Sync,
+    {
+        let name = name.strip_prefix(':').unwrap_or(name).to_string();
+        self.named.retain(|(bound, _)| *bound != name);
+        self.named.push((name, Box::new(move |args: &mut AnyArguments<'a>| {
+            let _ = args.add(value.clone());
+        })));
+        self
+    }
+
+    /// Returns the SQL and arguments to run, resolving named placeholders if any were bound.
+    fn into_statement(self) -> Result<(C, Cow<'a, str>, AnyArguments<'a>), Error> {
+        if self.named.is_empty() {
+            return Ok((self.conn, Cow::Borrowed(self.sql), self.args));
+        }
+        if self.args.len() > 0 {
+            return Err(Error::InvalidArgument("Raw queries cannot mix bind and bind_named".to_string()));
+        }
+
+        let (sql, order) = rewrite_named_params(self.sql, self.conn.driver());
+        if let Some((unused, _)) = self.named.iter().find(|(name, _)| !order.contains(name)) {
+            return Err(Error::InvalidArgument(format!("Named parameter ':{}' is not used in the query", unused)));
+        }
+        let mut args = AnyArguments::default();
+        for name in &order {
+            let Some((_, bind)) = self.named.iter().find(|(bound, _)| bound == name) else {
+                return Err(Error::InvalidArgument(format!("No value bound for named parameter ':{}'", name)));
+            };
+            bind(&mut args);
+        }
+        Ok((self.conn, Cow::Owned(sql), args))
+    }
+
+    /// Sets the time limit for this statement, overriding the database default.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.raw("VACUUM").timeout(Duration::from_secs(300)).execute().await?;
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.conn.set_query_timeout(Some(timeout));
+        self
+    }
+
     /// Executes the query and returns all matching rows.
     ///
     /// # Type Parameters
@@ -463,7 +1850,8 @@ impl<'a, C> RawQuery<'a, C> where C: Connection {
     ///     .await?;
     /// ```
     pub async fn fetch_all<T>(self) -> Result<Vec<T>, Error> where T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin {
-        let rows = self.conn.fetch_all(self.sql, self.args).await?;
+        let (conn, sql, args) = self.into_statement()?;
+        let rows = conn.fetch_all(&sql, args).await?;
         Ok(rows.iter().map(|r| T::from_row(r)).collect::<Result<Vec<_>, _>>()?)
     }
 
@@ -481,7 +1869,8 @@ impl<'a, C> RawQuery<'a, C> where C: Connection {
     ///     .await?;
     /// ```
     pub async fn fetch_one<T>(self) -> Result<T, Error> where T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin {
-        let row = self.conn.fetch_one(self.sql, self.args).await?;
+        let (conn, sql, args) = self.into_statement()?;
+        let row = conn.fetch_one(&sql, args).await?;
         Ok(T::from_row(&row)?)
     }
 
@@ -499,10 +1888,55 @@ impl<'a, C> RawQuery<'a, C> where C: Connection {
     ///     .await?;
     /// ```
     pub async fn fetch_optional<T>(self) -> Result<Option<T>, Error> where T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin {
-        let row = self.conn.fetch_optional(self.sql, self.args).await?;
+        let (conn, sql, args) = self.into_statement()?;
+        let row = conn.fetch_optional(&sql, args).await?;
         Ok(row.map(|r| T::from_row(&r)).transpose()?)
     }
 
+    /// Executes the query and yields its rows one at a time as they arrive.
+    ///
+    /// Rows are decoded into `T` as they are read, so memory stays bounded for large
+    /// exports. Like [`QueryBuilder::scan_stream`], the stream keeps its connection
+    /// busy until it is finished or dropped (locking the transaction on a
+    /// `Transaction`), and query timeouts do not apply.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type to map each row to.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut rows = db.raw("SELECT day, SUM(total) FROM orders GROUP BY day").fetch_stream::<(String, i64)>();
+    /// while let Some((day, total)) = rows.try_next().await? {
+    ///     writer.write_record(&[day, total.to_string()])?;
+    /// }
+    /// ```
+    pub fn fetch_stream<T>(self) -> BoxStream<'a, Result<T, Error>>
+    where
+        T: for<'r> sqlx::FromRow<'r, sqlx::any::AnyRow> + Send + Unpin + 'a,
+        C: 'a,
+    {
+        channel_stream(move |mut sender| async move {
+            let (conn, sql, args) = match self.into_statement() {
+                Ok(statement) => statement,
+                Err(e) => {
+                    let _ = sender.send(Err(e)).await;
+                    return;
+                }
+            };
+            let mut rows = conn.fetch_stream(&sql, args);
+            while let Some(row) = rows.next().await {
+                let item = row.and_then(|row| T::from_row(&row)).map_err(Error::from);
+                if sender.send(item).await.is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
     /// Executes the query and returns the number of affected rows.
     ///
     /// Useful for UPDATE, DELETE or INSERT queries.
@@ -515,7 +1949,108 @@ impl<'a, C> RawQuery<'a, C> where C: Connection {
     ///     .await?;
     /// ```
     pub async fn execute(self) -> Result<u64, Error> {
-        let result = self.conn.execute(self.sql, self.args).await?;
+        let (conn, sql, args) = self.into_statement()?;
+        let result = conn.execute(&sql, args).await?;
         Ok(result.rows_affected())
     }
+
+    /// Executes the query and returns the affected row count with the last insert id.
+    ///
+    /// `last_insert_id` is filled on MySQL and SQLite after an `INSERT` into a table
+    /// with an auto-increment key. PostgreSQL does not report it; use a `RETURNING`
+    /// clause with `fetch_one` there instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = db.raw("INSERT INTO users (name) VALUES (?)")
+    ///     .bind("alice")
+    ///     .execute_result()
+    ///     .await?;
+    /// let id = result.last_insert_id;
+    /// ```
+    pub async fn execute_result(self) -> Result<ExecuteResult, Error> {
+        let (conn, sql, args) = self.into_statement()?;
+        let (result, last_insert_id) = conn.execute_with_insert_id(&sql, args).await?;
+        Ok(ExecuteResult { rows_affected: result.rows_affected(), last_insert_id })
+    }
+}
+
+/// Rewrites `:name` placeholders to the positional form of `driver`.
+///
+/// Returns the rewritten SQL and the names to bind, in argument order. PostgreSQL
+/// reuses one `$n` per distinct name; MySQL and SQLite get a `?` (and a bind) per
+/// occurrence. Quoted text, `--` and `/* */` comments (and `#` comments on MySQL),
+/// PostgreSQL `$$` / `$tag$` bodies and `::` casts are copied unchanged.
+fn rewrite_named_params(sql: &str, driver: Drivers) -> (String, Vec<String>) {
+    let mut out = String::with_capacity(sql.len());
+    let mut order: Vec<String> = Vec::new();
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let verbatim_end = match bytes[i] {
+            q @ (b'\'' | b'"' | b'`') => Some(sql[i + 1..].find(q as char).map_or(sql.len(), |end| i + end + 2)),
+            b'-' if bytes.get(i + 1) == Some(&b'-') => Some(line_end(sql, i)),
+            b'#' if driver == Drivers::MySQL => Some(line_end(sql, i)),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => Some(sql[i + 2..].find("*/").map_or(sql.len(), |end| i + end + 4)),
+            b'$' if driver == Drivers::Postgres => dollar_quote_end(sql, i),
+            _ => None,
+        };
+        if let Some(end) = verbatim_end {
+            out.push_str(&sql[i..end]);
+            i = end;
+            continue;
+        }
+
+        if bytes[i] == b':' && bytes.get(i + 1) == Some(&b':') {
+            out.push_str("::");
+            i += 2;
+        } else if bytes[i] == b':' && bytes.get(i + 1).is_some_and(|n| n.is_ascii_alphabetic() || *n == b'_') {
+            let len = sql[i + 1..].find(|n: char| !(n.is_ascii_alphanumeric() || n == '_')).unwrap_or(sql.len() - i - 1);
+            let name = sql[i + 1..i + 1 + len].to_string();
+            i += len + 1;
+            match driver {
+                Drivers::Postgres => {
+                    let index = match order.iter().position(|bound| *bound == name) {
+                        Some(index) => index,
+                        None => {
+                            order.push(name);
+                            order.len() - 1
+                        }
+                    };
+                    out.push_str(&format!("${}", index + 1));
+                }
+                _ => {
+                    order.push(name);
+                    out.push('?');
+                }
+            }
+        } else {
+            let len = sql[i..].chars().next().map_or(1, char::len_utf8);
+            out.push_str(&sql[i..i + len]);
+            i += len;
+        }
+    }
+
+    (out, order)
+}
+
+/// Returns the index just past the end of the line containing `start`.
+fn line_end(sql: &str, start: usize) -> usize {
+    sql[start..].find('\n').map_or(sql.len(), |end| start + end + 1)
+}
+
+/// Returns the end of the PostgreSQL dollar-quoted string opening at `start`, if
+/// `start` opens one (`$$` or `$tag$`; `$1` does not).
+fn dollar_quote_end(sql: &str, start: usize) -> Option<usize> {
+    let tag_len = sql[start + 1..].find('$')?;
+    let tag = &sql[start + 1..start + 1 + tag_len];
+    let is_tag = tag.chars().enumerate().all(|(i, c)| c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()));
+    if !is_tag {
+        return None;
+    }
+    let delimiter = &sql[start..start + tag_len + 2];
+    let body = start + delimiter.len();
+    Some(sql[body..].find(delimiter).map_or(sql.len(), |end| body + end + delimiter.len()))
 }
@@ -0,0 +1,348 @@
+//! # Dynamic Query Module
+//!
+//! This module provides a query builder for tables that are only known by name at runtime.
+//! It is aimed at admin panels and generic tooling that cannot name a `Model` type.
+//!
+//! Columns are plain strings and rows come back as `HashMap<String, serde_json::Value>`,
+//! so no derive is required on the caller's side.
+
+// ============================================================================
+// External Crate Imports
+// ============================================================================
+
+use serde_json::Value;
+use sqlx::{any::AnyArguments, Any, Arguments, Column, Encode, Row, Type, ValueRef};
+use std::collections::HashMap;
+
+// ============================================================================
+// Internal Crate Imports
+// ============================================================================
+
+use crate::{
+    database::{table_columns_on, Connection, Drivers},
+    query_builder::{FilterFn, Op},
+    Error,
+};
+
+// ============================================================================
+// DynamicQuery Struct
+// ============================================================================
+
+/// A query builder for a table identified by its name at runtime.
+///
+/// Created with [`Database::table`](crate::Database::table) or
+/// [`Transaction::table`](crate::Transaction::table).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let rows = db.table("users")
+///     .filter("age", Op::Gte, 18)
+///     .order("id ASC")
+///     .scan_dynamic()
+///     .await?;
+///
+/// for row in rows {
+///     println!("{} -> {}", row["id"], row["username"]);
+/// }
+/// ```
+pub struct DynamicQuery<E> {
+    pub(crate) tx: E,
+    pub(crate) driver: Drivers,
+    pub(crate) table_name: String,
+    pub(crate) select_columns: Vec<String>,
+    pub(crate) filter_columns: Vec<String>,
+    pub(crate) where_clauses: Vec<FilterFn>,
+    pub(crate) order_clauses: Vec<String>,
+    pub(crate) limit: Option<usize>,
+    pub(crate) offset: Option<usize>,
+    pub(crate) debug_mode: bool,
+}
+
+// ============================================================================
+// DynamicQuery Implementation
+// ============================================================================
+
+impl<E: Connection> DynamicQuery<E> {
+    /// Creates a new dynamic query for `table_name`.
+    ///
+    /// The name may only contain ASCII letters, digits, `_` and `.` (for
+    /// `schema.table`). It is checked when the query runs, and an invalid name
+    /// fails with an error instead of reaching the database.
+    ///
+    /// Columns passed to `select`, `filter` and `order` are looked up in the table's
+    /// schema before the query runs, and an unknown column fails the same way.
+    pub fn new(tx: E, driver: Drivers, table_name: &str) -> Self {
+        Self {
+            tx,
+            driver,
+            table_name: table_name.to_string(),
+            select_columns: Vec::new(),
+            filter_columns: Vec::new(),
+            where_clauses: Vec::new(),
+            order_clauses: Vec::new(),
+            limit: None,
+            offset: None,
+            debug_mode: false,
+        }
+    }
+
+    /// Selects specific columns (comma-separated). Defaults to `*`.
+    ///
+    /// Each entry must be a column of the table, optionally written as `table.column`.
+    pub fn select(mut self, columns: &str) -> Self {
+        self.select_columns.push(columns.to_string());
+        self
+    }
+
+    /// Adds a WHERE clause comparing `col` against a bound value.
+    pub fn filter<V>(mut self, col: &str, op: Op, value: V) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.filter_columns.push(col.to_string());
+        let column = self.driver.quote_identifier(col);
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+            query.push_str(" AND ");
+
+            if !op.takes_value() {
+                query.push_str(&op.render(*driver, &column, ""));
+                return;
+            }
+            let placeholder = match driver {
+                Drivers::Postgres => {
+                    let placeholder = format!("${}", arg_counter);
+                    *arg_counter += 1;
+                    placeholder
+                }
+                _ => "?".to_string(),
+            };
+            query.push_str(&op.render(*driver, &column, &placeholder));
+
+            let _ = args.add(value.clone());
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Adds an ORDER BY clause (e.g. `"id DESC"`).
+    ///
+    /// Accepts a comma-separated list of columns, each optionally followed by
+    /// `ASC` or `DESC`.
+    pub fn order(mut self, order: &str) -> Self {
+        self.order_clauses.push(order.to_string());
+        self
+    }
+
+    /// Sets the maximum number of rows to return.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the number of rows to skip.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Logs the generated SQL at debug level.
+    pub fn debug(mut self) -> Self {
+        self.debug_mode = true;
+        self
+    }
+
+    /// Validates the table name and returns it quoted for the current driver.
+    fn quoted_table(&self) -> Result<String, sqlx::Error> {
+        if !is_valid_table_name(&self.table_name) {
+            return Err(sqlx::Error::Configuration(
+                format!(
+                    "invalid table name `{}`: only ASCII letters, digits, `_` and `.` are allowed",
+                    self.table_name
+                )
+                .into(),
+            ));
+        }
+        Ok(self.driver.quote_identifier(&self.table_name))
+    }
+
+    /// Checks the caller's columns against the table and returns the quoted select
+    /// list and ORDER BY items.
+    ///
+    /// The table's columns are only read when a column was passed to `select`,
+    /// `filter` or `order`.
+    async fn checked_columns(&self) -> Result<(String, Vec<String>), sqlx::Error> {
+        let mut orders = Vec::new();
+        for item in self.order_clauses.iter().flat_map(|o| o.split(',')) {
+            let mut parts = item.split_whitespace();
+            let (Some(column), dir, None) = (parts.next(), parts.next(), parts.next()) else {
+                return Err(sqlx::Error::Configuration(
+                    format!("invalid ORDER BY expression `{}`", item.trim()).into(),
+                ));
+            };
+            let dir = match dir.map(|d| d.to_ascii_uppercase()).as_deref() {
+                None => "",
+                Some("ASC") => " ASC",
+                Some("DESC") => " DESC",
+                _ => {
+                    return Err(sqlx::Error::Configuration(
+                        format!("invalid ORDER BY expression `{}`", item.trim()).into(),
+                    ));
+                }
+            };
+            orders.push((column, dir));
+        }
+        let selects: Vec<&str> = self.select_columns.iter().flat_map(|s| s.split(',')).map(str::trim).collect();
+
+        let referenced: Vec<&str> = self
+            .filter_columns
+            .iter()
+            .map(String::as_str)
+            .chain(selects.iter().copied())
+            .chain(orders.iter().map(|(column, _)| *column))
+            .collect();
+        if !referenced.is_empty() {
+            // `schema.table` is looked up by its table part
+            let table = self.table_name.rsplit('.').next().unwrap_or_default();
+            let known = table_columns_on(&self.tx, table).await.map_err(|e| match e {
+                Error::DatabaseError(e) => e,
+                other => sqlx::Error::Configuration(other.to_string().into()),
+            })?;
+            for column in referenced {
+                let (qualifier, name) = match column.split_once('.') {
+                    Some((qualifier, name)) => (Some(qualifier), name),
+                    None => (None, column),
+                };
+                if qualifier.is_some_and(|q| q != table) || !known.iter().any(|k| k == name) {
+                    return Err(sqlx::Error::Configuration(
+                        format!("unknown column `{}` for table `{}`", column, self.table_name).into(),
+                    ));
+                }
+            }
+        }
+
+        let select = if selects.is_empty() {
+            "*".to_string()
+        } else {
+            selects.iter().map(|c| self.driver.quote_identifier(c)).collect::<Vec<_>>().join(", ")
+        };
+        let order =
+            orders.iter().map(|(column, dir)| format!("{}{}", self.driver.quote_identifier(column), dir)).collect();
+        Ok((select, order))
+    }
+
+    /// Executes the query and returns every row as a column-name → JSON value map.
+    ///
+    /// Integers, floats, text and booleans map to the matching JSON types and SQL
+    /// `NULL` maps to `Value::Null`. Text that holds JSON is returned as a string.
+    pub async fn scan_dynamic(self) -> Result<Vec<HashMap<String, Value>>, sqlx::Error> {
+        let table = self.quoted_table()?;
+        let (select, order) = self.checked_columns().await?;
+        let mut query = format!("SELECT {} FROM {} WHERE 1=1", select, table);
+
+        let mut args = AnyArguments::default();
+        let mut arg_counter = 1;
+        for clause in &self.where_clauses {
+            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
+        }
+
+        if !order.is_empty() {
+            query.push_str(&format!(" ORDER BY {}", order.join(", ")));
+        }
+        for (keyword, value) in [(" LIMIT ", self.limit), (" OFFSET ", self.offset)] {
+            if let Some(value) = value {
+                query.push_str(keyword);
+                match self.driver {
+                    Drivers::Postgres => {
+                        query.push_str(&format!("${}", arg_counter));
+                        arg_counter += 1;
+                    }
+                    _ => query.push('?'),
+                }
+                let _ = args.add(value as i64);
+            }
+        }
+
+        if self.debug_mode {
+            log::debug!("SQL: {}", query);
+        }
+
+        let rows = self.tx.fetch_all(&query, args).await?;
+        Ok(rows.iter().map(row_to_map).collect())
+    }
+
+    /// Returns the number of rows matching the filters.
+    pub async fn count(self) -> Result<i64, sqlx::Error> {
+        let mut query = format!("SELECT COUNT(*) FROM {} WHERE 1=1", self.quoted_table()?);
+        self.checked_columns().await?;
+        let mut args = AnyArguments::default();
+        let mut arg_counter = 1;
+        for clause in &self.where_clauses {
+            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
+        }
+
+        if self.debug_mode {
+            log::debug!("SQL: {}", query);
+        }
+
+        let row = self.tx.fetch_one(&query, args).await?;
+        row.try_get::<i64, _>(0)
+    }
+
+    /// Deletes the rows matching the filters and returns the number affected.
+    pub async fn delete(self) -> Result<u64, sqlx::Error> {
+        let mut query = format!("DELETE FROM {} WHERE 1=1", self.quoted_table()?);
+        self.checked_columns().await?;
+        let mut args = AnyArguments::default();
+        let mut arg_counter = 1;
+        for clause in &self.where_clauses {
+            clause(&mut query, &mut args, &self.driver, &mut arg_counter);
+        }
+
+        if self.debug_mode {
+            log::debug!("SQL: {}", query);
+        }
+
+        let result = self.tx.execute(&query, args).await?;
+        Ok(result.rows_affected())
+    }
+}
+
+// ============================================================================
+// Identifier Validation
+// ============================================================================
+
+/// Returns `true` if `name` is a plain or `schema.table` identifier made of
+/// ASCII letters, digits and `_`.
+fn is_valid_table_name(name: &str) -> bool {
+    name.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+}
+
+// ============================================================================
+// Row Decoding
+// ============================================================================
+
+/// Converts a row into a column-name → JSON value map.
+fn row_to_map(row: &sqlx::any::AnyRow) -> HashMap<String, Value> {
+    let mut map = HashMap::with_capacity(row.columns().len());
+
+    for (i, column) in row.columns().iter().enumerate() {
+        let is_null = row.try_get_raw(i).map(|v| v.is_null()).unwrap_or(true);
+        let value = if is_null {
+            Value::Null
+        } else if let Ok(v) = row.try_get::<i64, _>(i) {
+            Value::from(v)
+        } else if let Ok(v) = row.try_get::<f64, _>(i) {
+            Value::from(v)
+        } else if let Ok(v) = row.try_get::<String, _>(i) {
+            Value::from(v)
+        } else if let Ok(v) = row.try_get::<bool, _>(i) {
+            Value::from(v)
+        } else {
+            Value::Null
+        };
+        map.insert(column.name().to_string(), value);
+    }
+
+    map
+}
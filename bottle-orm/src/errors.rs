@@ -9,6 +9,7 @@
 //! - **InvalidData**: Data validation errors (e.g., invalid format, constraint violations)
 //! - **DatabaseError**: Wrapped sqlx errors (connection issues, query failures, etc.)
 //! - **InvalidArgument**: Invalid arguments passed to ORM methods
+//! - **InvalidForeignKey**: A foreign key references a table or column that does not exist
 //!
 //! ## Example Usage
 //!
@@ -55,6 +56,7 @@ use thiserror::Error;
 /// * `InvalidData` - Data validation errors
 /// * `DatabaseError` - Wrapped sqlx database errors
 /// * `InvalidArgument` - Invalid arguments passed to methods
+/// * `InvalidForeignKey` - Foreign key target table/column is missing
 ///
 /// # Display Format
 ///
@@ -63,6 +65,7 @@ use thiserror::Error;
 /// - `InvalidData`: "Invalid Data {message}: {message}"
 /// - `DatabaseError`: "Database error {inner_error}:"
 /// - `InvalidArgument`: "Invalid argument {message}: {message}"
+/// - `InvalidForeignKey`: "Invalid foreign key: referenced column \"{table}\".\"{column}\" does not exist"
 ///
 /// # Example
 ///
@@ -211,6 +214,50 @@ pub enum Error {
     /// ```
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
+
+    /// Invalid foreign key error.
+    ///
+    /// Returned by `Database::assign_foreign_keys` (and therefore by the `Migrator`)
+    /// when a `#[orm(foreign_key = "Table::column")]` points at a table or column
+    /// that does not exist, instead of letting the `ALTER TABLE` fail at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match db.assign_foreign_keys::<Post>().await {
+    ///     Err(Error::InvalidForeignKey { table, column }) => {
+    ///         eprintln!("Post references missing column {}.{}", table, column);
+    ///     }
+    ///     other => other?,
+    /// }
+    /// ```
+    #[error("Invalid foreign key: referenced column \"{table}\".\"{column}\" does not exist")]
+    InvalidForeignKey {
+        /// The referenced (target) table.
+        table: String,
+        /// The referenced (target) column.
+        column: String,
+    },
+
+    /// Driver not compiled error.
+    ///
+    /// Returned by `Database::connect` when the connection URL names a database
+    /// whose driver was left out of the build. Enable the matching crate feature
+    /// (`postgres`, `mysql` or `sqlite`; all are on by default) to fix it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match Database::connect("postgres://localhost/app").await {
+    ///     Err(Error::DriverNotCompiled { driver }) => eprintln!("{:?} support is disabled", driver),
+    ///     other => { other?; }
+    /// }
+    /// ```
+    #[error("The {feature} driver is not compiled in; enable the `{feature}` feature of bottle-orm", feature = .driver.feature_name())]
+    DriverNotCompiled {
+        /// The driver the connection URL asked for.
+        driver: crate::database::Drivers,
+    },
 }
 
 // ============================================================================
@@ -237,6 +284,7 @@ pub enum Error {
 // Helper Functions and Traits
 // ============================================================================
 
+#[allow(clippy::empty_line_after_doc_comments)]
 impl Error {
     /// Creates an `InvalidData` error from a string slice.
     ///
@@ -301,4 +349,18 @@ impl Error {
     pub fn conversion(msg: &str) -> Self {
         Error::Conversion(msg.to_string())
     }
+
+    /// Returns whether the error aborted a transaction that may succeed if run again.
+    ///
+    /// Matches on the SQLSTATE: serialization failures (`40001`, which MySQL also
+    /// reports for its deadlock error 1213) and PostgreSQL deadlocks (`40P01`).
+    /// Used by `Database::transaction_with_retry`.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::DatabaseError(sqlx::Error::Database(e)) => {
+                matches!(e.code().as_deref(), Some("40001" | "40P01"))
+            }
+            _ => false,
+        }
+    }
 }
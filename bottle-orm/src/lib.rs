@@ -60,6 +60,9 @@ pub use bottle_orm_macro::Model;
 /// Re-export of the procedural macro for deriving `Display` and `FromStr` for enums.
 pub use bottle_orm_macro::BottleEnum;
 
+/// Re-export of the procedural macro for deriving `i32` conversions for enums stored as integers.
+pub use bottle_orm_macro::BottleIntEnum;
+
 /// Re-export of the procedural macro for deriving `FromRow` for `AnyRow` and `AnyImpl`.
 ///
 /// This macro facilitates scanning arbitrary query results (via `AnyRow`) into
@@ -126,6 +129,25 @@ pub mod value_binding;
 /// for easy extraction from query parameters in frameworks like Axum or Actix-web.
 pub mod pagination;
 
+/// In-memory result cache for `QueryBuilder::cache`.
+///
+/// Holds rows of repeated identical reads until their TTL expires or the table is
+/// written through the ORM.
+pub mod cache;
+
+/// Runtime-named table queries.
+///
+/// Provides `DynamicQuery`, returned by `Database::table`, for tooling that only
+/// knows a table name at runtime and reads rows as JSON value maps.
+pub mod dynamic;
+
+/// PostgreSQL range and geometric column types.
+///
+/// Provides `TsRange` (`TSTZRANGE`) and `Point` (`POINT`) for models, behind the
+/// `postgres-types` feature.
+#[cfg(feature = "postgres-types")]
+pub mod pg_types;
+
 // ============================================================================
 // Public API Re-exports
 // ============================================================================
@@ -134,13 +156,13 @@ pub mod pagination;
 ///
 /// This is the main entry point for establishing database connections
 /// and creating query builders or migrators.
-pub use database::{Database, DatabaseBuilder, RawQuery};
+pub use database::{Database, DatabaseBuilder, Drivers, ExecuteResult, RawQuery, SchemaDiff};
 
 /// Re-export of the `Model` trait and `ColumnInfo` struct.
 ///
 /// The `Model` trait defines the interface for ORM entities, while
 /// `ColumnInfo` contains metadata about individual table columns.
-pub use model::{ColumnInfo, Model, RelationInfo, RelationType};
+pub use model::{ColumnInfo, ColumnInfoBuilder, IndexInfo, Model, PgEnum, RelationInfo, RelationType};
 
 /// Re-export of `AnyImpl` and `AnyInfo` for dynamic row mapping.
 ///
@@ -154,7 +176,7 @@ pub use transaction::Transaction;
 ///
 /// `QueryBuilder` provides a fluent interface for building SELECT and INSERT
 /// queries with filtering, ordering, and pagination capabilities.
-pub use query_builder::{Op, QueryBuilder};
+pub use query_builder::{Aggregate, Loaded, Op, OrderDir, QueryBuilder, SqlType};
 
 /// Re-export of the `Migrator` for schema migration management.
 ///
@@ -169,4 +191,10 @@ pub use migration::Migrator;
 pub use errors::Error;
 
 /// Re-export of `Pagination` struct.
-pub use pagination::Pagination;
+pub use pagination::{CursorPage, CursorPagination, Pagination, ScrollPage};
+
+/// Re-export of `DynamicQuery` for runtime-named table queries.
+pub use dynamic::DynamicQuery;
+
+/// Re-export of the filter value conversion trait and its wrapper.
+pub use value_binding::{Bind, FilterValue, IntoBindValue};
@@ -63,12 +63,18 @@
 // ============================================================================
 
 use futures::future::BoxFuture;
+use sqlx::Row;
+use std::{collections::HashSet, future::Future};
 
 // ============================================================================
 // Internal Crate Imports
 // ============================================================================
 
-use crate::{database::Database, model::Model};
+use crate::{
+    database::{assign_foreign_keys_on, sync_table_on, Database, Drivers},
+    model::Model,
+    transaction::Transaction,
+};
 
 // ============================================================================
 // Type Aliases
@@ -113,6 +119,33 @@ use crate::{database::Database, model::Model};
 /// ```
 pub type MigrationTask = Box<dyn Fn(Database) -> BoxFuture<'static, Result<(), sqlx::Error>> + Send + Sync>;
 
+/// Type alias for the model tasks `run` executes inside its schema transaction.
+///
+/// Each task receives a handle to the shared transaction, so every statement
+/// it issues is committed or rolled back together with the other models.
+pub type SchemaTask = Box<dyn Fn(Transaction<'static>) -> BoxFuture<'static, Result<(), sqlx::Error>> + Send + Sync>;
+
+/// Type alias for the dry-run counterpart of a [`SchemaTask`].
+///
+/// Resolves to the SQL statements the task would execute, reading the schema
+/// when needed but never changing it.
+pub type PlanTask = Box<dyn Fn(Database) -> BoxFuture<'static, Result<Vec<String>, sqlx::Error>> + Send + Sync>;
+
+/// Converts a schema error for the `sqlx::Error`-based migration API.
+///
+/// Database errors pass through unchanged. Any other error, such as
+/// `Error::InvalidForeignKey`, is boxed as it is into `sqlx::Error::Configuration`,
+/// so callers can downcast it to [`crate::Error`] and read its fields.
+fn into_sqlx_error(e: crate::Error) -> sqlx::Error {
+    match e {
+        crate::Error::DatabaseError(se) => se,
+        other => sqlx::Error::Configuration(Box::new(other)),
+    }
+}
+
+/// Table recording which named migrations have been applied.
+pub const MIGRATIONS_TABLE: &str = "_bottle_migrations";
+
 // ============================================================================
 // Migrator Struct
 // ============================================================================
@@ -177,13 +210,37 @@ pub struct Migrator<'a> {
     /// These tasks are executed first, in the order they were registered.
     /// Each task creates a table with its columns, indexes, and constraints
     /// (except foreign keys).
-    pub(crate) tasks: Vec<MigrationTask>,
+    pub(crate) tasks: Vec<SchemaTask>,
 
     /// Queue of foreign key assignment tasks.
     ///
     /// These tasks are executed after all table creation tasks complete.
     /// This ensures that referenced tables exist before foreign keys are created.
-    pub(crate) fk_task: Vec<MigrationTask>,
+    pub(crate) fk_task: Vec<SchemaTask>,
+
+    /// Queue of foreign key removal tasks, run by `rollback` in reverse order.
+    pub(crate) drop_fk_tasks: Vec<MigrationTask>,
+
+    /// Queue of table drop tasks, run by `rollback` in reverse order.
+    pub(crate) drop_tasks: Vec<MigrationTask>,
+
+    /// Named migrations added with `add`, in the order they run.
+    ///
+    /// These run after both model phases, at most once per database: each
+    /// applied name is recorded in [`MIGRATIONS_TABLE`].
+    pub(crate) named: Vec<(String, MigrationTask)>,
+
+    /// Versioned migrations as `(version, up_sql, down_sql)`, in registration order.
+    ///
+    /// `run` and `plan` take them in ascending version order; `rollback` runs the
+    /// `down_sql` of the applied ones from the newest down.
+    pub(crate) versioned: Vec<(u64, String, String)>,
+
+    /// Dry-run counterparts of `tasks`, used by `plan`.
+    pub(crate) plan_tasks: Vec<PlanTask>,
+
+    /// Dry-run counterparts of `fk_task`, used by `plan`.
+    pub(crate) fk_plan_tasks: Vec<PlanTask>,
 }
 
 // ============================================================================
@@ -219,7 +276,17 @@ impl<'a> Migrator<'a> {
     /// let migrator = Migrator::new(&db);
     /// ```
     pub fn new(db: &'a Database) -> Self {
-        Self { db, tasks: Vec::new(), fk_task: Vec::new() }
+        Self {
+            db,
+            tasks: Vec::new(),
+            fk_task: Vec::new(),
+            drop_fk_tasks: Vec::new(),
+            drop_tasks: Vec::new(),
+            named: Vec::new(),
+            versioned: Vec::new(),
+            plan_tasks: Vec::new(),
+            fk_plan_tasks: Vec::new(),
+        }
     }
 
     // ========================================================================
@@ -306,37 +373,114 @@ impl<'a> Migrator<'a> {
         T: Model + 'static + Send + Sync,
     {
         // Create table creation task with diffing
-        let task = Box::new(|db: Database| -> BoxFuture<'static, Result<(), sqlx::Error>> {
+        let task = Box::new(|tx: Transaction<'static>| -> BoxFuture<'static, Result<(), sqlx::Error>> {
             Box::pin(async move {
                 // Synchronize table (create if not exists or add missing columns)
-                db.sync_table::<T>().await.map_err(|e| match e {
-                    crate::Error::DatabaseError(se) => se,
-                    _ => sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))),
-                })?;
+                sync_table_on::<T, _>(&tx).await.map_err(into_sqlx_error)?;
                 Ok(())
             })
         });
 
         // Create foreign key assignment task
-        let fk_task = Box::new(|db: Database| -> BoxFuture<'static, Result<(), sqlx::Error>> {
+        let fk_task = Box::new(|tx: Transaction<'static>| -> BoxFuture<'static, Result<(), sqlx::Error>> {
             Box::pin(async move {
                 // Assign foreign key constraints
-                db.assign_foreign_keys::<T>().await.map_err(|e| match e {
-                    crate::Error::DatabaseError(se) => se,
-                    _ => sqlx::Error::Decode(Box::new(std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))),
-                })?;
+                assign_foreign_keys_on::<T, _>(&tx).await.map_err(into_sqlx_error)?;
                 Ok(())
             })
         });
 
+        // Create the reverse tasks used by rollback()
+        let drop_fk_task = Box::new(|db: Database| -> BoxFuture<'static, Result<(), sqlx::Error>> {
+            Box::pin(async move { db.drop_foreign_keys::<T>().await.map_err(into_sqlx_error) })
+        });
+        let drop_task = Box::new(|db: Database| -> BoxFuture<'static, Result<(), sqlx::Error>> {
+            Box::pin(async move { db.drop_table::<T>().await.map_err(into_sqlx_error) })
+        });
+
+        // Create the dry-run tasks used by plan()
+        let plan_task = Box::new(|db: Database| -> BoxFuture<'static, Result<Vec<String>, sqlx::Error>> {
+            Box::pin(async move { db.sync_table_sql::<T>().await.map_err(into_sqlx_error) })
+        });
+        let fk_plan_task = Box::new(|db: Database| -> BoxFuture<'static, Result<Vec<String>, sqlx::Error>> {
+            Box::pin(async move { db.foreign_key_sql::<T>().map_err(into_sqlx_error) })
+        });
+
         // Add tasks to their respective queues
         self.tasks.push(task);
         self.fk_task.push(fk_task);
+        self.drop_fk_tasks.push(drop_fk_task);
+        self.drop_tasks.push(drop_task);
+        self.plan_tasks.push(plan_task);
+        self.fk_plan_tasks.push(fk_plan_task);
 
         // Return self for method chaining
         self
     }
 
+    /// Adds a named migration step that runs once per database.
+    ///
+    /// Named steps cover the changes model registration cannot express, such as
+    /// backfills or hand-written constraints. They run after the registered models
+    /// are synchronized, in the order they were added. Each applied name is recorded
+    /// in the `_bottle_migrations` table, so later runs skip it; a step that fails is
+    /// not recorded and is retried next time. Names must be unique and should never
+    /// be reused for a different step.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.migrator()
+    ///     .register::<User>()
+    ///     .add("0002_backfill_emails", |db| async move {
+    ///         sqlx::query("UPDATE \"user\" SET email = username || '@example.com' WHERE email = ''")
+    ///             .execute(&db.get_pool())
+    ///             .await?;
+    ///         Ok(())
+    ///     })
+    ///     .run()
+    ///     .await?;
+    /// ```
+    pub fn add<F, Fut>(mut self, name: &str, step: F) -> Self
+    where
+        F: Fn(Database) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), sqlx::Error>> + Send + 'static,
+    {
+        self.named.push((name.to_string(), Box::new(move |db| Box::pin(step(db)))));
+        self
+    }
+
+    /// Adds a versioned SQL migration with its reverse.
+    ///
+    /// `up_sql` runs once per database, like a step added with [`add()`](#method.add),
+    /// and the version is recorded in the `_bottle_migrations` table as `version_<n>`,
+    /// so it cannot clash with a step name. `down_sql` is run by
+    /// [`rollback()`](#method.rollback) when the version has been applied. Either
+    /// script may hold several `;`-separated statements. Versions run in ascending
+    /// order whatever order they are registered in, before the steps added with `add()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.migrator()
+    ///     .register::<User>()
+    ///     .register_migration(1, "CREATE INDEX idx_user_email ON \"user\" (email)", "DROP INDEX idx_user_email")
+    ///     .register_migration(2, "ALTER TABLE \"user\" ADD COLUMN bio TEXT", "ALTER TABLE \"user\" DROP COLUMN bio")
+    ///     .run()
+    ///     .await?;
+    /// ```
+    pub fn register_migration(mut self, version: u64, up_sql: &str, down_sql: &str) -> Self {
+        self.versioned.push((version, up_sql.to_string(), down_sql.to_string()));
+        self
+    }
+
+    /// Returns the versioned migrations sorted by version.
+    fn sorted_versions(&self) -> Vec<&(u64, String, String)> {
+        let mut versions: Vec<_> = self.versioned.iter().collect();
+        versions.sort_by_key(|(version, _, _)| *version);
+        versions
+    }
+
     // ========================================================================
     // Migration Execution
     // ========================================================================
@@ -355,7 +499,13 @@ impl<'a> Migrator<'a> {
     /// - Creates foreign key constraints between tables
     /// - Checks for existing constraints to avoid duplicates
     ///
-    /// If any task fails, the entire migration is aborted and an error is returned.
+    /// Both phases run in a single transaction: if any task fails, it is rolled
+    /// back, so the schema is left as it was, and the error is returned. This holds
+    /// on PostgreSQL and SQLite, whose DDL is transactional. MySQL commits implicitly
+    /// after each DDL statement, so there a failure still leaves the tables created
+    /// before it in place.
+    ///
+    /// Versioned migrations, then named ones, run after the transaction commits, each on its own.
     ///
     /// # Returns
     ///
@@ -409,7 +559,6 @@ impl<'a> Migrator<'a> {
     /// - Migrations are executed sequentially, not in parallel
     /// - Large schemas may take time to migrate
     /// - Consider running migrations during deployment/startup
-    /// - Use database transactions where supported
     ///
     /// # See Also
     ///
@@ -417,29 +566,204 @@ impl<'a> Migrator<'a> {
     /// * [`Database::create_table()`] - For manual table creation
     /// * [`Database::assign_foreign_keys()`] - For manual FK assignment
     pub async fn run(self) -> Result<Database, sqlx::Error> {
+        if !self.tasks.is_empty() {
+            let tx: Transaction<'static> = self.db.begin_detached().await.map_err(into_sqlx_error)?;
+
+            let schema = async {
+                // ============================================================
+                // Phase 1: Execute Table Creation Tasks
+                // ============================================================
+                // Create all tables in the order they were registered.
+                // This ensures that models are created before their dependents.
+                for task in &self.tasks {
+                    (task)(tx.clone()).await?;
+                }
+
+                // ============================================================
+                // Phase 2: Execute Foreign Key Assignment Tasks
+                // ============================================================
+                // Assign foreign keys after all tables exist.
+                // This prevents errors where a foreign key references a table
+                // that hasn't been created yet.
+                for task in &self.fk_task {
+                    (task)(tx.clone()).await?;
+                }
+                Ok::<(), sqlx::Error>(())
+            }
+            .await;
+
+            match schema {
+                Ok(()) => tx.commit().await?,
+                Err(e) => {
+                    let _ = tx.rollback().await;
+                    return Err(e);
+                }
+            }
+        }
+
         // ====================================================================
-        // Phase 1: Execute Table Creation Tasks
+        // Phase 3: Execute Versioned, Then Named Migrations Not Yet Applied
         // ====================================================================
-        // Create all tables in the order they were registered.
-        // This ensures that models are created before their dependents.
-        for task in self.tasks {
-            // Clone the database for the async task
-            // This is safe because Database contains a connection pool
+        if !self.named.is_empty() || !self.versioned.is_empty() {
+            let mut seen = HashSet::new();
+            if let Some((name, _)) = self.named.iter().find(|(name, _)| !seen.insert(name.as_str())) {
+                return Err(sqlx::Error::Configuration(
+                    format!("Migration '{}' was added more than once", name).into(),
+                ));
+            }
+            let versions = self.sorted_versions();
+            if let Some(pair) = versions.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+                return Err(sqlx::Error::Configuration(
+                    format!("Migration version {} was registered more than once", pair[0].0).into(),
+                ));
+            }
+
+            ensure_history_table(self.db).await?;
+            let applied = applied_migrations(self.db).await?;
+            for (version, up_sql, _) in versions {
+                let name = version_key(*version);
+                if applied.contains(&name) {
+                    continue;
+                }
+                sqlx::raw_sql(up_sql).execute(&self.db.pool).await?;
+                record_migration(self.db, &name).await?;
+            }
+            for (name, task) in self.named {
+                if applied.contains(&name) {
+                    continue;
+                }
+                (task)(self.db.clone()).await?;
+                record_migration(self.db, &name).await?;
+            }
+        }
+
+        // Return cloned database instance for continued use
+        Ok(self.db.clone())
+    }
+
+    /// Returns the SQL that [`run()`](#method.run) would execute, without executing it.
+    ///
+    /// The statements come in run order: the table statements of every registered
+    /// model (see [`Database::sync_table_sql()`]), then their foreign keys, then the
+    /// `up_sql` of versioned migrations not applied yet. The database is only read.
+    /// Steps added with [`add()`](#method.add) are Rust code and cannot be listed.
+    ///
+    /// Statements of a later model are computed against the current schema, so a
+    /// plan may differ from what `run` emits when one model's changes affect another.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let plan = db.migrator().register::<User>().register::<Post>().plan().await?;
+    /// std::fs::write("schema.sql", plan.join(";\n"))?;
+    /// ```
+    pub async fn plan(self) -> Result<Vec<String>, sqlx::Error> {
+        let mut statements = Vec::new();
+
+        for task in self.plan_tasks.iter().chain(self.fk_plan_tasks.iter()) {
+            statements.extend((task)(self.db.clone()).await?);
+        }
+
+        if !self.versioned.is_empty() {
+            let applied = if self.db.table_exists(MIGRATIONS_TABLE).await.map_err(into_sqlx_error)? {
+                applied_migrations(self.db).await?
+            } else {
+                HashSet::new()
+            };
+            statements.extend(
+                self.sorted_versions()
+                    .into_iter()
+                    .filter(|(version, _, _)| !applied.contains(&version_key(*version)))
+                    .map(|(_, up_sql, _)| up_sql.clone()),
+            );
+        }
+
+        Ok(statements)
+    }
+
+    /// Reverts the registered models, dropping their tables.
+    ///
+    /// Runs the inverse of [`run()`](#method.run): first the foreign keys of every
+    /// registered model are dropped, then the tables, each phase in reverse
+    /// registration order so dependent tables go before the tables they reference.
+    /// Missing tables and constraints are skipped, so rolling back twice is safe.
+    /// Versioned migrations are reverted first, newest first, by running their
+    /// `down_sql`. Named migrations added with [`add()`](#method.add) are not undone.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Tear down and rebuild the schema between CI runs
+    /// db.migrator().register::<User>().register::<Post>().rollback().await?;
+    /// db.migrator().register::<User>().register::<Post>().run().await?;
+    /// ```
+    pub async fn rollback(self) -> Result<Database, sqlx::Error> {
+        if !self.versioned.is_empty() {
+            ensure_history_table(self.db).await?;
+            let applied = applied_migrations(self.db).await?;
+            for (version, _, down_sql) in self.sorted_versions().into_iter().rev() {
+                let name = version_key(*version);
+                if applied.contains(&name) {
+                    sqlx::raw_sql(down_sql).execute(&self.db.pool).await?;
+                    forget_migration(self.db, &name).await?;
+                }
+            }
+        }
+
+        for task in self.drop_fk_tasks.into_iter().rev() {
             (task)(self.db.clone()).await?;
         }
 
-        // ====================================================================
-        // Phase 2: Execute Foreign Key Assignment Tasks
-        // ====================================================================
-        // Assign foreign keys after all tables exist.
-        // This prevents errors where a foreign key references a table
-        // that hasn't been created yet.
-        for task in self.fk_task {
-            // Clone the database for the async task
+        for task in self.drop_tasks.into_iter().rev() {
             (task)(self.db.clone()).await?;
         }
 
-        // Return cloned database instance for continued use
         Ok(self.db.clone())
     }
 }
+
+// ============================================================================
+// Migration History
+// ============================================================================
+
+/// Returns the name a versioned migration is recorded under in the history table.
+fn version_key(version: u64) -> String {
+    format!("version_{}", version)
+}
+
+/// Creates the migration history table if it does not exist yet.
+pub(crate) async fn ensure_history_table(db: &Database) -> Result<(), sqlx::Error> {
+    let sql = format!(
+        "CREATE TABLE IF NOT EXISTS {} (name VARCHAR(255) PRIMARY KEY, applied_at VARCHAR(64) NOT NULL)",
+        MIGRATIONS_TABLE
+    );
+    sqlx::query(&sql).execute(&db.pool).await?;
+    Ok(())
+}
+
+/// Returns the names of the migrations already recorded as applied.
+pub(crate) async fn applied_migrations(db: &Database) -> Result<HashSet<String>, sqlx::Error> {
+    let sql = format!("SELECT name FROM {}", MIGRATIONS_TABLE);
+    let rows = sqlx::query(&sql).fetch_all(&db.pool).await?;
+    rows.iter().map(|row| row.try_get::<String, _>(0)).collect()
+}
+
+/// Records a migration as applied.
+pub(crate) async fn record_migration(db: &Database, name: &str) -> Result<(), sqlx::Error> {
+    let sql = match db.driver {
+        Drivers::Postgres => format!("INSERT INTO {} (name, applied_at) VALUES ($1, $2)", MIGRATIONS_TABLE),
+        _ => format!("INSERT INTO {} (name, applied_at) VALUES (?, ?)", MIGRATIONS_TABLE),
+    };
+    sqlx::query(&sql).bind(name).bind(chrono::Utc::now().to_rfc3339()).execute(&db.pool).await?;
+    Ok(())
+}
+
+/// Removes a migration from the history, so it runs again on the next `run`.
+pub(crate) async fn forget_migration(db: &Database, name: &str) -> Result<(), sqlx::Error> {
+    let sql = match db.driver {
+        Drivers::Postgres => format!("DELETE FROM {} WHERE name = $1", MIGRATIONS_TABLE),
+        _ => format!("DELETE FROM {} WHERE name = ?", MIGRATIONS_TABLE),
+    };
+    sqlx::query(&sql).bind(name).execute(&db.pool).await?;
+    Ok(())
+}
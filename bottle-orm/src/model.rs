@@ -70,7 +70,7 @@
 //! - `#[orm(index)]` - Creates database index
 //! - `#[orm(size = N)]` - Sets VARCHAR size (for String fields)
 //! - `#[orm(create_time)]` - Auto-populate with current timestamp on creation
-//! - `#[orm(update_time)]` - Auto-update timestamp on modification (future feature)
+//! - `#[orm(update_time)]` - Auto-update timestamp on modification
 //! - `#[orm(foreign_key = "Table::Column")]` - Defines foreign key relationship
 
 // ============================================================================
@@ -79,8 +79,7 @@
 
 use std::collections::HashMap;
 use futures::future::BoxFuture;
-use crate::database::Connection;
-use crate::query_builder::QueryBuilder;
+use crate::database::{Connection, Drivers};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RelationType {
@@ -98,6 +97,58 @@ pub struct RelationInfo {
     pub local_key: &'static str,
 }
 
+/// A multi-column index declared on the model.
+///
+/// Produced by the struct-level `#[orm(index(columns = "a, b"))]` attribute, with
+/// `unique` set by `#[orm(index(columns = "a, b", unique))]`. Each entry names a
+/// field or its column and is stored as the column name; an entry matching neither
+/// fails to compile. The index is named `idx_{table}_{a}_{b}`, or
+/// `unique_{table}_{a}_{b}` when unique.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexInfo {
+    pub columns: Vec<&'static str>,
+    pub unique: bool,
+}
+
+impl IndexInfo {
+    /// Returns the name of the index on `table`.
+    pub fn name(&self, table: &str) -> String {
+        let prefix = if self.unique { "unique" } else { "idx" };
+        format!("{}_{}_{}", prefix, table, self.columns.join("_"))
+    }
+}
+
+/// A `BottleEnum` stored in a native PostgreSQL enum type.
+///
+/// Implemented by `#[derive(BottleEnum)]` when the enum carries `#[bottle(pg_enum)]`
+/// (the type is named after the enum in snake_case) or `#[bottle(pg_enum = "name")]`.
+/// A model field marked `#[orm(pg_enum)]` then uses the type as its column type on
+/// PostgreSQL, and `create_table` creates the type first if it does not exist.
+/// MySQL and SQLite keep storing the variant strings as `TEXT`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(BottleEnum, Debug, Clone, PartialEq)]
+/// #[bottle(pg_enum)]
+/// enum UserRole { Admin, Member }
+///
+/// #[derive(Model, Debug, Clone)]
+/// struct User {
+///     #[orm(primary_key)]
+///     id: i32,
+///     #[orm(pg_enum)]
+///     role: UserRole, // "role" "user_role" NOT NULL
+/// }
+/// ```
+pub trait PgEnum {
+    /// The quoted type name, used as the column's SQL type (e.g. `"\"user_role\""`).
+    const SQL_TYPE: &'static str;
+
+    /// The labels of the type, in declaration order.
+    const VARIANTS: &'static [&'static str];
+}
+
 // ============================================================================
 // Column Metadata Structure
 // ============================================================================
@@ -115,12 +166,21 @@ pub struct RelationInfo {
 /// * `sql_type` - SQL type string (e.g., "INTEGER", "TEXT", "UUID", "TIMESTAMPTZ")
 /// * `is_primary_key` - Whether this is the primary key column
 /// * `is_nullable` - Whether NULL values are allowed (from Option<T>)
-/// * `create_time` - Auto-populate with CURRENT_TIMESTAMP on insert
-/// * `update_time` - Auto-update timestamp on modification (future feature)
+/// * `create_time` - Auto-populate with the current time on insert
+/// * `update_time` - Auto-update timestamp on modification
 /// * `unique` - Whether UNIQUE constraint should be added
 /// * `index` - Whether to create an index on this column
 /// * `foreign_table` - Name of referenced table (for foreign keys)
 /// * `foreign_key` - Name of referenced column (for foreign keys)
+/// * `on_delete` / `on_update` - Referential actions of the foreign key
+/// * `omit` - Leave the column out of default selects
+/// * `soft_delete` - Column used as the soft-delete marker
+/// * `default` - Default value expression
+/// * `auto_increment` - Value generated by the database on insert
+/// * `pg_enum` - Labels of the native PostgreSQL enum type
+///
+/// Hand-written `Model` implementations should use [`ColumnInfo::builder`] rather
+/// than a struct literal, so they keep compiling when fields are added.
 ///
 /// # Example
 ///
@@ -141,6 +201,12 @@ pub struct RelationInfo {
 ///     index: true,
 ///     foreign_table: None,
 ///     foreign_key: None,
+///     on_delete: None,
+///     on_update: None,
+///     omit: false,
+///     soft_delete: false,
+///     default: None,
+///     auto_increment: false,
 /// }
 /// ```
 ///
@@ -236,37 +302,30 @@ pub struct ColumnInfo {
 
     /// Whether this column should be automatically populated with the creation timestamp.
     ///
-    /// Set via `#[orm(create_time)]` attribute. When `true`, the column gets
-    /// a `DEFAULT CURRENT_TIMESTAMP` constraint.
-    ///
-    /// # SQL Impact
-    /// - Adds `DEFAULT CURRENT_TIMESTAMP`
-    /// - Column is auto-populated on INSERT
+    /// Set via `#[orm(create_time)]` attribute. When `true`, `insert()` and
+    /// `batch_insert()` fill the column with the current time if the value is
+    /// unset (`None`, or the Unix epoch produced by `Default`).
     ///
     /// # Example
     /// ```rust,ignore
     /// #[orm(create_time)]
     /// created_at: DateTime<Utc>,
     /// // create_time: true
-    /// // SQL: created_at TIMESTAMPTZ DEFAULT CURRENT_TIMESTAMP
     /// ```
     pub create_time: bool,
 
     /// Whether this column should be automatically updated on modification.
     ///
-    /// Set via `#[orm(update_time)]` attribute. This is a **future feature**
-    /// not yet fully implemented.
-    ///
-    /// # Future Implementation
-    /// When implemented, this will:
-    /// - Add database trigger or application-level update
-    /// - Auto-update timestamp on every UPDATE
+    /// Set via `#[orm(update_time)]` attribute. Every UPDATE issued through the
+    /// query builder sets the column to the current time unless it is assigned
+    /// explicitly (e.g. `update("updated_at", ts)`). Inserts fill it like
+    /// `create_time` when unset.
     ///
     /// # Example
     /// ```rust,ignore
     /// #[orm(update_time)]
     /// updated_at: DateTime<Utc>,
-    /// // update_time: true (future feature)
+    /// // update_time: true
     /// ```
     pub update_time: bool,
 
@@ -277,15 +336,14 @@ pub struct ColumnInfo {
     /// on database).
     ///
     /// # SQL Impact
-    /// - Adds `UNIQUE` constraint
-    /// - Creates a unique index automatically
+    /// - Creates a unique index named `unique_{table}_{column}`
     ///
     /// # Example
     /// ```rust,ignore
     /// #[orm(unique)]
     /// username: String,
     /// // unique: true
-    /// // SQL: username VARCHAR(255) UNIQUE
+    /// // SQL: CREATE UNIQUE INDEX "unique_user_username" ON "user" ("username")
     /// ```
     pub unique: bool,
 
@@ -310,13 +368,14 @@ pub struct ColumnInfo {
     /// The name of the foreign table, if this is a Foreign Key.
     ///
     /// Set via `#[orm(foreign_key = "Table::Column")]` attribute. Contains
-    /// the name of the referenced table.
+    /// the name of the referenced table: a capitalized `Table` is a model type
+    /// and resolves to its [`Model::table_name`], anything else is used as is.
     ///
     /// # Example
     /// ```rust,ignore
     /// #[orm(foreign_key = "User::id")]
     /// user_id: Uuid,
-    /// // foreign_table: Some("User")
+    /// // foreign_table: Some("user")
     /// ```
     pub foreign_table: Option<&'static str>,
 
@@ -334,6 +393,25 @@ pub struct ColumnInfo {
     /// ```
     pub foreign_key: Option<&'static str>,
 
+    /// The referential action applied when the referenced row is deleted.
+    ///
+    /// Set via `#[orm(on_delete = "...")]` next to `foreign_key`. Accepted values are
+    /// `cascade`, `restrict`, `set null` and `no action` (case-insensitive).
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(foreign_key = "User::id", on_delete = "cascade")]
+    /// user_id: i32,
+    /// // on_delete: Some("cascade")
+    /// // SQL: FOREIGN KEY (user_id) REFERENCES user (id) ON DELETE CASCADE
+    /// ```
+    pub on_delete: Option<&'static str>,
+
+    /// The referential action applied when the referenced key is updated.
+    ///
+    /// Set via `#[orm(on_update = "...")]`; accepts the same values as `on_delete`.
+    pub on_update: Option<&'static str>,
+
     /// Whether this field should be omitted from queries by default.
     ///
     /// Set via `#[orm(omit)]` attribute. When `true`, this column will be
@@ -362,6 +440,219 @@ pub struct ColumnInfo {
     /// // Records with deleted_at set will be excluded from queries
     /// ```
     pub soft_delete: bool,
+
+    /// The column's default value expression, if any.
+    ///
+    /// Set via `#[orm(default = "...")]` attribute. Emitted as `DEFAULT <expr>`
+    /// by `create_table` and when `sync_table` adds the column to an existing
+    /// table. Numbers, booleans, `NULL`, `CURRENT_TIMESTAMP`-style keywords,
+    /// already-quoted literals, and function calls such as `now()` are emitted
+    /// as-is; anything else is quoted as a string literal.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(default = "now()")]
+    /// created_at: DateTime<Utc>,
+    /// // default: Some("now()")
+    /// // SQL: "created_at" TIMESTAMPTZ NOT NULL DEFAULT now()
+    ///
+    /// #[orm(default = "pending")]
+    /// status: String,
+    /// // SQL: "status" TEXT NOT NULL DEFAULT 'pending'
+    /// ```
+    pub default: Option<&'static str>,
+
+    /// Whether the database generates this column's value on insert.
+    ///
+    /// Only meaningful on a single integer primary key. `create_table` emits
+    /// `SERIAL`/`BIGSERIAL` on PostgreSQL, `AUTO_INCREMENT` on MySQL and
+    /// `INTEGER PRIMARY KEY AUTOINCREMENT` on SQLite. `insert()` leaves the column
+    /// out while the field still holds `0`, so the database assigns the value.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(primary_key, auto_increment)]
+    /// id: i32,
+    /// // auto_increment: true
+    /// ```
+    pub auto_increment: bool,
+
+    /// The labels of the PostgreSQL enum type named by `sql_type`, if any.
+    ///
+    /// Set via `#[orm(pg_enum)]` from the field type's [`PgEnum`] implementation.
+    /// On PostgreSQL `create_table` creates the type before the table; other drivers
+    /// store the column as `TEXT`.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// #[orm(pg_enum)]
+    /// role: UserRole,
+    /// // sql_type: "\"user_role\"", pg_enum: Some(&["admin", "member"])
+    /// ```
+    pub pg_enum: Option<&'static [&'static str]>,
+}
+
+impl ColumnInfo {
+    /// Starts a [`ColumnInfoBuilder`] for a column with the given name and SQL type.
+    ///
+    /// Every flag starts out `false` and every optional attribute `None`, so
+    /// hand-written `Model` implementations only spell out what they use and keep
+    /// compiling when new attributes are added.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let email = ColumnInfo::builder("email", "VARCHAR(255)").unique().index().build();
+    /// let author = ColumnInfo::builder("author_id", "INTEGER")
+    ///     .foreign_key("user", "id")
+    ///     .on_delete("cascade")
+    ///     .build();
+    /// ```
+    pub fn builder(name: &'static str, sql_type: &'static str) -> ColumnInfoBuilder {
+        ColumnInfoBuilder {
+            column: ColumnInfo {
+                name,
+                sql_type,
+                is_primary_key: false,
+                is_nullable: false,
+                create_time: false,
+                update_time: false,
+                unique: false,
+                index: false,
+                foreign_table: None,
+                foreign_key: None,
+                on_delete: None,
+                on_update: None,
+                omit: false,
+                soft_delete: false,
+                default: None,
+                auto_increment: false,
+                pg_enum: None,
+            },
+        }
+    }
+
+    /// Returns the column type to declare on `driver`.
+    ///
+    /// Native PostgreSQL enum columns fall back to `TEXT` on other drivers.
+    pub(crate) fn sql_type_for(&self, driver: Drivers) -> &'static str {
+        match (self.pg_enum, driver) {
+            (Some(_), Drivers::MySQL | Drivers::SQLite) => "TEXT",
+            _ => self.sql_type,
+        }
+    }
+
+    /// Returns the idempotent `CREATE TYPE` statement for a native PostgreSQL enum column.
+    pub(crate) fn create_enum_type_sql(&self) -> Option<String> {
+        let variants = self.pg_enum?;
+        let labels: Vec<String> = variants.iter().map(|v| format!("'{}'", v.replace('\'', "''"))).collect();
+        Some(format!(
+            "DO $$ BEGIN CREATE TYPE {} AS ENUM ({}); EXCEPTION WHEN duplicate_object THEN NULL; END $$",
+            self.sql_type,
+            labels.join(", ")
+        ))
+    }
+}
+
+/// A builder for [`ColumnInfo`], created with [`ColumnInfo::builder`].
+///
+/// Each setter mirrors the `#[orm(...)]` attribute of the same name.
+#[derive(Debug, Clone)]
+pub struct ColumnInfoBuilder {
+    column: ColumnInfo,
+}
+
+impl ColumnInfoBuilder {
+    /// Marks the column as the primary key (`#[orm(primary_key)]`).
+    pub fn primary_key(mut self) -> Self {
+        self.column.is_primary_key = true;
+        self
+    }
+
+    /// Allows NULL values, as an `Option<T>` field would.
+    pub fn nullable(mut self) -> Self {
+        self.column.is_nullable = true;
+        self
+    }
+
+    /// Fills the column with the creation timestamp (`#[orm(create_time)]`).
+    pub fn create_time(mut self) -> Self {
+        self.column.create_time = true;
+        self
+    }
+
+    /// Marks the column as the modification timestamp (`#[orm(update_time)]`).
+    pub fn update_time(mut self) -> Self {
+        self.column.update_time = true;
+        self
+    }
+
+    /// Adds a UNIQUE constraint (`#[orm(unique)]`).
+    pub fn unique(mut self) -> Self {
+        self.column.unique = true;
+        self
+    }
+
+    /// Creates an index on the column (`#[orm(index)]`).
+    pub fn index(mut self) -> Self {
+        self.column.index = true;
+        self
+    }
+
+    /// References `table`.`column` (`#[orm(foreign_key = "Table::column")]`).
+    pub fn foreign_key(mut self, table: &'static str, column: &'static str) -> Self {
+        self.column.foreign_table = Some(table);
+        self.column.foreign_key = Some(column);
+        self
+    }
+
+    /// Sets the ON DELETE action of the foreign key (`#[orm(on_delete = "...")]`).
+    pub fn on_delete(mut self, action: &'static str) -> Self {
+        self.column.on_delete = Some(action);
+        self
+    }
+
+    /// Sets the ON UPDATE action of the foreign key (`#[orm(on_update = "...")]`).
+    pub fn on_update(mut self, action: &'static str) -> Self {
+        self.column.on_update = Some(action);
+        self
+    }
+
+    /// Leaves the column out of default selects (`#[orm(omit)]`).
+    pub fn omit(mut self) -> Self {
+        self.column.omit = true;
+        self
+    }
+
+    /// Uses the column as the soft-delete marker (`#[orm(soft_delete)]`).
+    pub fn soft_delete(mut self) -> Self {
+        self.column.soft_delete = true;
+        self
+    }
+
+    /// Sets the column's default expression (`#[orm(default = "...")]`).
+    pub fn default(mut self, expr: &'static str) -> Self {
+        self.column.default = Some(expr);
+        self
+    }
+
+    /// Lets the database generate the value on insert (`#[orm(auto_increment)]`).
+    pub fn auto_increment(mut self) -> Self {
+        self.column.auto_increment = true;
+        self
+    }
+
+    /// Stores the column in the PostgreSQL enum type `E` (`#[orm(pg_enum)]`).
+    pub fn pg_enum<E: PgEnum>(mut self) -> Self {
+        self.column.sql_type = E::SQL_TYPE;
+        self.column.pg_enum = Some(E::VARIANTS);
+        self
+    }
+
+    /// Returns the finished [`ColumnInfo`].
+    pub fn build(self) -> ColumnInfo {
+        self.column
+    }
 }
 
 // ============================================================================
@@ -423,30 +714,8 @@ pub struct ColumnInfo {
 ///
 ///     fn columns() -> Vec<ColumnInfo> {
 ///         vec![
-///             ColumnInfo {
-///                 name: "id",
-///                 sql_type: "INTEGER",
-///                 is_primary_key: true,
-///                 is_nullable: false,
-///                 create_time: false,
-///                 update_time: false,
-///                 unique: false,
-///                 index: false,
-///                 foreign_table: None,
-///                 foreign_key: None,
-///             },
-///             ColumnInfo {
-///                 name: "name",
-///                 sql_type: "TEXT",
-///                 is_primary_key: false,
-///                 is_nullable: false,
-///                 create_time: false,
-///                 update_time: false,
-///                 unique: false,
-///                 index: false,
-///                 foreign_table: None,
-///                 foreign_key: None,
-///             },
+///             ColumnInfo::builder("id", "INTEGER").primary_key().build(),
+///             ColumnInfo::builder("name", "TEXT").build(),
 ///         ]
 ///     }
 ///
@@ -542,6 +811,14 @@ pub trait Model {
         Vec::new()
     }
 
+    /// Returns the multi-column indexes declared with `#[orm(index(columns = "..."))]`.
+    ///
+    /// Single-column indexes are described by [`ColumnInfo::index`] and
+    /// [`ColumnInfo::unique`] instead.
+    fn indexes() -> Vec<IndexInfo> {
+        Vec::new()
+    }
+
     /// Loads a specific relation for a collection of models.
     ///
     /// This method is used by the Query Builder to implement eager loading (with).
@@ -608,6 +885,54 @@ pub trait Model {
     /// assert_eq!(map.get("age"), Some(&Some("25".to_string())));
     /// ```
     fn to_map(&self) -> HashMap<String, Option<String>>;
+
+    /// Converts the model instance into `(column, value)` pairs in declaration order.
+    ///
+    /// Unlike [`to_map`](Self::to_map), the order is deterministic, so the query
+    /// builder uses it to line up column lists with bound values. The derive
+    /// generates it directly; the default implementation orders `to_map()` by
+    /// [`columns`](Self::columns) and appends any remaining keys sorted by name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let pairs = user.to_ordered_pairs();
+    /// assert_eq!(pairs[0].0, "id");
+    /// assert_eq!(pairs[1], ("username".to_string(), Some("john_doe".to_string())));
+    /// ```
+    fn to_ordered_pairs(&self) -> Vec<(String, Option<String>)> {
+        let mut map = self.to_map();
+        let mut pairs = Vec::with_capacity(map.len());
+        for col in Self::columns() {
+            if let Some(pair) = map.remove_entry(col.name) {
+                pairs.push(pair);
+            }
+        }
+        let mut rest: Vec<_> = map.into_iter().collect();
+        rest.sort_by(|a, b| a.0.cmp(&b.0));
+        pairs.extend(rest);
+        pairs
+    }
+
+    /// Converts only the listed fields into a value map (Column Name → String Value).
+    ///
+    /// The derived implementation accepts either the Rust field name or its
+    /// database column in `changed`; the default implementation matches column
+    /// names only. Unknown names are ignored. Used by `QueryBuilder::update_fields`
+    /// to build the SET list of a partial update.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let map = user.update_map(&["username"]);
+    /// assert_eq!(map.len(), 1);
+    /// assert_eq!(map.get("username"), Some(&Some("john_doe".to_string())));
+    /// ```
+    fn update_map(&self, changed: &[&str]) -> HashMap<String, Option<String>> {
+        let mut map = self.to_map();
+        map.retain(|column, _| changed.contains(&column.as_str()));
+        map
+    }
 }
 
 // ============================================================================
@@ -633,6 +958,11 @@ mod tests {
             foreign_key: None,
             omit: false,
             soft_delete: false,
+            default: None,
+            auto_increment: false,
+            on_delete: None,
+            on_update: None,
+            pg_enum: None,
         };
 
         assert_eq!(col.name, "test_column");
@@ -656,6 +986,11 @@ mod tests {
             foreign_key: Some("id"),
             omit: false,
             soft_delete: false,
+            default: None,
+            auto_increment: false,
+            on_delete: None,
+            on_update: None,
+            pg_enum: None,
         };
 
         assert_eq!(col.foreign_table, Some("User"));
@@ -9,7 +9,7 @@
 // ============================================================================
 
 use serde::{Deserialize, Serialize};
-use sqlx::Row;
+use sqlx::{any::AnyArguments, Row};
 
 // ============================================================================
 // Internal Crate Imports
@@ -19,7 +19,7 @@ use crate::{
     any_struct::FromAnyRow,
     database::Connection,
     model::Model,
-    query_builder::QueryBuilder,
+    query_builder::{push_typed_value, FilterFn, OrderDir, QueryBuilder},
     AnyImpl,
 };
 
@@ -45,6 +45,22 @@ pub struct Paginated<T> {
     pub total_pages: i64,
 }
 
+/// A page of results from [`Pagination::paginate_no_count`].
+///
+/// Carries no total: it only tells whether a next page exists, which is all an
+/// infinite-scroll client needs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrollPage<T> {
+    /// The list of items for the current page
+    pub data: Vec<T>,
+    /// The current page number (zero-based)
+    pub page: usize,
+    /// The number of items per page
+    pub limit: usize,
+    /// Whether at least one more row exists after this page
+    pub has_next: bool,
+}
+
 /// A builder for pagination settings.
 ///
 /// Use this struct to define how results should be paginated before executing
@@ -62,11 +78,16 @@ pub struct Pagination {
     /// Maximum allowed items per page (safety limit)
     #[serde(default = "default_max_limit", skip_deserializing)]
     pub max_limit: usize,
+
+    /// Orders by the primary key when the query has no ORDER BY; set with
+    /// [`order_by_primary_key`](Self::order_by_primary_key)
+    #[serde(skip)]
+    order_by_primary_key: bool,
 }
 
 /// Sets defaults values to limit.
 fn default_limit() -> usize {
-    10
+    20
 }
 
 
@@ -76,9 +97,12 @@ fn default_max_limit() -> usize {
 }
 
 /// Default for axum headers
+///
+/// Matches the serde defaults, so `?page=2` alone deserializes to page 2 with
+/// 20 items per page.
 impl Default for Pagination {
     fn default() -> Self {
-        Self { page: 0, limit: 10, max_limit: 100 }
+        Self { page: 0, limit: default_limit(), max_limit: default_max_limit(), order_by_primary_key: false }
     }
 }
 
@@ -95,7 +119,7 @@ impl Pagination {
         if f_limit > max_limit {
             f_limit = max_limit;
         }
-        Self { page, limit: f_limit, max_limit }
+        Self { page, limit: f_limit, max_limit, order_by_primary_key: false }
     }
 
     /// Creates a new Pagination instance with a default safety limit of 100.
@@ -108,11 +132,40 @@ impl Pagination {
         Self::new_with_limit(page, limit, 100)
     }
 
+    /// Orders unordered queries by the primary key so pages are stable.
+    ///
+    /// Without an ORDER BY the database may return rows in any order, so the
+    /// same page can hold different rows from one request to the next. With this
+    /// option, `apply`, `paginate` and `paginate_as` add `ORDER BY <pk> ASC` when
+    /// the query has no ordering of its own. Otherwise they log a warning when the
+    /// query is in [`debug`](crate::query_builder::QueryBuilder::debug) mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let page: Paginated<User> = Pagination::new(2, 20)
+    ///     .order_by_primary_key()
+    ///     .paginate(db.model::<User>())
+    ///     .await?;
+    /// ```
+    pub fn order_by_primary_key(mut self) -> Self {
+        self.order_by_primary_key = true;
+        self
+    }
+
+    /// Returns the page size actually used: capped at `max_limit`, with a limit of
+    /// `0` (e.g. `?limit=0`) replaced by the default so page math never divides by zero.
+    fn effective_limit(&self) -> usize {
+        let limit = if self.limit == 0 { default_limit() } else { self.limit };
+        limit.min(self.max_limit).max(1)
+    }
+
     /// Applies pagination settings to a `QueryBuilder`.
     ///
     /// This method sets the `limit` and `offset` of the query builder
     /// based on the pagination parameters. It also enforces the `max_limit`
-    /// check before applying the limit.
+    /// check before applying the limit. Unordered queries get a primary key
+    /// ordering if [`order_by_primary_key`](Self::order_by_primary_key) is set.
     ///
     /// # Arguments
     ///
@@ -121,16 +174,15 @@ impl Pagination {
     /// # Returns
     ///
     /// The modified `QueryBuilder`
-    pub fn apply<T, E>(mut self, query: QueryBuilder<T, E>) -> QueryBuilder<T, E>
+    pub fn apply<T, E>(mut self, mut query: QueryBuilder<T, E>) -> QueryBuilder<T, E>
     where
         T: Model + Send + Sync + Unpin + AnyImpl,
         E: Connection + Send,
     {
         // Enforce max_limit again during application to ensure safety
-        if self.limit > self.max_limit {
-            self.limit = self.max_limit;
-        }
+        self.limit = self.effective_limit();
 
+        self.ensure_order(&mut query);
         query.limit(self.limit).offset(self.page * self.limit)
     }
 
@@ -161,51 +213,29 @@ impl Pagination {
     ///     println!("User: {}", user.username);
     /// }
     /// ```
-    pub async fn paginate<T, E, R>(self, mut query: QueryBuilder<T, E>) -> Result<Paginated<R>, sqlx::Error>
+    pub async fn paginate<T, E, R>(mut self, mut query: QueryBuilder<T, E>) -> Result<Paginated<R>, sqlx::Error>
     where
         T: Model + Send + Sync + Unpin + AnyImpl,
         E: Connection + Send,
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        self.limit = self.effective_limit();
+        self.ensure_order(&mut query);
+
         // 1. Prepare COUNT query
-        // We temporarily replace selected columns with COUNT(*) and remove order/limit/offset
+        // We temporarily remove order/limit/offset; count_sql swaps in COUNT(*)
         let original_select = query.select_columns.clone();
         let original_order = query.order_clauses.clone();
         let _original_limit = query.limit;
         let _original_offset = query.offset;
 
-        query.select_columns = vec!["COUNT(*)".to_string()];
         query.order_clauses.clear();
         query.limit = None;
         query.offset = None;
 
         // 2. Generate and Execute Count SQL
         // We cannot use query.scalar() easily because it consumes self.
-        // We use query.to_sql() and construct a manual query execution using the builder's state.
-
-        let count_sql = query.to_sql();
-
-        // We need to re-bind arguments. This logic mirrors QueryBuilder::scan
-        let mut args = sqlx::any::AnyArguments::default();
-        let mut arg_counter = 1;
-
-        // Re-bind arguments for count query
-        // Note: We access internal fields of QueryBuilder. This assumes this module is part of the crate.
-        // If WHERE clauses are complex, this manual reconstruction is necessary.
-        let mut dummy_query = String::new(); // Just to satisfy the closure signature
-        for clause in &query.where_clauses {
-            clause(&mut dummy_query, &mut args, &query.driver, &mut arg_counter);
-        }
-        if !query.having_clauses.is_empty() {
-            for clause in &query.having_clauses {
-                clause(&mut dummy_query, &mut args, &query.driver, &mut arg_counter);
-            }
-        }
-
-        // Execute count query
-        let count_row = query.tx.fetch_one(&count_sql, args).await?;
-
-        let total: i64 = count_row.try_get(0)?;
+        let total = fetch_count(&mut query, "COUNT(*)").await?;
 
         // 3. Restore Query State for Data Fetch
         query.select_columns = original_select;
@@ -241,40 +271,26 @@ impl Pagination {
     ///
     /// * `Ok(Paginated<R>)` - The paginated results mapped to type `R`.
     /// * `Err(sqlx::Error)` - Database error.
-    pub async fn paginate_as<T, E, R>(self, mut query: QueryBuilder<T, E>) -> Result<Paginated<R>, sqlx::Error>
+    pub async fn paginate_as<T, E, R>(mut self, mut query: QueryBuilder<T, E>) -> Result<Paginated<R>, sqlx::Error>
     where
         T: Model + Send + Sync + Unpin + AnyImpl,
         E: Connection + Send,
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        self.limit = self.effective_limit();
+        self.ensure_order(&mut query);
+
         // 1. Prepare COUNT query
         let original_select = query.select_columns.clone();
         let original_order = query.order_clauses.clone();
         let _original_limit = query.limit;
         let _original_offset = query.offset;
     
-        query.select_columns = vec!["COUNT(*)".to_string()];
         query.order_clauses.clear();
         query.limit = None;
         query.offset = None;
     
-        let count_sql = query.to_sql();
-    
-        let mut args = sqlx::any::AnyArguments::default();
-        let mut arg_counter = 1;
-    
-        let mut dummy_query = String::new();
-        for clause in &query.where_clauses {
-            clause(&mut dummy_query, &mut args, &query.driver, &mut arg_counter);
-        }
-        if !query.having_clauses.is_empty() {
-            for clause in &query.having_clauses {
-                clause(&mut dummy_query, &mut args, &query.driver, &mut arg_counter);
-            }
-        }
-    
-        let count_row = query.tx.fetch_one(&count_sql, args).await?;
-        let total: i64 = count_row.try_get(0)?;
+        let total = fetch_count(&mut query, "COUNT(*)").await?;
     
         // 3. Restore Query State
         query.select_columns = original_select;
@@ -290,4 +306,262 @@ impl Pagination {
     
         Ok(Paginated { data, total, page: self.page, limit: self.limit, total_pages })
     }
+
+    /// Executes the query without counting, for infinite-scroll style paging.
+    ///
+    /// Skips the `COUNT(*)` query of [`paginate`](Self::paginate) and fetches
+    /// `limit + 1` rows instead; the extra row only sets `has_next` and is not
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let res: ScrollPage<Post> = Pagination::new(3, 20).paginate_no_count(db.model::<Post>()).await?;
+    /// if res.has_next {
+    ///     // show "load more"
+    /// }
+    /// ```
+    pub async fn paginate_no_count<T, E, R>(mut self, mut query: QueryBuilder<T, E>) -> Result<ScrollPage<R>, sqlx::Error>
+    where
+        T: Model + Send + Sync + Unpin + AnyImpl,
+        E: Connection + Send,
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        self.limit = self.effective_limit();
+        self.ensure_order(&mut query);
+
+        query.limit = Some(self.limit + 1);
+        query.offset = Some(self.page * self.limit);
+        let mut data = query.scan::<R>().await?;
+
+        let has_next = data.len() > self.limit;
+        data.truncate(self.limit);
+
+        Ok(ScrollPage { data, page: self.page, limit: self.limit, has_next })
+    }
+}
+
+// ============================================================================
+// Cursor Pagination
+// ============================================================================
+
+/// A page of results from [`CursorPagination::paginate`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorPage<T> {
+    /// The list of items for the current page
+    pub data: Vec<T>,
+    /// Cursor to pass to [`CursorPagination::after`] for the next page, `None` on the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Keyset (cursor) pagination over a sortable column.
+///
+/// Instead of skipping `page * limit` rows, each page starts after the last
+/// value of `column` seen on the previous page (`WHERE column > cursor`). The
+/// cost does not grow with the page number, and rows inserted meanwhile do not
+/// shift later pages. `column` should be unique, such as the primary key,
+/// otherwise rows sharing a value across a page boundary are skipped.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut cursor = None;
+/// loop {
+///     let page: CursorPage<User> = CursorPagination::new("id", 50)
+///         .after(cursor)
+///         .paginate(db.model::<User>())
+///         .await?;
+///     process(&page.data);
+///     match page.next_cursor {
+///         Some(next) => cursor = Some(next),
+///         None => break,
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CursorPagination {
+    /// Model column the pages are ordered and split by
+    pub column: String,
+    /// Last value of `column` on the previous page; `None` starts from the beginning
+    pub after: Option<String>,
+    /// Number of items per page
+    pub limit: usize,
+    /// Maximum allowed items per page (safety limit)
+    pub max_limit: usize,
+    /// Walk direction; `Desc` pages with `column < cursor`
+    pub direction: OrderDir,
+}
+
+impl CursorPagination {
+    /// Creates ascending cursor pagination over `column` with a safety limit of 100.
+    pub fn new(column: &str, limit: usize) -> Self {
+        Self { column: column.to_string(), after: None, limit: limit.min(100), max_limit: 100, direction: OrderDir::Asc }
+    }
+
+    /// Starts after `cursor`, usually the `next_cursor` of the previous page.
+    pub fn after(mut self, cursor: Option<String>) -> Self {
+        self.after = cursor;
+        self
+    }
+
+    /// Walks the column from the highest value down.
+    pub fn descending(mut self) -> Self {
+        self.direction = OrderDir::Desc;
+        self
+    }
+
+    /// Applies the cursor filter, ordering and limit to a `QueryBuilder`.
+    ///
+    /// The cursor column is placed first in the ORDER BY. An unknown column makes
+    /// the query fail when it runs.
+    pub fn apply<T, E>(&self, query: QueryBuilder<T, E>) -> QueryBuilder<T, E>
+    where
+        T: Model + Send + Sync + Unpin + AnyImpl,
+        E: Connection + Send,
+    {
+        self.apply_with_limit(query, self.limit.min(self.max_limit))
+    }
+
+    /// Executes the query and returns one page plus the cursor for the next one.
+    ///
+    /// Fetches one extra row to tell whether another page exists, so the last
+    /// page reports `next_cursor: None` without an empty follow-up request.
+    /// The next cursor is read from the returned rows, so `R` must include the
+    /// cursor column; a page of rows without it is an error.
+    pub async fn paginate<T, E, R>(&self, query: QueryBuilder<T, E>) -> Result<CursorPage<R>, sqlx::Error>
+    where
+        T: Model + Send + Sync + Unpin + AnyImpl,
+        E: Connection + Send,
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        let limit = self.limit.min(self.max_limit);
+        let mut data = self.apply_with_limit(query, limit + 1).scan::<R>().await?;
+
+        // Without the column every page would look like the last one
+        if let Some(first) = data.first()
+            && !AnyImpl::to_map(first).contains_key(&self.column)
+        {
+            return Err(sqlx::Error::Configuration(
+                format!("Cursor column '{}' is not part of the paginated rows", self.column).into(),
+            ));
+        }
+
+        let next_cursor = if data.len() > limit {
+            data.truncate(limit);
+            data.last().and_then(|last| AnyImpl::to_map(last).remove(&self.column).flatten())
+        } else {
+            None
+        };
+
+        Ok(CursorPage { data, next_cursor })
+    }
+
+    fn apply_with_limit<T, E>(&self, mut query: QueryBuilder<T, E>, limit: usize) -> QueryBuilder<T, E>
+    where
+        T: Model + Send + Sync + Unpin + AnyImpl,
+        E: Connection + Send,
+    {
+        let Some(sql_type) = query.columns_info.iter().find(|c| c.name == self.column).map(|c| c.sql_type) else {
+            query.deferred_error.get_or_insert_with(|| format!("Cannot paginate by unknown column '{}'", self.column));
+            return query;
+        };
+
+        let column = query.driver.quote_identifier(&format!("{}.{}", query.get_table_identifier(), self.column));
+        if let Some(cursor) = self.after.clone() {
+            let op = match self.direction {
+                OrderDir::Asc => ">",
+                OrderDir::Desc => "<",
+            };
+            let column = column.clone();
+            let clause: FilterFn = Box::new(move |sql, args, driver, arg_counter| {
+                sql.push_str(&format!(" AND {} {} ", column, op));
+                push_typed_value(sql, args, driver, arg_counter, &cursor, sql_type);
+            });
+            query.where_clauses.push(clause);
+        }
+
+        query.order_clauses.insert(0, format!("{} {}", column, self.direction.as_sql()));
+        query.limit(limit)
+    }
+}
+
+// ============================================================================
+// Ordering Helpers
+// ============================================================================
+
+impl Pagination {
+    /// Adds a primary key ORDER BY to an unordered query, or warns in debug mode that
+    /// its pages are unstable.
+    fn ensure_order<T, E>(&self, query: &mut QueryBuilder<T, E>)
+    where
+        T: Model + Send + Sync + Unpin + AnyImpl,
+        E: Connection + Send,
+    {
+        if !query.order_clauses.is_empty() {
+            return;
+        }
+
+        let primary_keys: Vec<&str> = query.columns_info.iter().filter(|c| c.is_primary_key).map(|c| c.name).collect();
+        if self.order_by_primary_key && !primary_keys.is_empty() && !query.is_grouped() {
+            let table = query.alias.clone().unwrap_or_else(|| query.table_name.to_string());
+            for pk in primary_keys {
+                let clause = format!("{} ASC", query.driver.quote_identifier(&format!("{}.{}", table, pk)));
+                query.order_clauses.push(clause);
+            }
+        } else if query.debug_mode {
+            log::warn!(
+                "Paginating \"{}\" without ORDER BY; page contents may differ between requests",
+                query.table_name
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Count Helpers
+// ============================================================================
+
+/// Builds the count query for the builder's current filters, with its bound values.
+///
+/// Grouped queries are wrapped in a subquery so the total is the number of groups
+/// rather than the number of rows before grouping. Otherwise the select list is
+/// replaced by `count_expr`; callers restore it afterwards. The SQL and arguments are
+/// written in one pass, so values bound by joins, filters and unions stay in order.
+fn count_sql<T, E>(query: &mut QueryBuilder<T, E>, count_expr: &str) -> (String, AnyArguments<'static>)
+where
+    T: Model + Send + Sync + Unpin + AnyImpl,
+    E: Connection,
+{
+    let mut sql = String::new();
+    let mut args = AnyArguments::default();
+    let mut arg_counter = 1;
+
+    if query.is_grouped() {
+        sql.push_str(&format!("SELECT {} FROM (", count_expr));
+        query.write_select_sql::<T>(&mut sql, &mut args, &mut arg_counter);
+        sql.push_str(") AS bottle_count");
+    } else {
+        query.select_columns = vec![count_expr.to_string()];
+        query.write_select_sql::<T>(&mut sql, &mut args, &mut arg_counter);
+    }
+    (sql, args)
+}
+
+/// Runs the count query built by [`count_sql`] and returns the total.
+pub(crate) async fn fetch_count<T, E>(query: &mut QueryBuilder<T, E>, count_expr: &str) -> Result<i64, sqlx::Error>
+where
+    T: Model + Send + Sync + Unpin + AnyImpl,
+    E: Connection,
+{
+    query.check_deferred_error()?;
+    // The count never locks; a row lock stays on the builder for the page query
+    let row_lock = query.row_lock.take();
+    let (count_sql, args) = count_sql(query, count_expr);
+    query.row_lock = row_lock;
+    if query.debug_mode {
+        log::debug!("SQL: {}", count_sql);
+    }
+
+    let count_row = query.tx.fetch_one(&count_sql, args).await?;
+    count_row.try_get(0)
 }
@@ -0,0 +1,217 @@
+//! # PostgreSQL Range and Geometric Types
+//!
+//! This module provides model field types for PostgreSQL-only column types that
+//! sqlx's `Any` driver cannot encode or decode natively. It is only compiled with
+//! the `postgres-types` feature.
+//!
+//! ## Supported Type Mappings
+//!
+//! - `TsRange` → `TSTZRANGE`
+//! - `Point` → `POINT`
+//!
+//! Values travel as their PostgreSQL text form: they are bound as strings cast to
+//! the column type and selected with a `::TEXT` cast. Filter range columns with
+//! `QueryBuilder::range_contains`.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use bottle_orm::pg_types::TsRange;
+//!
+//! #[derive(Model)]
+//! struct Booking {
+//!     #[orm(primary_key)]
+//!     id: i32,
+//!     during: TsRange, // Maps to TSTZRANGE
+//! }
+//!
+//! let active: Vec<Booking> = db.model::<Booking>()
+//!     .range_contains("during", Utc::now())
+//!     .scan()
+//!     .await?;
+//! ```
+
+// ============================================================================
+// External Crate Imports
+// ============================================================================
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{any::AnyRow, Row};
+use std::{collections::HashMap, fmt, str::FromStr};
+
+// ============================================================================
+// Internal Crate Imports
+// ============================================================================
+
+use crate::{
+    any_struct::{AnyImpl, AnyInfo, FromAnyRow},
+    Error,
+};
+
+// ============================================================================
+// TsRange
+// ============================================================================
+
+/// A `TSTZRANGE` value: a range of UTC timestamps.
+///
+/// A `None` bound is unbounded on that side. Empty ranges are not representable
+/// and fail to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TsRange {
+    /// Lower bound, `None` for unbounded
+    pub start: Option<DateTime<Utc>>,
+    /// Upper bound, `None` for unbounded
+    pub end: Option<DateTime<Utc>>,
+    /// Whether `start` belongs to the range (`[` rather than `(`)
+    pub start_inclusive: bool,
+    /// Whether `end` belongs to the range (`]` rather than `)`)
+    pub end_inclusive: bool,
+}
+
+impl TsRange {
+    /// Creates the half-open range `[start, end)`, PostgreSQL's default.
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self { start: Some(start), end: Some(end), start_inclusive: true, end_inclusive: false }
+    }
+}
+
+impl fmt::Display for TsRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.start_inclusive { "[" } else { "(" })?;
+        if let Some(start) = self.start {
+            write!(f, "\"{}\"", start.to_rfc3339())?;
+        }
+        f.write_str(",")?;
+        if let Some(end) = self.end {
+            write!(f, "\"{}\"", end.to_rfc3339())?;
+        }
+        f.write_str(if self.end_inclusive { "]" } else { ")" })
+    }
+}
+
+impl FromStr for TsRange {
+    type Err = Error;
+
+    /// Parses PostgreSQL's range output, e.g. `["2024-01-01 00:00:00+00","2024-02-01 00:00:00+00")`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || Error::Conversion(format!("Invalid TSTZRANGE value: {}", s));
+        if s.eq_ignore_ascii_case("empty") {
+            return Err(Error::Conversion("Empty TSTZRANGE values are not supported".to_string()));
+        }
+
+        let start_inclusive = match s.chars().next() {
+            Some('[') => true,
+            Some('(') => false,
+            _ => return Err(invalid()),
+        };
+        let end_inclusive = match s.chars().last() {
+            Some(']') => true,
+            Some(')') => false,
+            _ => return Err(invalid()),
+        };
+        let (start, end) = s[1..s.len() - 1].split_once(',').ok_or_else(invalid)?;
+
+        Ok(Self { start: parse_bound(start)?, end: parse_bound(end)?, start_inclusive, end_inclusive })
+    }
+}
+
+/// Parses one range bound; an empty bound is unbounded.
+fn parse_bound(bound: &str) -> Result<Option<DateTime<Utc>>, Error> {
+    let bound = bound.trim().trim_matches('"');
+    if bound.is_empty() {
+        return Ok(None);
+    }
+    DateTime::parse_from_rfc3339(bound)
+        .or_else(|_| DateTime::parse_from_str(bound, "%Y-%m-%d %H:%M:%S%.f%#z"))
+        .map(|dt| Some(dt.with_timezone(&Utc)))
+        .map_err(|e| Error::Conversion(format!("Invalid TSTZRANGE bound '{}': {}", bound, e)))
+}
+
+// ============================================================================
+// Point
+// ============================================================================
+
+/// A `POINT` value.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    /// Horizontal coordinate
+    pub x: f64,
+    /// Vertical coordinate
+    pub y: f64,
+}
+
+impl Point {
+    /// Creates a point from its coordinates.
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl FromStr for Point {
+    type Err = Error;
+
+    /// Parses PostgreSQL's point output, e.g. `(1.5,-2)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::Conversion(format!("Invalid POINT value: {}", s));
+        let inner = s.trim().strip_prefix('(').and_then(|s| s.strip_suffix(')')).ok_or_else(invalid)?;
+        let (x, y) = inner.split_once(',').ok_or_else(invalid)?;
+        Ok(Self { x: x.trim().parse().map_err(|_| invalid())?, y: y.trim().parse().map_err(|_| invalid())? })
+    }
+}
+
+// ============================================================================
+// Row Mapping
+// ============================================================================
+
+macro_rules! impl_text_decoded {
+    ($($t:ty),*) => {
+        $(
+            impl AnyImpl for $t {
+                fn columns() -> Vec<AnyInfo> {
+                    Vec::new()
+                }
+                fn to_map(&self) -> HashMap<String, Option<String>> {
+                    HashMap::new()
+                }
+            }
+
+            impl FromAnyRow for $t {
+                fn from_any_row(row: &AnyRow) -> Result<Self, sqlx::Error> {
+                    let mut index = 0;
+                    Self::from_any_row_at(row, &mut index)
+                }
+
+                fn from_any_row_at(row: &AnyRow, index: &mut usize) -> Result<Self, sqlx::Error> {
+                    if *index >= row.len() {
+                        return Err(sqlx::Error::ColumnIndexOutOfBounds { index: *index, len: row.len() });
+                    }
+                    let text: String = row.try_get(*index)?;
+                    *index += 1;
+                    text.parse().map_err(|e: Error| sqlx::Error::Decode(Box::new(e)))
+                }
+            }
+        )*
+    };
+}
+
+impl_text_decoded!(TsRange, Point);
+
+// ============================================================================
+// Query Builder Support
+// ============================================================================
+
+/// Returns the element type a range column compares against, for `range_contains`.
+pub(crate) fn range_element_type(sql_type: &str) -> Option<&'static str> {
+    match sql_type {
+        "TSTZRANGE" => Some("TIMESTAMPTZ"),
+        _ => None,
+    }
+}
@@ -47,9 +47,9 @@
 // External Crate Imports
 // ============================================================================
 
-use futures::future::BoxFuture;
+use futures::{future::BoxFuture, stream::BoxStream, SinkExt, StreamExt};
 use heck::ToSnakeCase;
-use sqlx::{Any, Arguments, Decode, Encode, Type, any::AnyArguments};
+use sqlx::{Any, Arguments, Decode, Encode, Row, Type, any::{AnyArguments, AnyRow}};
 use std::marker::PhantomData;
 use std::collections::{HashMap, HashSet};
 
@@ -60,11 +60,11 @@ use std::collections::{HashMap, HashSet};
 
 use crate::{
     AnyImpl, Error,
-    any_struct::FromAnyRow,
+    any_struct::{AnyInfo, FromAnyRow},
     database::{Connection, Drivers},
     model::{ColumnInfo, Model},
     temporal::{self, is_temporal_type},
-    value_binding::ValueBinder,
+    value_binding::{Bind, FilterValue, IntoBindValue, ValueBinder},
 };
 
 // ============================================================================
@@ -171,6 +171,8 @@ pub enum Op {
     Like,
     /// SQL NOT LIKE pattern matching
     NotLike,
+    /// Case-insensitive LIKE: `ILIKE` on PostgreSQL, `LOWER(col) LIKE LOWER(?)` elsewhere
+    ILike,
     /// SQL IN (for arrays/lists)
     In,
     /// SQL NOT IN
@@ -179,6 +181,10 @@ pub enum Op {
     Between,
     /// SQL NOT BETWEEN
     NotBetween,
+    /// SQL IS NULL; takes no value (pass `()`)
+    IsNull,
+    /// SQL IS NOT NULL; takes no value (pass `()`)
+    IsNotNull,
 }
 
 impl Op {
@@ -193,10 +199,168 @@ impl Op {
             Op::Lte => "<=",
             Op::Like => "LIKE",
             Op::NotLike => "NOT LIKE",
+            Op::ILike => "ILIKE",
             Op::In => "IN",
             Op::NotIn => "NOT IN",
             Op::Between => "BETWEEN",
             Op::NotBetween => "NOT BETWEEN",
+            Op::IsNull => "IS NULL",
+            Op::IsNotNull => "IS NOT NULL",
+        }
+    }
+
+    /// Returns whether the operator compares against a value.
+    pub(crate) fn takes_value(&self) -> bool {
+        !matches!(self, Op::IsNull | Op::IsNotNull)
+    }
+
+    /// Renders `left <op> right` for the driver.
+    ///
+    /// `right` is ignored by the null checks. Only [`Op::ILike`] differs between
+    /// drivers: MySQL and SQLite have no `ILIKE`, so both sides are lowercased and
+    /// compared with `LIKE`.
+    pub(crate) fn render(&self, driver: Drivers, left: &str, right: &str) -> String {
+        match (self, driver) {
+            (Op::IsNull | Op::IsNotNull, _) => format!("{} {}", left, self.as_sql()),
+            (Op::ILike, Drivers::MySQL | Drivers::SQLite) => format!("LOWER({}) LIKE LOWER({})", left, right),
+            _ => format!("{} {} {}", left, self.as_sql(), right),
+        }
+    }
+}
+
+/// SQL type hint for [`QueryBuilder::filter_typed`].
+///
+/// The `Any` driver binds values without telling the database their type, so a
+/// comparison can fail (PostgreSQL: `operator does not exist: timestamptz > text`)
+/// or compare with the wrong semantics (SQLite comparing a TEXT column as text).
+/// A hint wraps the placeholder in a `CAST` to the driver's spelling of the type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlType {
+    /// 32-bit integer
+    Integer,
+    /// 64-bit integer
+    BigInt,
+    /// Double precision float
+    Double,
+    /// Arbitrary precision decimal
+    Numeric,
+    /// Text
+    Text,
+    /// Boolean
+    Boolean,
+    /// Calendar date
+    Date,
+    /// Timestamp without time zone
+    Timestamp,
+    /// Timestamp with time zone
+    TimestampTz,
+    /// UUID
+    Uuid,
+    /// JSON document
+    Json,
+}
+
+impl SqlType {
+    /// Returns the `CAST(... AS <type>)` target for the driver.
+    pub fn cast_target(&self, driver: Drivers) -> &'static str {
+        match (self, driver) {
+            (SqlType::Integer, Drivers::MySQL) | (SqlType::BigInt, Drivers::MySQL) => "SIGNED",
+            (SqlType::Integer, _) => "INTEGER",
+            (SqlType::BigInt, Drivers::SQLite) => "INTEGER",
+            (SqlType::BigInt, _) => "BIGINT",
+            (SqlType::Double, Drivers::Postgres) => "DOUBLE PRECISION",
+            (SqlType::Double, Drivers::MySQL) => "DOUBLE",
+            (SqlType::Double, Drivers::SQLite) => "REAL",
+            (SqlType::Numeric, Drivers::MySQL) => "DECIMAL(65,30)",
+            (SqlType::Numeric, _) => "NUMERIC",
+            (SqlType::Text, Drivers::MySQL) => "CHAR",
+            (SqlType::Text, _) => "TEXT",
+            (SqlType::Boolean, Drivers::Postgres) => "BOOLEAN",
+            (SqlType::Boolean, Drivers::MySQL) => "UNSIGNED",
+            (SqlType::Boolean, Drivers::SQLite) => "INTEGER",
+            (SqlType::Date, Drivers::SQLite) => "TEXT",
+            (SqlType::Date, _) => "DATE",
+            (SqlType::Timestamp, Drivers::Postgres) => "TIMESTAMP",
+            (SqlType::TimestampTz, Drivers::Postgres) => "TIMESTAMPTZ",
+            (SqlType::Timestamp | SqlType::TimestampTz, Drivers::MySQL) => "DATETIME(6)",
+            (SqlType::Timestamp | SqlType::TimestampTz, Drivers::SQLite) => "TEXT",
+            (SqlType::Uuid, Drivers::Postgres) => "UUID",
+            (SqlType::Uuid, Drivers::MySQL) => "CHAR(36)",
+            (SqlType::Uuid, Drivers::SQLite) => "TEXT",
+            (SqlType::Json, Drivers::Postgres) => "JSONB",
+            (SqlType::Json, Drivers::MySQL) => "JSON",
+            (SqlType::Json, Drivers::SQLite) => "TEXT",
+        }
+    }
+}
+
+/// Sort direction for [`QueryBuilder::order_by`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::OrderDir;
+///
+/// db.model::<User>()
+///     .order_by(user_fields::CREATED_AT, OrderDir::Desc)
+///     .scan()
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderDir {
+    /// Ascending: `ASC`
+    Asc,
+    /// Descending: `DESC`
+    Desc,
+}
+
+impl OrderDir {
+    /// Converts the direction to its SQL keyword.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            OrderDir::Asc => "ASC",
+            OrderDir::Desc => "DESC",
+        }
+    }
+}
+
+/// Aggregate function for [`QueryBuilder::having_agg`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use bottle_orm::{Aggregate, Op};
+///
+/// db.model::<Order>()
+///     .select("customer_id, SUM(total) AS spent")
+///     .group_by_column("customer_id")
+///     .having_agg(Aggregate::Sum, "total", Op::Gt, 1000)
+///     .scan_as::<CustomerSpend>()
+///     .await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// `COUNT`
+    Count,
+    /// `SUM`
+    Sum,
+    /// `AVG`
+    Avg,
+    /// `MIN`
+    Min,
+    /// `MAX`
+    Max,
+}
+
+impl Aggregate {
+    /// Converts the aggregate to its SQL function name.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Aggregate::Count => "COUNT",
+            Aggregate::Sum => "SUM",
+            Aggregate::Avg => "AVG",
+            Aggregate::Min => "MIN",
+            Aggregate::Max => "MAX",
         }
     }
 }
@@ -277,23 +441,51 @@ pub struct QueryBuilder<T, E> {
     /// Activate debug mode in query
     pub(crate) debug_mode: bool,
 
+    /// How long results of this query may be served from the query cache
+    pub(crate) cache_ttl: Option<std::time::Duration>,
+
+    /// Tables read by subqueries, recorded with cached results next to the joined tables
+    pub(crate) subquery_tables: Vec<String>,
+
     /// Clauses for GROUP BY
     pub(crate) group_by_clauses: Vec<String>,
 
+    /// Whether plain columns selected alongside aggregates are grouped automatically
+    pub(crate) auto_group_by: bool,
+
+    /// Column assignments accumulated by `set()` for `update_set()`
+    pub(crate) set_values: Vec<(String, Option<String>)>,
+
     /// Clauses for HAVING
     pub(crate) having_clauses: Vec<FilterFn>,
 
+    /// First error found while the query was built (an unknown column, a rejected
+    /// ORDER BY, ...), reported when the query runs
+    pub(crate) deferred_error: Option<String>,
+
     /// Distinct flag
     pub(crate) is_distinct: bool,
 
+    /// Expressions for a PostgreSQL `DISTINCT ON (...)`
+    pub(crate) distinct_on: Vec<String>,
+
     /// Columns to omit from the query results (inverse of select_columns)
     pub(crate) omit_columns: Vec<String>,
 
     /// Whether to include soft-deleted records in query results
     pub(crate) with_deleted: bool,
 
-    /// UNION and UNION ALL clauses
-    pub(crate) union_clauses: Vec<(String, FilterFn)>,
+    /// Queries combined with UNION or UNION ALL, rendered with the scan's result type
+    pub(crate) unions: Vec<(&'static str, QueryBuilder<T, E>)>,
+
+    /// Row lock appended to the SELECT (`FOR UPDATE` or `FOR SHARE`)
+    pub(crate) row_lock: Option<&'static str>,
+
+    /// Whether locked rows are skipped instead of waited for (`SKIP LOCKED`)
+    pub(crate) skip_locked: bool,
+
+    /// Whether inserts leave out `None` values of columns that declare a default
+    pub(crate) use_defaults: bool,
 
     /// PhantomData to bind the generic type T
     pub(crate) _marker: PhantomData<T>,
@@ -305,7 +497,25 @@ pub struct QueryBuilder<T, E> {
 
 /// A wrapper for relation query modifiers to allow storage in Any-based collections.
 pub struct QueryModifier {
-    pub modifier: std::sync::Arc<dyn Fn(QueryBuilder<crate::any_struct::AnyImplStruct, crate::Database>) -> QueryBuilder<crate::any_struct::AnyImplStruct, crate::Database> + Send + Sync + 'static>,
+    pub modifier: ModifierFn,
+}
+
+/// Closure type stored by [`QueryModifier`] to customize a relation query.
+pub type ModifierFn = std::sync::Arc<
+    dyn Fn(QueryBuilder<crate::any_struct::AnyImplStruct, crate::Database>) -> QueryBuilder<crate::any_struct::AnyImplStruct, crate::Database>
+        + Send
+        + Sync
+        + 'static,
+>;
+
+/// A row together with the related rows loaded for it by
+/// [`QueryBuilder::scan_with_many`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loaded<P, C> {
+    /// The row returned by the main query
+    pub parent: P,
+    /// The related rows attached to `parent`
+    pub children: C,
 }
 
 impl<T, E> QueryBuilder<T, E>
@@ -358,9 +568,8 @@ where
         columns_info: Vec<ColumnInfo>,
         columns: Vec<String>,
     ) -> Self {
-        // Pre-populate omit_columns with globally omitted columns (from #[orm(omit)] attribute)
-        let omit_columns: Vec<String> =
-            columns_info.iter().filter(|c| c.omit).map(|c| c.name.to_snake_case()).collect();
+        // Columns marked `#[orm(omit)]` stay out of the default select list
+        let omit_columns = columns_info.iter().filter(|c| c.omit).map(|c| c.name.to_snake_case()).collect();
 
         Self {
             tx,
@@ -370,19 +579,28 @@ where
             columns_info,
             columns,
             debug_mode: false,
+            cache_ttl: None,
+            subquery_tables: Vec::new(),
             select_columns: Vec::new(),
             where_clauses: Vec::new(),
             order_clauses: Vec::new(),
             joins_clauses: Vec::new(),
             join_aliases: std::collections::HashMap::new(),
             group_by_clauses: Vec::new(),
+            auto_group_by: false,
+            set_values: Vec::new(),
             having_clauses: Vec::new(),
+            deferred_error: None,
             is_distinct: false,
+            distinct_on: Vec::new(),
             omit_columns,
             limit: None,
             offset: None,
             with_deleted: false,
-            union_clauses: Vec::new(),
+            unions: Vec::new(),
+            row_lock: None,
+            skip_locked: false,
+            use_defaults: false,
             with_relations: Vec::new(),
             with_modifiers: std::collections::HashMap::new(),
             _marker: PhantomData,
@@ -391,7 +609,7 @@ where
 
     /// Returns the table name or alias if set.
     pub(crate) fn get_table_identifier(&self) -> String {
-        self.alias.clone().unwrap_or_else(|| self.table_name.to_snake_case())
+        self.alias.clone().unwrap_or_else(|| self.table_name.to_string())
     }
 
     /// Adds a relation to be eager loaded with the query results.
@@ -456,44 +674,89 @@ where
     // ========================================================================
 
     /// Internal helper to add a WHERE clause with a specific join operator.
-    fn filter_internal<V>(mut self, joiner: &str, col: &'static str, op: Op, value: V) -> Self
+    fn filter_internal<V>(self, joiner: &str, col: &'static str, op: Op, values: Vec<V>) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.filter_cast_internal(joiner, col, op, values, None)
+    }
+
+    fn filter_cast_internal<V>(mut self, joiner: &str, col: &'static str, op: Op, values: Vec<V>, hint: Option<SqlType>) -> Self
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        let op_str = op.as_sql();
         let table_id = self.get_table_identifier();
         // Check if the column exists in the main table to avoid ambiguous references in JOINS
         let is_main_col = self.columns.contains(&col.to_snake_case());
+        // Postgres will not compare a native enum column with a text parameter
+        let own_column = match col.split_once('.') {
+            Some((table, column)) if table == self.table_name || table == table_id => column,
+            Some(_) => "",
+            None => col,
+        };
+        let enum_type = self
+            .columns_info
+            .iter()
+            .find(|c| c.pg_enum.is_some() && c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case() == own_column)
+            .map(|c| c.sql_type);
         let joiner_owned = joiner.to_string();
+        // Null checks bind nothing, whatever value was passed
+        let values = if op.takes_value() { values } else { Vec::new() };
         let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
             query.push_str(&joiner_owned);
-            if let Some((table, column)) = col.split_once(".") {
+
+            // An empty list matches nothing for IN and everything for NOT IN
+            if values.is_empty() && matches!(op, Op::In | Op::NotIn) {
+                query.push_str(if op == Op::In { "1=0" } else { "1=1" });
+                return;
+            }
+
+            let column = if let Some((table, column)) = col.split_once(".") {
                 // If explicit table prefix is provided, use it
-                query.push_str(&format!("\"{}\".\"{}\"", table, column));
+                format!("\"{}\".\"{}\"", table, column)
             } else if is_main_col {
                 // If it's a known column of the main table, apply the table name/alias prefix
-                query.push_str(&format!("\"{}\".\"{}\"", table_id, col));
+                format!("\"{}\".\"{}\"", table_id, col)
             } else {
                 // Otherwise leave it unqualified so the DB can resolve it (or fail if ambiguous)
-                query.push_str(&format!("\"{}\"", col));
-            }
-            query.push(' ');
-            query.push_str(op_str);
-            query.push(' ');
+                format!("\"{}\"", col)
+            };
 
             // Handle different placeholder syntaxes based on database driver
-            match driver {
-                // PostgreSQL uses numbered placeholders: $1, $2, $3, ...
-                Drivers::Postgres => {
-                    query.push_str(&format!("${}", arg_counter));
-                    *arg_counter += 1;
-                }
-                // MySQL and SQLite use question mark placeholders: ?
-                _ => query.push('?'),
-            }
+            let placeholders: Vec<String> = values
+                .iter()
+                .map(|_| {
+                    let placeholder = match driver {
+                        // PostgreSQL uses numbered placeholders: $1, $2, $3, ...
+                        Drivers::Postgres => {
+                            let placeholder = format!("${}", arg_counter);
+                            *arg_counter += 1;
+                            placeholder
+                        }
+                        // MySQL and SQLite use question mark placeholders: ?
+                        _ => "?".to_string(),
+                    };
+                    match (hint, enum_type) {
+                        (Some(hint), _) => format!("CAST({} AS {})", placeholder, hint.cast_target(*driver)),
+                        (None, Some(sql_type)) if matches!(driver, Drivers::Postgres) => {
+                            format!("{}::{}", placeholder, sql_type)
+                        }
+                        (None, _) => placeholder,
+                    }
+                })
+                .collect();
 
-            // Bind the value to the query
-            let _ = args.add(value.clone());
+            let right = match placeholders.len() {
+                1 if !matches!(op, Op::In | Op::NotIn) => placeholders[0].clone(),
+                0 => "NULL".to_string(),
+                _ => format!("({})", placeholders.join(", ")),
+            };
+            query.push_str(&op.render(*driver, &column, &right));
+
+            // Bind the values to the query
+            for value in &values {
+                let _ = args.add(value.clone());
+            }
         });
 
         self.where_clauses.push(clause);
@@ -515,23 +778,155 @@ where
         SE: Connection + 'static,
     {
         subquery.apply_soft_delete_filter();
+        self.subquery_tables.extend(subquery.read_tables());
         let table_id = self.get_table_identifier();
         let is_main_col = self.columns.contains(&col.to_snake_case());
-        let op_str = op.as_sql();
 
-        let clause: FilterFn = Box::new(move |query, args, _driver, arg_counter| {
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
             query.push_str(" AND ");
-            if let Some((table, column)) = col.split_once(".") {
-                query.push_str(&format!("\"{}\".\"{}\"", table, column));
+            let column = if let Some((table, column)) = col.split_once(".") {
+                format!("\"{}\".\"{}\"", table, column)
             } else if is_main_col {
-                query.push_str(&format!("\"{}\".\"{}\"", table_id, col));
+                format!("\"{}\".\"{}\"", table_id, col)
             } else {
-                query.push_str(&format!("\"{}\"", col));
+                format!("\"{}\"", col)
+            };
+
+            // Null checks ignore the subquery, so it is neither written nor bound
+            let mut right = String::new();
+            if op.takes_value() {
+                right.push('(');
+                subquery.write_select_sql::<S>(&mut right, args, arg_counter);
+                right.push(')');
             }
-            query.push_str(&format!(" {} (", op_str));
+            query.push_str(&op.render(*driver, &column, &right));
+        });
+
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Keeps the rows whose `col` is among the values returned by `subquery`.
+    ///
+    /// Shorthand for [`filter_subquery`](Self::filter_subquery) with [`Op::In`]. The
+    /// subquery should select a single column. Its bound values are numbered after
+    /// the ones added to this builder before it, so filters on both sides may use
+    /// parameters, in any order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let active = db.model::<User>().select("id").filter("last_seen", Op::Gt, cutoff);
+    /// let posts: Vec<Post> = db.model::<Post>()
+    ///     .filter("published", Op::Eq, true)
+    ///     .where_in_subquery("user_id", active)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn where_in_subquery<S, SE>(self, col: &'static str, subquery: QueryBuilder<S, SE>) -> Self
+    where
+        S: Model + Send + Sync + Unpin + AnyImpl + 'static,
+        SE: Connection + 'static,
+    {
+        self.filter_subquery(col, Op::In, subquery)
+    }
+
+    /// Keeps the rows whose `col` is not among the values returned by `subquery`.
+    ///
+    /// Shorthand for [`filter_subquery`](Self::filter_subquery) with [`Op::NotIn`].
+    /// As with any `NOT IN`, a `NULL` in the subquery's results matches no row.
+    pub fn where_not_in_subquery<S, SE>(self, col: &'static str, subquery: QueryBuilder<S, SE>) -> Self
+    where
+        S: Model + Send + Sync + Unpin + AnyImpl + 'static,
+        SE: Connection + 'static,
+    {
+        self.filter_subquery(col, Op::NotIn, subquery)
+    }
+
+    /// Keeps the rows for which `subquery` returns at least one row.
+    ///
+    /// Renders `EXISTS (SELECT 1 FROM ...)`, replacing the subquery's select list.
+    /// To correlate it with this query, compare its columns against this table's
+    /// with [`where_column`](Self::where_column), naming this table (or its alias)
+    /// explicitly. The subquery's bound values are spliced in after the ones added
+    /// before it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Users with at least one post
+    /// let authors: Vec<User> = db.model::<User>()
+    ///     .where_exists(db.model::<Post>().where_column("user_id", Op::Eq, "user.id"))
+    ///     .scan()
+    ///     .await?;
+    /// // SQL: ... WHERE 1=1 AND EXISTS (SELECT 1 FROM "post" WHERE 1=1 AND "post"."user_id" = "user"."id")
+    /// ```
+    pub fn where_exists<S, SE>(self, subquery: QueryBuilder<S, SE>) -> Self
+    where
+        S: Model + Send + Sync + Unpin + AnyImpl + 'static,
+        SE: Connection + 'static,
+    {
+        self.exists_clause("EXISTS", subquery)
+    }
+
+    /// Keeps the rows for which `subquery` returns no row.
+    ///
+    /// The negation of [`where_exists`](Self::where_exists).
+    pub fn where_not_exists<S, SE>(self, subquery: QueryBuilder<S, SE>) -> Self
+    where
+        S: Model + Send + Sync + Unpin + AnyImpl + 'static,
+        SE: Connection + 'static,
+    {
+        self.exists_clause("NOT EXISTS", subquery)
+    }
+
+    fn exists_clause<S, SE>(mut self, keyword: &'static str, mut subquery: QueryBuilder<S, SE>) -> Self
+    where
+        S: Model + Send + Sync + Unpin + AnyImpl + 'static,
+        SE: Connection + 'static,
+    {
+        // The subquery never runs on its own, so its errors surface through this query
+        if let Some(error) = subquery.deferred_error.take() {
+            self.deferred_error.get_or_insert(error);
+        }
+        subquery.apply_soft_delete_filter();
+        self.subquery_tables.extend(subquery.read_tables());
+        subquery.select_columns = vec!["1".to_string()];
+        subquery.order_clauses.clear();
 
+        let clause: FilterFn = Box::new(move |query, args, _driver, arg_counter| {
+            query.push_str(&format!(" AND {} (", keyword));
             subquery.write_select_sql::<S>(query, args, arg_counter);
-            query.push_str(")");
+            query.push(')');
+        });
+
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Compares two columns instead of a column and a value.
+    ///
+    /// A name without a `.` refers to a column of this query's table; `table.column`
+    /// names any other table in scope, such as a joined table or, inside a
+    /// [`where_exists`](Self::where_exists) subquery, the outer query's table.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Orders shipped after their due date
+    /// let late: Vec<Order> = db.model::<Order>().where_column("shipped_at", Op::Gt, "due_at").scan().await?;
+    /// ```
+    pub fn where_column(mut self, col: &'static str, op: Op, other: &'static str) -> Self {
+        let table_id = self.get_table_identifier();
+        let qualify = move |name: &str| match name.split_once('.') {
+            Some((table, column)) => format!("\"{}\".\"{}\"", table, column),
+            None => format!("\"{}\".\"{}\"", table_id, name),
+        };
+        let (left, right) = (qualify(col), qualify(other));
+
+        let clause: FilterFn = Box::new(move |query, _args, driver, _arg_counter| {
+            query.push_str(" AND ");
+            query.push_str(&op.render(*driver, &left, &right));
         });
 
         self.where_clauses.push(clause);
@@ -554,7 +949,7 @@ where
     /// db.model::<Log>().truncate().await?;
     /// ```
     pub async fn truncate(self) -> Result<(), sqlx::Error> {
-        let table_name = self.table_name.to_snake_case();
+        let table_name = self.table_name.to_string();
         let query = match self.driver {
             Drivers::Postgres | Drivers::MySQL => format!("TRUNCATE TABLE \"{}\"", table_name),
             Drivers::SQLite => format!("DELETE FROM \"{}\"", table_name),
@@ -565,6 +960,7 @@ where
         }
 
         self.tx.execute(&query, AnyArguments::default()).await?;
+        self.invalidate_cache();
         
         // For SQLite, reset auto-increment if exists
         if matches!(self.driver, Drivers::SQLite) {
@@ -579,6 +975,12 @@ where
     /// This method allows you to combine the result sets of two queries into a single
     /// result set. Duplicate rows are removed by default.
     ///
+    /// Each side is rendered as its own parenthesized SELECT, so its filters, ORDER BY
+    /// and LIMIT apply to it alone, and bound values follow the order of the queries.
+    /// Both sides must project the same number of columns; the query is rejected when
+    /// it runs otherwise. Both are rendered with the type being scanned, so scanning
+    /// into a DTO projects the DTO's columns on every side.
+    ///
     /// # Arguments
     ///
     /// * `other` - Another QueryBuilder instance to combine with.
@@ -586,18 +988,19 @@ where
     /// # Example
     ///
     /// ```rust,ignore
-    /// let q1 = db.model::<User>().filter("age", ">", 18);
-    /// let q2 = db.model::<User>().filter("status", "=", "premium");
+    /// let q1 = db.model::<User>().filter("age", Op::Gt, 18);
+    /// let q2 = db.model::<User>().filter("status", Op::Eq, "premium");
     /// let results = q1.union(q2).scan().await?;
     /// ```
-    pub fn union(self, other: QueryBuilder<T, E>) -> Self where T: AnyImpl + 'static, E: 'static {
+    pub fn union(self, other: QueryBuilder<T, E>) -> Self {
         self.union_internal("UNION", other)
     }
 
     /// Combines the results of this query with another query using UNION ALL.
     ///
     /// This method allows you to combine the result sets of two queries into a single
-    /// result set, including all duplicates.
+    /// result set, including all duplicates. See [`union`](Self::union) for how the
+    /// queries are rendered.
     ///
     /// # Arguments
     ///
@@ -606,27 +1009,42 @@ where
     /// # Example
     ///
     /// ```rust,ignore
-    /// let q1 = db.model::<User>().filter("age", ">", 18);
-    /// let q2 = db.model::<User>().filter("status", "=", "premium");
+    /// let q1 = db.model::<User>().filter("age", Op::Gt, 18);
+    /// let q2 = db.model::<User>().filter("status", Op::Eq, "premium");
     /// let results = q1.union_all(q2).scan().await?;
     /// ```
-    pub fn union_all(self, other: QueryBuilder<T, E>) -> Self where T: AnyImpl + 'static, E: 'static {
+    pub fn union_all(self, other: QueryBuilder<T, E>) -> Self {
         self.union_internal("UNION ALL", other)
     }
 
-    fn union_internal(mut self, op: &str, mut other: QueryBuilder<T, E>) -> Self where T: AnyImpl + 'static, E: 'static {
+    fn union_internal(mut self, op: &'static str, mut other: QueryBuilder<T, E>) -> Self {
         other.apply_soft_delete_filter();
-        let op_owned = op.to_string();
-        
-        self.union_clauses.push((op_owned.clone(), Box::new(move |query: &mut String, args: &mut AnyArguments<'_>, _driver: &Drivers, arg_counter: &mut usize| {
-            query.push_str(" ");
-            query.push_str(&op_owned);
-            query.push_str(" ");
-            other.write_select_sql::<T>(query, args, arg_counter);
-        })));
+        self.unions.push((op, other));
         self
     }
 
+    /// Rejects a UNION whose queries project different numbers of columns or lock rows.
+    fn check_unions<R: AnyImpl>(&self) -> Result<(), sqlx::Error> {
+        let width_of = |query: &Self| query.select_args_sql::<R>().iter().map(|s| split_top_level(s).len()).sum::<usize>();
+        for (op, other) in &self.unions {
+            other.check_unions::<R>()?;
+            // Row locks are not allowed on the members of a UNION
+            if self.row_lock.is_some() || other.row_lock.is_some() {
+                return Err(sqlx::Error::Configuration(
+                    format!("for_update()/for_share() cannot be combined with {}", op).into(),
+                ));
+            }
+            let (width, other_width) = (width_of(self), width_of(other));
+            if other_width != width {
+                return Err(sqlx::Error::Configuration(
+                    format!("{} of \"{}\" selects {} column(s) but the combined query selects {}", op, other.table_name, other_width, width)
+                        .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Internal helper to write the SELECT SQL to a string buffer.
     pub(crate) fn write_select_sql<R: AnyImpl>(
         &self,
@@ -634,9 +1052,27 @@ where
         args: &mut AnyArguments,
         arg_counter: &mut usize,
     ) {
+        if self.unions.is_empty() {
+            self.write_single_select_sql::<R>(query, args, arg_counter);
+            return;
+        }
+
+        write_union_member(self.driver, query, |query| self.write_single_select_sql::<R>(query, args, arg_counter));
+        for (op, other) in &self.unions {
+            query.push(' ');
+            query.push_str(op);
+            query.push(' ');
+            write_union_member(self.driver, query, |query| other.write_select_sql::<R>(query, args, arg_counter));
+        }
+    }
+
+    /// Writes this query's own SELECT, without the queries combined into it.
+    fn write_single_select_sql<R: AnyImpl>(&self, query: &mut String, args: &mut AnyArguments, arg_counter: &mut usize) {
         query.push_str("SELECT ");
 
-        if self.is_distinct {
+        if !self.distinct_on.is_empty() {
+            query.push_str(&format!("DISTINCT ON ({}) ", self.distinct_on.join(", ")));
+        } else if self.is_distinct {
             query.push_str("DISTINCT ");
         }
 
@@ -644,7 +1080,7 @@ where
 
         // Build FROM clause
         query.push_str(" FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
+        query.push_str(self.table_name);
         query.push_str("\" ");
         if let Some(alias) = &self.alias {
             query.push_str(&format!("\"{}\" ", alias));
@@ -665,8 +1101,12 @@ where
         }
 
         // Apply GROUP BY
-        if !self.group_by_clauses.is_empty() {
-            query.push_str(&format!(" GROUP BY {}", self.group_by_clauses.join(", ")));
+        let mut group_by = self.group_by_clauses.clone();
+        if self.auto_group_by {
+            group_by.extend(self.ungrouped_select_columns());
+        }
+        if !group_by.is_empty() {
+            query.push_str(&format!(" GROUP BY {}", group_by.join(", ")));
         }
 
         // Apply HAVING
@@ -708,9 +1148,15 @@ where
             let _ = args.add(offset as i64);
         }
 
-        // Apply UNION clauses
-        for (_op, clause) in &self.union_clauses {
-            clause(query, args, &self.driver, arg_counter);
+        // Apply row locking; SQLite has no row locks
+        if let Some(lock) = self.row_lock
+            && !matches!(self.driver, Drivers::SQLite)
+        {
+            query.push(' ');
+            query.push_str(lock);
+            if self.skip_locked {
+                query.push_str(" SKIP LOCKED");
+            }
         }
     }
 
@@ -728,7 +1174,8 @@ where
     ///
     /// * `col` - The column name to filter on
     /// * `op` - The comparison operator (e.g., "=", ">", "LIKE", "IN")
-    /// * `value` - The value to compare against
+    /// * `value` - The value to compare against: a list (`Vec`, array or slice) for
+    ///   [`Op::In`] and [`Op::NotIn`], and `()` for [`Op::IsNull`] and [`Op::IsNotNull`]
     ///
     /// # Example
     ///
@@ -749,9 +1196,31 @@ where
     /// ```
     pub fn filter<V>(self, col: &'static str, op: Op, value: V) -> Self
     where
-        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        V: FilterValue,
+    {
+        self.filter_internal(" AND ", col, op, value.into_filter_values())
+    }
+
+    /// Adds a WHERE clause whose bound value is cast to an explicit SQL type.
+    ///
+    /// Works like [`filter`](Self::filter), but the placeholder is written as
+    /// `CAST(? AS <type>)`. Use it when the `Any` driver's untyped parameter makes
+    /// a comparison fail or compare the wrong way, e.g. a timestamp string against
+    /// a PostgreSQL `TIMESTAMPTZ` column, or a number against a SQLite TEXT column.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<Event>()
+    ///     .filter_typed("starts_at", Op::Gt, "2024-01-01T00:00:00Z".to_string(), SqlType::TimestampTz)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn filter_typed<V>(self, col: &'static str, op: Op, value: V, sql_type: SqlType) -> Self
+    where
+        V: FilterValue,
     {
-        self.filter_internal(" AND ", col, op, value)
+        self.filter_cast_internal(" AND ", col, op, value.into_filter_values(), Some(sql_type))
     }
 
     /// Adds an OR WHERE clause to the query.
@@ -784,9 +1253,9 @@ where
     /// ```
     pub fn or_filter<V>(self, col: &'static str, op: Op, value: V) -> Self
     where
-        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        V: FilterValue,
     {
-        self.filter_internal(" OR ", col, op, value)
+        self.filter_internal(" OR ", col, op, value.into_filter_values())
     }
 
     /// Adds an AND NOT WHERE clause to the query.
@@ -816,9 +1285,9 @@ where
     /// ```
     pub fn not_filter<V>(self, col: &'static str, op: Op, value: V) -> Self
     where
-        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        V: FilterValue,
     {
-        self.filter_internal(" AND NOT ", col, op, value)
+        self.filter_internal(" AND NOT ", col, op, value.into_filter_values())
     }
 
     /// Adds an OR NOT WHERE clause to the query.
@@ -851,18 +1320,23 @@ where
     /// ```
     pub fn or_not_filter<V>(self, col: &'static str, op: Op, value: V) -> Self
     where
-        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        V: FilterValue,
     {
-        self.filter_internal(" OR NOT ", col, op, value)
+        self.filter_internal(" OR NOT ", col, op, value.into_filter_values())
     }
 
-    /// Adds a BETWEEN clause to the query.
+    /// Adds a bitwise AND WHERE clause to the query.
+    ///
+    /// Emits `("col" & mask) op value`, binding both the mask and the value. Useful for
+    /// flag or permission bitmask columns. The `&` operator is understood by PostgreSQL,
+    /// MySQL and SQLite alike.
     ///
     /// # Arguments
     ///
-    /// * `col` - The column name
-    /// * `start` - The start value of the range
-    /// * `end` - The end value of the range
+    /// * `col` - The column name holding the bitmask
+    /// * `mask` - The bits to keep
+    /// * `op` - The comparison operator applied to the masked value
+    /// * `value` - The value to compare the masked column against
     ///
     /// # Example
     ///
@@ -872,48 +1346,160 @@ where
     /// # struct User {
     /// #     #[orm(primary_key)]
     /// #     id: i32,
-    /// #     age: i32,
+    /// #     permissions: i64,
     /// # }
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// #     let db = Database::connect("sqlite::memory:").await?;
-    /// let query = db.model::<User>().between("age", 18, 30);
+    /// const CAN_WRITE: i64 = 0b10;
+    /// let query = db.model::<User>().filter_bitand("permissions", CAN_WRITE, Op::Eq, CAN_WRITE);
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn between<V>(mut self, col: &'static str, start: V, end: V) -> Self
+    pub fn filter_bitand<M, V>(mut self, col: &'static str, mask: M, op: Op, value: V) -> Self
     where
+        M: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
         let table_id = self.get_table_identifier();
         let is_main_col = self.columns.contains(&col.to_snake_case());
         let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
-            query.push_str(" AND ");
-            if let Some((table, column)) = col.split_once(".") {
-                query.push_str(&format!("\"{}\".\"{}\"", table, column));
+            let column = if let Some((table, column)) = col.split_once(".") {
+                format!("\"{}\".\"{}\"", table, column)
             } else if is_main_col {
-                query.push_str(&format!("\"{}\".\"{}\"", table_id, col));
+                format!("\"{}\".\"{}\"", table_id, col)
             } else {
-                query.push_str(&format!("\"{}\"", col));
-            }
-            query.push_str(" BETWEEN ");
+                format!("\"{}\"", col)
+            };
 
-            match driver {
+            let mut next_placeholder = || match driver {
                 Drivers::Postgres => {
-                    query.push_str(&format!("${} AND ${}", arg_counter, *arg_counter + 1));
-                    *arg_counter += 2;
+                    let placeholder = format!("${}", arg_counter);
+                    *arg_counter += 1;
+                    placeholder
                 }
-                _ => query.push_str("? AND ?"),
-            }
+                _ => "?".to_string(),
+            };
+            let masked = format!("({} & {})", column, next_placeholder());
+            let _ = args.add(mask.clone());
+            let right = if op.takes_value() {
+                let _ = args.add(value.clone());
+                next_placeholder()
+            } else {
+                String::new()
+            };
 
-            let _ = args.add(start.clone());
-            let _ = args.add(end.clone());
+            query.push_str(" AND ");
+            query.push_str(&op.render(*driver, &masked, &right));
         });
         self.where_clauses.push(clause);
         self
     }
 
-    /// Adds an OR BETWEEN clause to the query.
+    /// Adds a WHERE clause comparing a column to the database server's current time.
+    ///
+    /// Emits `col op <now>` with nothing bound, so the comparison uses the server's
+    /// clock instead of the application's. The current time is `CURRENT_TIMESTAMP` on
+    /// PostgreSQL and `UTC_TIMESTAMP(6)` on MySQL, matching the UTC values the ORM
+    /// stores. SQLite keeps timestamps as text, so both sides are compared through
+    /// `julianday()` there, which also disables index use on the column.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The timestamp column to compare
+    /// * `op` - The comparison operator
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let active: Vec<Session> = db.model::<Session>()
+    ///     .filter_now("expires_at", Op::Gt)
+    ///     .scan()
+    ///     .await?;
+    /// // SQL (PostgreSQL): ... WHERE "session"."expires_at" > CURRENT_TIMESTAMP
+    /// ```
+    pub fn filter_now(mut self, col: &'static str, op: Op) -> Self {
+        let table_id = self.get_table_identifier();
+        let is_main_col = self.columns.contains(&col.to_snake_case());
+        let clause: FilterFn = Box::new(move |query, _args, driver, _arg_counter| {
+            let column = if let Some((table, column)) = col.split_once(".") {
+                format!("\"{}\".\"{}\"", table, column)
+            } else if is_main_col {
+                format!("\"{}\".\"{}\"", table_id, col)
+            } else {
+                format!("\"{}\"", col)
+            };
+
+            let (left, right) = match driver {
+                Drivers::Postgres => (column, "CURRENT_TIMESTAMP".to_string()),
+                Drivers::MySQL => (column, "UTC_TIMESTAMP(6)".to_string()),
+                Drivers::SQLite => (format!("julianday({})", column), "julianday('now')".to_string()),
+            };
+            query.push_str(" AND ");
+            query.push_str(&op.render(*driver, &left, &right));
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Adds a BETWEEN clause to the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column name
+    /// * `start` - The start value of the range
+    /// * `end` - The end value of the range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bottle_orm::{Database, Model, Op};
+    /// # #[derive(Model, Debug, Clone)]
+    /// # struct User {
+    /// #     #[orm(primary_key)]
+    /// #     id: i32,
+    /// #     age: i32,
+    /// # }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let db = Database::connect("sqlite::memory:").await?;
+    /// let query = db.model::<User>().between("age", 18, 30);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn between<V>(mut self, col: &'static str, start: V, end: V) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        let table_id = self.get_table_identifier();
+        let is_main_col = self.columns.contains(&col.to_snake_case());
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+            query.push_str(" AND ");
+            if let Some((table, column)) = col.split_once(".") {
+                query.push_str(&format!("\"{}\".\"{}\"", table, column));
+            } else if is_main_col {
+                query.push_str(&format!("\"{}\".\"{}\"", table_id, col));
+            } else {
+                query.push_str(&format!("\"{}\"", col));
+            }
+            query.push_str(" BETWEEN ");
+
+            match driver {
+                Drivers::Postgres => {
+                    query.push_str(&format!("${} AND ${}", arg_counter, *arg_counter + 1));
+                    *arg_counter += 2;
+                }
+                _ => query.push_str("? AND ?"),
+            }
+
+            let _ = args.add(start.clone());
+            let _ = args.add(end.clone());
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Adds an OR BETWEEN clause to the query.
     ///
     /// # Arguments
     ///
@@ -1044,6 +1630,78 @@ where
         self
     }
 
+    /// Adds an IN list clause filled with the values another query plucks.
+    ///
+    /// Runs `other.pluck(other_column)` right away and passes the resulting
+    /// values to [`in_list`](Self::in_list). This costs two round trips and binds
+    /// one parameter per value, so it suits small ID sets; for large sets a
+    /// subquery keeps the work in the database. Because the values are read
+    /// first, later changes to the other table are not seen by this query.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let users: Vec<User> = db.model::<User>()
+    ///     .in_plucked::<Ban, _, i32>("id", db.model::<Ban>().filter("active", Op::Eq, 1), "user_id")
+    ///     .await?
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub async fn in_plucked<U, F, V>(
+        self,
+        col: &'static str,
+        other: QueryBuilder<U, F>,
+        other_column: &str,
+    ) -> Result<Self, sqlx::Error>
+    where
+        U: Model + Send + Sync + Unpin + AnyImpl,
+        F: Connection,
+        V: FromAnyRow + AnyImpl + Unpin + 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        let values = other.pluck::<V>(other_column).await?;
+        Ok(self.in_list(col, values))
+    }
+
+    /// Keeps rows whose range column contains `value` (`col @> value`).
+    ///
+    /// PostgreSQL only: on other drivers the query fails when it runs, as it does
+    /// when `col` is not a range column. Requires the `postgres-types` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let current: Vec<Booking> = db.model::<Booking>()
+    ///     .range_contains("during", Utc::now())
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "postgres-types")]
+    pub fn range_contains<V: ToUpdateValue>(mut self, col: &'static str, value: V) -> Self {
+        if self.driver != Drivers::Postgres {
+            self.deferred_error
+                .get_or_insert_with(|| format!("range_contains() requires PostgreSQL; {:?} has no range types", self.driver));
+            return self;
+        }
+        let element_type = self
+            .columns_info
+            .iter()
+            .find(|c| c.name == col)
+            .and_then(|c| crate::pg_types::range_element_type(c.sql_type));
+        let (Some(element_type), Some(value)) = (element_type, value.to_update_value()) else {
+            self.deferred_error
+                .get_or_insert_with(|| format!("range_contains() needs a range column and a non-NULL value, got '{}'", col));
+            return self;
+        };
+
+        let column = self.driver.quote_identifier(&format!("{}.{}", self.get_table_identifier(), col));
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+            query.push_str(&format!(" AND {} @> ", column));
+            push_typed_value(query, args, driver, arg_counter, &value, element_type);
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
     /// Adds an OR IN list clause to the query.
     ///
     /// # Arguments
@@ -1113,6 +1771,114 @@ where
         self
     }
 
+    /// Adds a NOT IN list clause to the query.
+    ///
+    /// An empty list excludes nothing, so it matches every row. Rows where the
+    /// column is NULL never match `NOT IN`. The column must be one of the model's
+    /// columns, a select alias or a known `table.column`; anything else makes the
+    /// query fail when it runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column name
+    /// * `values` - The values to exclude
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bottle_orm::{Database, Model, Op};
+    /// # #[derive(Model, Debug, Clone)]
+    /// # struct User {
+    /// #     #[orm(primary_key)]
+    /// #     id: i32,
+    /// #     status: String,
+    /// # }
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let db = Database::connect("sqlite::memory:").await?;
+    /// let query = db.model::<User>().not_in_list("status", vec!["banned".to_string(), "deleted".to_string()]);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn not_in_list<V>(self, col: &'static str, values: Vec<V>) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.not_in_list_internal(" AND ", col, values)
+    }
+
+    /// Adds an OR NOT IN list clause to the query.
+    ///
+    /// An empty list excludes nothing, so the OR branch matches every row.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let query = db.model::<User>()
+    ///     .filter("role", Op::Eq, "admin".to_string())
+    ///     .or_not_in_list("status", vec!["banned".to_string()]);
+    /// ```
+    pub fn or_not_in_list<V>(self, col: &'static str, values: Vec<V>) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.not_in_list_internal(" OR ", col, values)
+    }
+
+    fn not_in_list_internal<V>(mut self, connector: &'static str, col: &'static str, values: Vec<V>) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        if !self.is_orderable_column(col) {
+            self.deferred_error.get_or_insert_with(|| format!("Cannot filter on unknown column '{}'", col));
+            return self;
+        }
+
+        if values.is_empty() {
+            // Nothing is excluded; an OR branch must still match everything
+            if connector == " OR " {
+                let clause: FilterFn = Box::new(|query, _, _, _| {
+                    query.push_str(" OR 1=1");
+                });
+                self.where_clauses.push(clause);
+            }
+            return self;
+        }
+
+        let table_id = self.get_table_identifier();
+        let is_main_col = self.columns.contains(&col.to_snake_case());
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+            query.push_str(connector);
+            if let Some((table, column)) = col.split_once(".") {
+                query.push_str(&format!("\"{}\".\"{}\"", table, column));
+            } else if is_main_col {
+                query.push_str(&format!("\"{}\".\"{}\"", table_id, col));
+            } else {
+                query.push_str(&format!("\"{}\"", col));
+            }
+            query.push_str(" NOT IN (");
+
+            let mut placeholders = Vec::new();
+            for _ in &values {
+                match driver {
+                    Drivers::Postgres => {
+                        placeholders.push(format!("${}", arg_counter));
+                        *arg_counter += 1;
+                    }
+                    _ => placeholders.push("?".to_string()),
+                }
+            }
+            query.push_str(&placeholders.join(", "));
+            query.push(')');
+
+            for val in &values {
+                let _ = args.add(val.clone());
+            }
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
     /// Groups filters inside parentheses with an AND operator.
     ///
     /// This allows for constructing complex WHERE clauses with nested logic.
@@ -1146,7 +1912,7 @@ where
                 for c in &group_clauses {
                     c(query, args, driver, arg_counter);
                 }
-                query.push_str(")");
+                query.push(')');
             });
             self.where_clauses.push(clause);
         }
@@ -1184,7 +1950,7 @@ where
                 for c in &group_clauses {
                     c(query, args, driver, arg_counter);
                 }
-                query.push_str(")");
+                query.push(')');
             });
             self.where_clauses.push(clause);
         }
@@ -1302,7 +2068,7 @@ where
     /// ```
     pub fn equals<V>(self, col: &'static str, value: V) -> Self
     where
-        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+        V: IntoBindValue,
     {
         self.filter(col, Op::Eq, value)
     }
@@ -1333,11 +2099,73 @@ where
     ///     .order("priority DESC")
     ///     .order("created_at ASC")
     /// ```
+    ///
+    /// The expression is used as written, so it must not come from user input; prefer
+    /// [`order_by`](Self::order_by) for that. Expressions containing `;` or SQL
+    /// comments are rejected when the query runs.
     pub fn order(mut self, order: &str) -> Self {
+        if order.contains(';') || order.contains("--") || order.contains("/*") {
+            self.deferred_error.get_or_insert_with(|| format!("Invalid ORDER BY expression '{}'", order));
+            return self;
+        }
         self.order_clauses.push(order.to_string());
         self
     }
 
+    /// Adds an ORDER BY on a single column with an explicit direction.
+    ///
+    /// The column must be one of the model's columns, an alias from `select()`, or a
+    /// `table.column` pair whose table is the model's table, its alias, or a joined
+    /// table. It is quoted for the driver. Anything else, such as a value taken from a
+    /// request that carries SQL, makes the query fail when it runs instead of being
+    /// spliced into the statement.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let users: Vec<User> = db.model::<User>()
+    ///     .order_by(user_fields::AGE, OrderDir::Desc)
+    ///     .order_by("username", OrderDir::Asc)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn order_by(mut self, column: &str, dir: OrderDir) -> Self {
+        if self.is_orderable_column(column) {
+            let clause = format!("{} {}", self.driver.quote_identifier(column), dir.as_sql());
+            self.order_clauses.push(clause);
+        } else {
+            self.deferred_error.get_or_insert_with(|| format!("Cannot order by unknown column '{}'", column));
+        }
+        self
+    }
+
+    /// Returns whether `column` names a model column, a select alias or a known `table.column`.
+    ///
+    /// Used to validate columns that are spliced into the statement by `order_by` and
+    /// `not_in_list`.
+    fn is_orderable_column(&self, column: &str) -> bool {
+        let is_ident = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        match column.split_once('.') {
+            Some((table, col)) => {
+                is_ident(table)
+                    && is_ident(col)
+                    && (table == self.table_name
+                        || Some(table) == self.alias.as_deref()
+                        || self.join_aliases.iter().any(|(name, alias)| name == table || alias == table))
+            }
+            None => {
+                is_ident(column)
+                    && (self.columns_info.iter().any(|c| c.name == column)
+                        || self.select_columns.iter().flat_map(|s| split_top_level(s)).any(|item| {
+                            item.to_lowercase()
+                                .rsplit_once(" as ")
+                                .is_some_and(|(_, alias)| alias.trim().trim_matches('"') == column.to_lowercase())
+                        }))
+            }
+        }
+    }
+
     /// Defines a SQL alias for the primary table in the query.
     ///
     /// This method allows you to set a short alias for the model's underlying table.
@@ -1386,7 +2214,9 @@ where
     /// Activates debug mode for this query.
     ///
     /// When enabled, the generated SQL query will be logged using the `log` crate
-    /// at the `DEBUG` level before execution.
+    /// at the `DEBUG` level before execution. `scan` and `scan_as` also log a
+    /// warning when the query has no ORDER BY, since the database may then return
+    /// rows in any order; this points at tests and pagination that only pass by luck.
     ///
     /// # Note
     ///
@@ -1402,11 +2232,105 @@ where
     ///     .scan()
     ///     .await?;
     /// ```
+    #[allow(clippy::empty_line_after_doc_comments)]
     pub fn debug(mut self) -> Self {
         self.debug_mode = true;
         self
     }
 
+    /// Serves repeated identical reads from an in-memory cache for `ttl`.
+    ///
+    /// Results of `scan`, `scan_as`, `first` and `scalar` (and the helpers built on
+    /// them, like `count`) are stored under the generated SQL and its arguments. Any
+    /// insert, update or delete made through the ORM on the same table, or on a table
+    /// joined into the query, drops them; writes made with raw SQL do not, so only
+    /// cache data that changes through the query builder.
+    ///
+    /// Inside a transaction the cache is bypassed: reads always hit the database, and
+    /// the writes drop cached entries only once the transaction commits.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let roles: Vec<Role> = db.model::<Role>()
+    ///     .cache(Duration::from_secs(60))
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn cache(mut self, ttl: std::time::Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Sets the time limit for the statements run by this query.
+    ///
+    /// Overrides `DatabaseBuilder::default_query_timeout` for this query only. A
+    /// statement that runs longer fails with an `std::io::ErrorKind::TimedOut` I/O error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let report: Vec<Sale> = db.model::<Sale>().timeout(Duration::from_secs(60)).scan().await?;
+    /// ```
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.tx.set_query_timeout(Some(timeout));
+        self
+    }
+
+    /// Runs a read, using the query cache when `cache()` was called.
+    async fn fetch_all_cached(&self, query: &str, args: AnyArguments<'_>) -> Result<Vec<AnyRow>, sqlx::Error> {
+        let (Some(ttl), Some(cache)) = (self.cache_ttl, self.tx.query_cache()) else {
+            return self.tx.fetch_all(query, args).await;
+        };
+
+        let key = format!("{}|{:?}", query, args.values.0);
+        if let Some(rows) = cache.get(&key) {
+            if self.debug_mode {
+                log::debug!("Cache hit: {}", query);
+            }
+            return Ok(rows);
+        }
+
+        let rows = self.tx.fetch_all(query, args).await?;
+        cache.insert(self.read_tables(), key, rows.clone(), ttl);
+        Ok(rows)
+    }
+
+    /// Returns every table the query reads: its own, joined tables, subquery tables and
+    /// those of combined queries, so a write to any of them drops a cached result.
+    pub(crate) fn read_tables(&self) -> Vec<String> {
+        let mut tables = vec![self.table_name.to_string()];
+        tables.extend(self.join_aliases.keys().cloned());
+        tables.extend(self.subquery_tables.iter().cloned());
+        for (_, other) in &self.unions {
+            tables.extend(other.read_tables());
+        }
+        tables.sort();
+        tables.dedup();
+        tables
+    }
+
+    /// Like `fetch_all_cached`, returning the first row or `RowNotFound`.
+    async fn fetch_one_cached(&self, query: &str, args: AnyArguments<'_>) -> Result<AnyRow, sqlx::Error> {
+        if self.cache_ttl.is_none() {
+            return self.tx.fetch_one(query, args).await;
+        }
+        self.fetch_all_cached(query, args).await?.into_iter().next().ok_or(sqlx::Error::RowNotFound)
+    }
+
+    /// Fetches at most one row, going through the result cache when `cache()` was called.
+    async fn fetch_optional_cached(&self, query: &str, args: AnyArguments<'_>) -> Result<Option<AnyRow>, sqlx::Error> {
+        if self.cache_ttl.is_none() {
+            return self.tx.fetch_optional(query, args).await;
+        }
+        Ok(self.fetch_all_cached(query, args).await?.into_iter().next())
+    }
+
+    /// Drops cached reads of this table after a write.
+    fn invalidate_cache(&self) {
+        self.tx.invalidate_cached(self.table_name);
+    }
+
     /// Adds an IS NULL filter for the specified column.
     ///
     /// # Arguments
@@ -1475,35 +2399,129 @@ where
         self
     }
 
-    /// Includes soft-deleted records in query results.
+    /// Keeps rows whose timestamp column is older than `age` (`col < now - age`).
     ///
-    /// By default, queries on models with a `#[orm(soft_delete)]` column exclude
-    /// records where that column is not NULL. This method disables that filter.
+    /// The current time is taken from the database, and the duration is bound as a
+    /// parameter: `NOW() - interval` on PostgreSQL, `UTC_TIMESTAMP(6) - INTERVAL ? MICROSECOND`
+    /// on MySQL and `datetime('now', ?)` on SQLite.
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// // Get all users including deleted ones
-    /// db.model::<User>()
-    ///     .with_deleted()
+    /// // Sessions idle for more than 30 minutes
+    /// let stale: Vec<Session> = db.model::<Session>()
+    ///     .older_than("last_seen", Duration::from_secs(30 * 60))
     ///     .scan()
     ///     .await?;
     /// ```
-    pub fn with_deleted(mut self) -> Self {
-        self.with_deleted = true;
-        self
+    pub fn older_than(self, col: &str, age: std::time::Duration) -> Self {
+        self.age_filter(col, age, "<")
     }
 
-    /// Adds an INNER JOIN clause to the query.
+    /// Keeps rows whose timestamp column is newer than `age` (`col > now - age`).
     ///
-    /// # Arguments
-    ///
-    /// * `table` - The name of the table to join (with optional alias)
-    /// * `on` - The join condition (e.g., "users.id = posts.user_id")
-    ///
-    /// # Example
-    ///
-    /// ```rust,ignore
+    /// See [`older_than`](Self::older_than) for how the comparison is built per driver.
+    pub fn newer_than(self, col: &str, age: std::time::Duration) -> Self {
+        self.age_filter(col, age, ">")
+    }
+
+    /// Internal helper for `older_than` / `newer_than`.
+    fn age_filter(mut self, col: &str, age: std::time::Duration, op: &'static str) -> Self {
+        let col_owned = col.to_string();
+        let table_id = self.get_table_identifier();
+        let is_main_col = self.columns.contains(&col_owned.to_snake_case());
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+            let column = if let Some((table, column)) = col_owned.split_once(".") {
+                format!("\"{}\".\"{}\"", table, column)
+            } else if is_main_col {
+                format!("\"{}\".\"{}\"", table_id, col_owned)
+            } else {
+                format!("\"{}\"", col_owned)
+            };
+
+            match driver {
+                Drivers::Postgres => {
+                    query.push_str(&format!(
+                        " AND {} {} NOW() - (${}::DOUBLE PRECISION * INTERVAL '1 second')",
+                        column, op, arg_counter
+                    ));
+                    *arg_counter += 1;
+                    let _ = args.add(age.as_secs_f64());
+                }
+                Drivers::MySQL => {
+                    query.push_str(&format!(" AND {} {} UTC_TIMESTAMP(6) - INTERVAL ? MICROSECOND", column, op));
+                    let _ = args.add(age.as_micros() as i64);
+                }
+                Drivers::SQLite => {
+                    // Stored values are RFC 3339 text; datetime() normalizes both sides to UTC
+                    query.push_str(&format!(" AND datetime({}) {} datetime('now', ?)", column, op));
+                    let _ = args.add(format!("-{:.6} seconds", age.as_secs_f64()));
+                }
+            }
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Includes soft-deleted records in query results.
+    ///
+    /// By default, queries on models with a `#[orm(soft_delete)]` column exclude
+    /// records where that column is not NULL. This method disables that filter.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Get all users including deleted ones
+    /// db.model::<User>()
+    ///     .with_deleted()
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn with_deleted(mut self) -> Self {
+        self.with_deleted = true;
+        self
+    }
+
+    /// Includes soft-deleted records in query results.
+    ///
+    /// Alias of [`with_deleted`](Self::with_deleted).
+    pub fn with_trashed(self) -> Self {
+        self.with_deleted()
+    }
+
+    /// Restricts the query to soft-deleted records.
+    ///
+    /// Adds `WHERE <soft_delete column> IS NOT NULL` in place of the implicit
+    /// `IS NULL` filter. On a model without a `#[orm(soft_delete)]` column nothing
+    /// is ever trashed, so the query matches no rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let deleted: Vec<User> = db.model::<User>().only_trashed().scan().await?;
+    /// ```
+    pub fn only_trashed(mut self) -> Self {
+        self.with_deleted = true;
+        let table_id = self.get_table_identifier();
+        let soft_delete_col = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name.to_string());
+        let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| match &soft_delete_col {
+            Some(col) => query.push_str(&format!(" AND \"{}\".\"{}\" IS NOT NULL", table_id, col)),
+            None => query.push_str(" AND 1=0"),
+        });
+        self.where_clauses.push(clause);
+        self
+    }
+
+    /// Adds an INNER JOIN clause to the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table to join (with optional alias)
+    /// * `on` - The join condition (e.g., "users.id = posts.user_id")
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
     /// db.model::<User>()
     ///     .join("posts p", "u.id = p.user_id")
     ///     .scan()
@@ -1525,10 +2543,10 @@ where
         
         if let Some((first, second)) = values {
             // Try to parse table.column = table.column
-            if let Some((t1, c1)) = first.split_once('.') {
-                if let Some((t2, c2)) = second.split_once('.') {
-                    parsed_query = format!("\"{}\".\"{}\" = \"{}\".\"{}\"", t1, c1, t2, c2);
-                }
+            if let Some((t1, c1)) = first.split_once('.')
+                && let Some((t2, c2)) = second.split_once('.')
+            {
+                parsed_query = format!("\"{}\".\"{}\" = \"{}\".\"{}\"", t1, c1, t2, c2);
             }
         }
 
@@ -1569,7 +2587,7 @@ where
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        self.join_generic_raw("", table, on, value)
+        self.join_generic_raw("", table, on, vec![value])
     }
 
     /// Adds a raw LEFT JOIN clause with a placeholder and a bound value.
@@ -1589,7 +2607,7 @@ where
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        self.join_generic_raw("LEFT", table, on, value)
+        self.join_generic_raw("LEFT", table, on, vec![value])
     }
 
     /// Adds a raw RIGHT JOIN clause with a placeholder and a bound value.
@@ -1609,7 +2627,7 @@ where
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        self.join_generic_raw("RIGHT", table, on, value)
+        self.join_generic_raw("RIGHT", table, on, vec![value])
     }
 
     /// Adds a raw INNER JOIN clause with a placeholder and a bound value.
@@ -1629,7 +2647,7 @@ where
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        self.join_generic_raw("INNER", table, on, value)
+        self.join_generic_raw("INNER", table, on, vec![value])
     }
 
     /// Adds a raw FULL JOIN clause with a placeholder and a bound value.
@@ -1649,14 +2667,54 @@ where
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        self.join_generic_raw("FULL", table, on, value)
+        self.join_generic_raw("FULL", table, on, vec![value])
+    }
+
+    /// Adds a JOIN clause whose condition binds several values.
+    ///
+    /// Each `?` in `on` is replaced, in order, by the next value of `values`; the
+    /// number of placeholders must match the number of values, or the query fails
+    /// when it runs. On PostgreSQL the placeholders are numbered after the ones
+    /// already in the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<Permission>()
+    ///     .join_raw_many("role_permission rp", "rp.permission_id = permission.id AND rp.role_id IN (?, ?)", &[1, 2])
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn join_raw_many<V>(self, table: &str, on: &str, values: &[V]) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.join_generic_raw("", table, on, values.to_vec())
+    }
+
+    /// Adds a LEFT JOIN clause whose condition binds several values.
+    ///
+    /// See [`join_raw_many`](Self::join_raw_many).
+    pub fn left_join_raw_many<V>(self, table: &str, on: &str, values: &[V]) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.join_generic_raw("LEFT", table, on, values.to_vec())
     }
 
     /// Internal helper for raw join types
-    fn join_generic_raw<V>(mut self, join_type: &str, table: &str, on: &str, value: V) -> Self
+    fn join_generic_raw<V>(mut self, join_type: &str, table: &str, on: &str, values: Vec<V>) -> Self
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
+        let placeholders = on.matches('?').count();
+        if placeholders != values.len() {
+            self.deferred_error.get_or_insert_with(|| {
+                format!("Join condition '{}' has {} placeholder(s) but {} value(s) were bound", on, placeholders, values.len())
+            });
+            return self;
+        }
+
         let table_owned = table.to_string();
         let on_owned = on.to_string();
         let join_type_owned = join_type.to_string();
@@ -1674,21 +2732,19 @@ where
                 query.push_str(&format!("{} JOIN \"{}\" ON ", join_type_owned, table_owned));
             }
 
-            let mut processed_on = on_owned.clone();
-            if let Some(pos) = processed_on.find('?') {
-                let placeholder = match driver {
+            let mut parts = on_owned.split('?');
+            query.push_str(parts.next().unwrap_or_default());
+            for (part, value) in parts.zip(&values) {
+                match driver {
                     Drivers::Postgres => {
-                        let p = format!("${}", arg_counter);
+                        query.push_str(&format!("${}", arg_counter));
                         *arg_counter += 1;
-                        p
                     }
-                    _ => "?".to_string(),
-                };
-                processed_on.replace_range(pos..pos + 1, &placeholder);
+                    _ => query.push('?'),
+                }
+                query.push_str(part);
+                let _ = args.add(value.clone());
             }
-            
-            query.push_str(&processed_on);
-            let _ = args.add(value.clone());
         }));
         self
     }
@@ -1757,6 +2813,9 @@ where
 
     /// Adds a FULL JOIN clause.
     ///
+    /// Supported by PostgreSQL and SQLite 3.39+. MySQL has no FULL OUTER JOIN; there,
+    /// combine a `left_join` query and a `right_join` query with `union()` instead.
+    ///
     /// # Arguments
     ///
     /// * `table` - The name of the table to join with
@@ -1792,6 +2851,102 @@ where
         self
     }
 
+    /// Keeps only the first row of each set of rows sharing the given expressions.
+    ///
+    /// Emits PostgreSQL's `DISTINCT ON (...)`. Which row is kept is decided by
+    /// `order()`, whose leading expressions must match these ones. MySQL and SQLite
+    /// have no equivalent, so executing the query there returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Latest post of every user
+    /// let latest: Vec<Post> = db.model::<Post>()
+    ///     .distinct_on(&["user_id"])
+    ///     .order("user_id, created_at DESC")
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn distinct_on(mut self, columns: &[&str]) -> Self {
+        self.distinct_on.extend(columns.iter().map(|c| c.to_string()));
+        self
+    }
+
+    /// Locks the selected rows for writing until the transaction ends.
+    ///
+    /// Appends `FOR UPDATE` on PostgreSQL and MySQL, so other transactions
+    /// trying to update or lock the same rows wait for this one to finish. Only
+    /// meaningful inside a transaction. SQLite has no row locks and serializes
+    /// writers instead, so the clause is left out there.
+    ///
+    /// `count()`, the aggregate helpers, `exists()` and the count query of
+    /// `paginate()` run without the lock. Combining a lock with `union()` is
+    /// reported as an error when the query runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let tx = db.begin().await?;
+    /// let from: Account = tx.model::<Account>().equals("id", from_id).for_update().first().await?;
+    /// // ... check the balance and update both accounts ...
+    /// tx.commit().await?;
+    /// ```
+    pub fn for_update(mut self) -> Self {
+        self.row_lock = Some("FOR UPDATE");
+        self
+    }
+
+    /// Locks the selected rows against writes, while still allowing other readers to share the lock.
+    ///
+    /// Appends `FOR SHARE` on PostgreSQL and MySQL (8.0 or later); like
+    /// [`for_update`](Self::for_update) it is left out on SQLite.
+    pub fn for_share(mut self) -> Self {
+        self.row_lock = Some("FOR SHARE");
+        self
+    }
+
+    /// Skips rows locked by other transactions instead of waiting for them.
+    ///
+    /// Adds `SKIP LOCKED` after [`for_update`](Self::for_update) or
+    /// [`for_share`](Self::for_share), the usual way to let several workers pull
+    /// jobs from one queue table. Has no effect without one of them, or on SQLite.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let job: Option<Job> = tx.model::<Job>()
+    ///     .equals("status", "pending")
+    ///     .order("id")
+    ///     .for_update()
+    ///     .skip_locked()
+    ///     .first_optional()
+    ///     .await?;
+    /// ```
+    pub fn skip_locked(mut self) -> Self {
+        self.skip_locked = true;
+        self
+    }
+
+    /// Lets column defaults apply to `None` values on insert.
+    ///
+    /// By default a `None` is inserted as `NULL`. With this flag, `insert`,
+    /// `insert_get_id` and `insert_returning` leave out `None` values of columns that
+    /// declare `#[orm(default = "...")]`, so the database fills in the default.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let note: Note = db.model::<Note>()
+    ///     .use_defaults()
+    ///     .insert_returning(&Note { id: 0, title: "draft".into(), created_at: None })
+    ///     .await?;
+    /// assert!(note.created_at.is_some());
+    /// ```
+    pub fn use_defaults(mut self) -> Self {
+        self.use_defaults = true;
+        self
+    }
+
     /// Adds a GROUP BY clause to the query.
     ///
     /// Groups rows that have the same values into summary rows. Often used with
@@ -1816,6 +2971,51 @@ where
         self
     }
 
+    /// Adds a single column to the GROUP BY clause.
+    ///
+    /// Unlike [`group_by`](Self::group_by), the column is validated the same way as in
+    /// [`order_by`](Self::order_by) and quoted for the driver, so it is safe to pass a
+    /// name taken from a request. An unknown column makes the query fail when it runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let stats: Vec<(i32, i64)> = db.model::<Post>()
+    ///     .select("user_id, COUNT(*)")
+    ///     .group_by_column("user_id")
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn group_by_column(mut self, column: &str) -> Self {
+        if self.is_orderable_column(column) {
+            self.group_by_clauses.push(self.driver.quote_identifier(column));
+        } else {
+            self.deferred_error.get_or_insert_with(|| format!("Cannot group by unknown column '{}'", column));
+        }
+        self
+    }
+
+    /// Groups by every plain column selected alongside an aggregate.
+    ///
+    /// Without this, mixing aggregates and plain columns in `select()` without a
+    /// matching `group_by()` is rejected before the query runs. With it, the missing
+    /// columns are appended to the GROUP BY clause instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // SELECT user_id, COUNT(*) FROM "post" GROUP BY user_id
+    /// let per_user: Vec<(i32, i64)> = db.model::<Post>()
+    ///     .select("user_id, COUNT(*)")
+    ///     .auto_group_by()
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn auto_group_by(mut self) -> Self {
+        self.auto_group_by = true;
+        self
+    }
+
     /// Adds a HAVING clause to the query.
     ///
     /// Used to filter groups created by `group_by`. Similar to `filter` (WHERE),
@@ -1838,25 +3038,85 @@ where
     ///     .scan()
     ///     .await?;
     /// ```
-    pub fn having<V>(mut self, col: &'static str, op: Op, value: V) -> Self
+    pub fn having<V>(self, col: &'static str, op: Op, value: V) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.having_internal(col.to_string(), op, value)
+    }
+
+    /// Adds a HAVING clause comparing `COUNT(*)` of each group with a bound value.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // GROUP BY "user_id" HAVING COUNT(*) > 5
+    /// let prolific: Vec<(i32, i64)> = db.model::<Post>()
+    ///     .select("user_id, COUNT(*)")
+    ///     .group_by_column("user_id")
+    ///     .having_count(Op::Gt, 5)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn having_count<V>(self, op: Op, value: V) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.having_internal("COUNT(*)".to_string(), op, value)
+    }
+
+    /// Adds a HAVING clause comparing an aggregate of a column with a bound value.
+    ///
+    /// The column is validated and quoted like in [`order_by`](Self::order_by), and
+    /// `"*"` is accepted for [`Aggregate::Count`]; anything else makes the query fail
+    /// when it runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // GROUP BY "user_id" HAVING SUM("views") >= 1000
+    /// let popular: Vec<(i32, i64)> = db.model::<Post>()
+    ///     .select("user_id, SUM(views)")
+    ///     .group_by_column("user_id")
+    ///     .having_agg(Aggregate::Sum, "views", Op::Gte, 1000)
+    ///     .scan()
+    ///     .await?;
+    /// ```
+    pub fn having_agg<V>(mut self, func: Aggregate, column: &str, op: Op, value: V) -> Self
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        let argument = if column == "*" && func == Aggregate::Count {
+            "*".to_string()
+        } else if self.is_orderable_column(column) {
+            self.driver.quote_identifier(column)
+        } else {
+            self.deferred_error
+                .get_or_insert_with(|| format!("Cannot aggregate unknown column '{}' in HAVING", column));
+            return self;
+        };
+        self.having_internal(format!("{}({})", func.as_sql(), argument), op, value)
+    }
+
+    fn having_internal<V>(mut self, expr: String, op: Op, value: V) -> Self
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
-        let op_str = op.as_sql();
         let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
             query.push_str(" AND ");
-            query.push_str(col);
-            query.push(' ');
-            query.push_str(op_str);
-            query.push(' ');
-
-            match driver {
+            if !op.takes_value() {
+                query.push_str(&op.render(*driver, &expr, ""));
+                return;
+            }
+            let placeholder = match driver {
                 Drivers::Postgres => {
-                    query.push_str(&format!("${}", arg_counter));
+                    let placeholder = format!("${}", arg_counter);
                     *arg_counter += 1;
+                    placeholder
                 }
-                _ => query.push('?'),
-            }
+                _ => "?".to_string(),
+            };
+            query.push_str(&op.render(*driver, &expr, &placeholder));
             let _ = args.add(value.clone());
         });
 
@@ -1881,12 +3141,97 @@ where
     /// ```
     pub async fn count(mut self) -> Result<i64, sqlx::Error> {
         self.select_columns = vec!["COUNT(*)".to_string()];
+        // PostgreSQL rejects FOR UPDATE next to an aggregate
+        self.row_lock = None;
         self.scalar::<i64>().await
     }
 
+    /// Returns the number of distinct non-NULL values of `column` among the matching rows.
+    ///
+    /// Builds `SELECT COUNT(DISTINCT column)` with the query's filters. Grouped
+    /// queries are wrapped in a subquery, so the count runs over the grouped rows and
+    /// `column` must be part of their select list. The column must be one of the
+    /// model's columns, a select alias or a known `table.column`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let posters = db.model::<Post>()
+    ///     .filter("published", Op::Eq, true)
+    ///     .count_distinct("user_id")
+    ///     .await?;
+    /// ```
+    pub async fn count_distinct(mut self, column: &str) -> Result<i64, sqlx::Error> {
+        if !self.is_orderable_column(column) {
+            return Err(sqlx::Error::Configuration(format!("Cannot count unknown column '{}'", column).into()));
+        }
+        self.check_group_by()?;
+        self.check_deferred_error()?;
+        self.apply_soft_delete_filter();
+        self.order_clauses.clear();
+        self.limit = None;
+        self.offset = None;
+
+        // Inside the grouped subquery only the output column name is visible
+        let target = if self.is_grouped() {
+            column.rsplit('.').next().unwrap_or(column).to_string()
+        } else if !column.contains('.') && self.columns_info.iter().any(|c| c.name == column) {
+            format!("{}.{}", self.get_table_identifier(), column)
+        } else {
+            column.to_string()
+        };
+        let count_expr = format!("COUNT(DISTINCT {})", self.driver.quote_identifier(&target));
+
+        crate::pagination::fetch_count(&mut self, &count_expr).await
+    }
+
+    /// Returns whether at least one row matches the query.
+    ///
+    /// Wraps the filtered query as `SELECT EXISTS(SELECT 1 FROM ... WHERE ...)`, dropping
+    /// the select list, ordering and pagination, so the database can stop at the first
+    /// match instead of returning rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let taken = db.model::<User>()
+    ///     .filter(user_fields::EMAIL, Op::Eq, email.clone())
+    ///     .exists()
+    ///     .await?;
+    /// ```
+    pub async fn exists(mut self) -> Result<bool, sqlx::Error> {
+        self.check_deferred_error()?;
+        self.apply_soft_delete_filter();
+        self.select_columns = vec!["1 AS present".to_string()];
+        self.order_clauses.clear();
+        self.limit = None;
+        self.offset = None;
+        self.is_distinct = false;
+        self.distinct_on.clear();
+        self.row_lock = None;
+
+        let mut inner = String::new();
+        let mut args = AnyArguments::default();
+        let mut arg_counter = 1;
+        self.write_select_sql::<T>(&mut inner, &mut args, &mut arg_counter);
+        let query = format!("SELECT EXISTS({})", inner);
+
+        if self.debug_mode {
+            log::debug!("SQL: {}", query);
+        }
+
+        let row = self.tx.fetch_one(&query, args).await?;
+        // PostgreSQL returns a BOOLEAN; MySQL and SQLite return 0 or 1
+        match self.driver {
+            Drivers::Postgres => row.try_get::<bool, _>(0),
+            _ => row.try_get::<i64, _>(0).map(|v| v != 0),
+        }
+    }
+
     /// Returns the SUM of the specified column.
     ///
-    /// Calculates the sum of a numeric column.
+    /// Calculates the sum of a numeric column. Use `Option<N>` to get `None`
+    /// instead of an error when no rows match.
     ///
     /// # Arguments
     ///
@@ -1897,23 +3242,18 @@ where
     /// ```rust,ignore
     /// let total_age: i64 = db.model::<User>().sum("age").await?;
     /// ```
-    pub async fn sum<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn sum<N>(self, column: &str) -> Result<N, sqlx::Error>
     where
         N: FromAnyRow + AnyImpl + for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        let quoted_col = if column.contains('.') {
-            let parts: Vec<&str> = column.split('.').collect();
-            format!("\"{}\".\"{}\"", parts[0].trim_matches('"'), parts[1].trim_matches('"'))
-        } else {
-            format!("\"{}\"", column.trim_matches('"'))
-        };
-        self.select_columns = vec![format!("SUM({})", quoted_col)];
-        self.scalar::<N>().await
+        self.aggregate::<N>("SUM", column).await
     }
 
     /// Returns the AVG of the specified column.
     ///
-    /// Calculates the average value of a numeric column.
+    /// Calculates the average value of a numeric column. The average is computed as
+    /// a double on every driver, so `f64` works even for integer columns. Over an
+    /// empty set the result is SQL `NULL`; ask for `Option<f64>` to receive `None`.
     ///
     /// # Arguments
     ///
@@ -1923,24 +3263,19 @@ where
     ///
     /// ```rust,ignore
     /// let avg_age: f64 = db.model::<User>().avg("age").await?;
+    /// let avg_unknown: Option<f64> = db.model::<User>().filter("age", Op::Gt, 200).avg("age").await?;
     /// ```
-    pub async fn avg<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn avg<N>(self, column: &str) -> Result<N, sqlx::Error>
     where
         N: FromAnyRow + AnyImpl + for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        let quoted_col = if column.contains('.') {
-            let parts: Vec<&str> = column.split('.').collect();
-            format!("\"{}\".\"{}\"", parts[0].trim_matches('"'), parts[1].trim_matches('"'))
-        } else {
-            format!("\"{}\"", column.trim_matches('"'))
-        };
-        self.select_columns = vec![format!("AVG({})", quoted_col)];
-        self.scalar::<N>().await
+        self.aggregate::<N>("AVG", column).await
     }
 
     /// Returns the MIN of the specified column.
     ///
-    /// Finds the minimum value in a column.
+    /// Finds the minimum value in a column. Use `Option<N>` to get `None` instead
+    /// of an error when no rows match.
     ///
     /// # Arguments
     ///
@@ -1951,23 +3286,17 @@ where
     /// ```rust,ignore
     /// let min_age: i32 = db.model::<User>().min("age").await?;
     /// ```
-    pub async fn min<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn min<N>(self, column: &str) -> Result<N, sqlx::Error>
     where
         N: FromAnyRow + AnyImpl + for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        let quoted_col = if column.contains('.') {
-            let parts: Vec<&str> = column.split('.').collect();
-            format!("\"{}\".\"{}\"", parts[0].trim_matches('"'), parts[1].trim_matches('"'))
-        } else {
-            format!("\"{}\"", column.trim_matches('"'))
-        };
-        self.select_columns = vec![format!("MIN({})", quoted_col)];
-        self.scalar::<N>().await
+        self.aggregate::<N>("MIN", column).await
     }
 
     /// Returns the MAX of the specified column.
     ///
-    /// Finds the maximum value in a column.
+    /// Finds the maximum value in a column. Use `Option<N>` to get `None` instead
+    /// of an error when no rows match.
     ///
     /// # Arguments
     ///
@@ -1978,17 +3307,32 @@ where
     /// ```rust,ignore
     /// let max_age: i32 = db.model::<User>().max("age").await?;
     /// ```
-    pub async fn max<N>(mut self, column: &str) -> Result<N, sqlx::Error>
+    pub async fn max<N>(self, column: &str) -> Result<N, sqlx::Error>
     where
         N: FromAnyRow + AnyImpl + for<'r> Decode<'r, Any> + Type<Any> + Send + Unpin,
     {
-        let quoted_col = if column.contains('.') {
-            let parts: Vec<&str> = column.split('.').collect();
-            format!("\"{}\".\"{}\"", parts[0].trim_matches('"'), parts[1].trim_matches('"'))
-        } else {
-            format!("\"{}\"", column.trim_matches('"'))
-        };
-        self.select_columns = vec![format!("MAX({})", quoted_col)];
+        self.aggregate::<N>("MAX", column).await
+    }
+
+    /// Selects `func(column)` and returns it through `scalar`, keeping filters and grouping.
+    ///
+    /// `column` must be one of the model's columns or a known `table.column`.
+    async fn aggregate<N>(mut self, func: &str, column: &str) -> Result<N, sqlx::Error>
+    where
+        N: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        if !self.is_orderable_column(column) {
+            return Err(sqlx::Error::Configuration(format!("Cannot aggregate unknown column '{}'", column).into()));
+        }
+        let expr = format!("{}({})", func, self.driver.quote_identifier(column));
+
+        // PostgreSQL and MySQL return AVG as NUMERIC/DECIMAL, which the Any driver cannot decode
+        self.select_columns = vec![match (func, self.driver) {
+            ("AVG", Drivers::Postgres) => format!("CAST({} AS DOUBLE PRECISION)", expr),
+            ("AVG", Drivers::MySQL) => format!("CAST({} AS DOUBLE)", expr),
+            _ => expr,
+        }];
+        self.row_lock = None;
         self.scalar::<N>().await
     }
 
@@ -2080,6 +3424,43 @@ where
         self
     }
 
+    /// Adds a raw SQL expression to the select list.
+    ///
+    /// The expression is written into the SELECT exactly as given: it is not quoted,
+    /// qualified or split on the commas inside function calls. Give it an alias that
+    /// matches a field when scanning into a struct.
+    ///
+    /// **Safety:** the expression is spliced into the SQL as-is, so it must never be
+    /// built from user input. Bind values through `filter()` instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let rows: Vec<UserBio> = db.model::<User>()
+    ///     .select("id, name")
+    ///     .select_raw("LENGTH(bio) AS bio_len")
+    ///     .select_raw("COUNT(*) OVER () AS total")
+    ///     .scan_as()
+    ///     .await?;
+    /// ```
+    pub fn select_raw(self, expr: &str) -> Self {
+        self.select(expr)
+    }
+
+    /// Adds a raw SQL expression to the select list under an alias.
+    ///
+    /// Shorthand for `select_raw("<expr> AS \"<alias>\"")`; the same safety rules apply
+    /// to `expr`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// query.select_as("LENGTH(bio)", "bio_len")
+    /// ```
+    pub fn select_as(self, expr: &str, alias: &str) -> Self {
+        self.select_raw(&format!("{} AS \"{}\"", expr, alias))
+    }
+
     /// Excludes specific columns from the query results.
     ///
     /// This is the inverse of `select()`. Instead of specifying which columns to include,
@@ -2093,6 +3474,14 @@ where
     ///
     /// If both `select()` and `omit()` are used, `select()` takes priority.
     ///
+    /// Scanning into a type that still needs an omitted column (e.g. the full model)
+    /// fails before the query runs with an error naming the missing field; use
+    /// `scan_as` with a DTO that leaves it out instead. Omitted `Option` fields are
+    /// selected as NULL and come back as `None`.
+    ///
+    /// Fields marked `#[orm(omit)]` on the model are omitted on every query by default;
+    /// the model decodes them as `Default::default()` instead of failing.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -2121,6 +3510,39 @@ where
         self
     }
 
+    /// Brings omitted columns back into the default select list.
+    ///
+    /// Reverses `omit()` and `#[orm(omit)]` for the given comma-separated columns, so
+    /// an endpoint that does need a hidden column can fetch it without listing every
+    /// other column in `select()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // `password_hash` is `#[orm(omit)]` on the model
+    /// let user: User = db.model::<User>()
+    ///     .include(user_fields::PASSWORD_HASH)
+    ///     .filter(user_fields::EMAIL, Op::Eq, email)
+    ///     .first()
+    ///     .await?;
+    /// ```
+    pub fn include(mut self, columns: &str) -> Self {
+        for col in columns.split(',') {
+            let col = col.trim().to_snake_case();
+            self.omit_columns.retain(|c| *c != col);
+        }
+        self
+    }
+
+    /// Brings every omitted column back into the default select list.
+    ///
+    /// Equivalent to calling `include()` with each column marked `#[orm(omit)]` or
+    /// passed to `omit()` earlier in the chain.
+    pub fn with_omitted(mut self) -> Self {
+        self.omit_columns.clear();
+        self
+    }
+
     /// Sets the query offset (pagination).
     ///
     /// Specifies the number of rows to skip before starting to return rows.
@@ -2222,99 +3644,280 @@ where
     /// ```
     pub fn insert<'b>(&'b mut self, model: &'b T) -> BoxFuture<'b, Result<(), sqlx::Error>> {
         Box::pin(async move {
-            // Serialize model to a HashMap of column_name -> string_value
-            let data_map = Model::to_map(model);
-
             // Early return if no data to insert
-            if data_map.is_empty() {
+            let Some((query_str, args)) = self.build_insert(model) else {
                 return Ok(());
+            };
+
+            if self.debug_mode {
+                log::debug!("SQL: {}", query_str);
             }
 
-            let table_name = self.table_name.to_snake_case();
-            let columns_info = <T as Model>::columns();
+            // Execute the INSERT query
+            self.tx.execute(&query_str, args).await?;
+            self.invalidate_cache();
+            Ok(())
+        })
+    }
 
-            let mut target_columns = Vec::new();
-            let mut bindings: Vec<(Option<String>, &str)> = Vec::new();
+    /// Inserts a record and returns the value of its generated primary key.
+    ///
+    /// Intended for models with an `#[orm(primary_key, auto_increment)]` integer key:
+    /// leave the key as `0` and the database assigns it. PostgreSQL and SQLite read the
+    /// key back through `RETURNING`; MySQL uses the connection's last insert id.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let id = db.model::<Post>().insert_get_id(&Post { id: 0, title: "Hello".into() }).await?;
+    /// ```
+    pub fn insert_get_id<'b>(&'b mut self, model: &'b T) -> BoxFuture<'b, Result<i64, sqlx::Error>> {
+        Box::pin(async move {
+            let Some((mut query_str, args)) = self.build_insert(model) else {
+                return Err(sqlx::Error::Configuration("Nothing to insert".into()));
+            };
+
+            if self.driver != Drivers::MySQL {
+                let pk = <T as Model>::columns().into_iter().find(|c| c.is_primary_key).ok_or_else(|| {
+                    sqlx::Error::Configuration(format!("Model '{}' has no primary key", self.table_name).into())
+                })?;
+                query_str.push_str(&format!(" RETURNING CAST(\"{}\" AS BIGINT)", pk.name));
+
+                if self.debug_mode {
+                    log::debug!("SQL: {}", query_str);
+                }
+
+                let row = self.tx.fetch_one(&query_str, args).await?;
+                self.invalidate_cache();
+                return row.try_get::<i64, _>(0);
+            }
+
+            if self.debug_mode {
+                log::debug!("SQL: {}", query_str);
+            }
+
+            let result = self.tx.execute(&query_str, args).await?;
+            self.invalidate_cache();
+            result.last_insert_id().ok_or_else(|| {
+                sqlx::Error::Configuration(format!("No generated id returned for '{}'", self.table_name).into())
+            })
+        })
+    }
+
+    /// Inserts a record and returns the stored row mapped to `R`.
+    ///
+    /// The returned row includes values filled in by the database, such as generated
+    /// keys and column defaults. PostgreSQL and SQLite read it back through `RETURNING`;
+    /// MySQL runs a follow-up `SELECT` by primary key. Columns passed to `select()` or
+    /// `omit()` narrow the returned columns the same way they do for `scan_as`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let saved: Post = db.model::<Post>()
+    ///     .insert_returning(&Post { id: 0, title: "Hello".into(), created_at: None })
+    ///     .await?;
+    /// println!("created at {:?}", saved.created_at);
+    /// ```
+    pub fn insert_returning<'b, R>(&'b mut self, model: &'b T) -> BoxFuture<'b, Result<R, sqlx::Error>>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin + 'b,
+    {
+        Box::pin(async move {
+            self.check_select_coverage::<R>()?;
+            let Some((mut query_str, args)) = self.build_insert(model) else {
+                return Err(sqlx::Error::Configuration("Nothing to insert".into()));
+            };
+            let returning = self.returning_sql::<R>();
+
+            if self.driver != Drivers::MySQL {
+                query_str.push_str(&format!(" RETURNING {}", returning));
+
+                if self.debug_mode {
+                    log::debug!("SQL: {}", query_str);
+                }
+
+                let row = self.tx.fetch_one(&query_str, args).await?;
+                self.invalidate_cache();
+                return R::from_any_row(&row);
+            }
+
+            if self.debug_mode {
+                log::debug!("SQL: {}", query_str);
+            }
+
+            let result = self.tx.execute(&query_str, args).await?;
+            self.invalidate_cache();
+
+            // MySQL has no RETURNING: read the row back through its primary key
+            let data_map = Model::to_map(model);
+            let pk_columns: Vec<ColumnInfo> = <T as Model>::columns().into_iter().filter(|c| c.is_primary_key).collect();
+            if pk_columns.is_empty() {
+                return Err(sqlx::Error::Configuration(format!("Model '{}' has no primary key", self.table_name).into()));
+            }
+
+            let mut select_str = format!("SELECT {} FROM \"{}\" WHERE 1=1", returning, self.table_name);
+            let mut select_args = AnyArguments::default();
+            for col in &pk_columns {
+                select_str.push_str(&format!(" AND \"{}\" = ?", col.name));
+                let value = data_map.get(col.name).cloned().flatten();
+                match value {
+                    Some(v) if !(col.auto_increment && v == "0") => {
+                        if select_args.bind_value(&v, col.sql_type, &self.driver).is_err() {
+                            let _ = select_args.add(v);
+                        }
+                    }
+                    _ => {
+                        let id = result.last_insert_id().ok_or_else(|| {
+                            sqlx::Error::Configuration(format!("No generated id returned for '{}'", self.table_name).into())
+                        })?;
+                        let _ = select_args.add(id);
+                    }
+                }
+            }
+
+            if self.debug_mode {
+                log::debug!("SQL: {}", select_str);
+            }
+
+            let row = self.tx.fetch_one(&select_str, select_args).await?;
+            R::from_any_row(&row)
+        })
+    }
+
+    /// Builds the INSERT statement and its arguments for a single model.
+    ///
+    /// Auto-increment columns still holding `0` and `None` values of columns with a
+    /// declared `default` are left out so the database fills them in. Returns `None`
+    /// when the model has nothing to insert.
+    fn build_insert(&self, model: &T) -> Option<(String, AnyArguments<'static>)> {
+        // Serialize model to (column_name, string_value) pairs in declaration order
+        let data_pairs = Model::to_ordered_pairs(model);
+
+        if data_pairs.is_empty() {
+            return None;
+        }
+
+        let table_name = self.table_name.to_string();
+        let columns_info = <T as Model>::columns();
+
+        let mut target_columns = Vec::new();
+        let mut bindings: Vec<(Option<String>, &str)> = Vec::new();
+
+        // Build column list and collect values with their SQL types
+        for (col_name, value) in data_pairs {
+            let col_info = columns_info.iter().find(|c| c.name == col_name);
 
-            // Build column list and collect values with their SQL types
-            for (col_name, value) in data_map {
-                // Strip the "r#" prefix if present (for Rust keywords used as field names)
-                let col_name_clean = col_name.strip_prefix("r#").unwrap_or(&col_name).to_snake_case();
-                target_columns.push(format!("\"{}\"", col_name_clean));
+            // Stamp unset `create_time`/`update_time` columns with the current time
+            let value = match col_info {
+                Some(c) if (c.create_time || c.update_time)
+                    && temporal::is_temporal_type(c.sql_type)
+                    && temporal::is_unset_timestamp(value.as_deref()) =>
+                {
+                    Some(temporal::current_timestamp_value(c.sql_type))
+                }
+                _ => value,
+            };
 
-                // Find the SQL type for this column
-                let sql_type = columns_info.iter().find(|c| c.name == col_name).map(|c| c.sql_type).unwrap_or("TEXT");
+            // Let the database generate auto-increment keys that were left unset
+            if col_info.is_some_and(|c| c.auto_increment) && value.as_deref().is_none_or(|v| v == "0") {
+                continue;
+            }
 
-                bindings.push((value, sql_type));
+            // With use_defaults(), let the column default apply instead of binding NULL
+            if self.use_defaults && value.is_none() && col_info.is_some_and(|c| c.default.is_some()) {
+                continue;
             }
 
-            // Generate placeholders with proper type casting for PostgreSQL
-            let placeholders: Vec<String> = bindings
-                .iter()
-                .enumerate()
-                .map(|(i, (_, sql_type))| match self.driver {
-                    Drivers::Postgres => {
-                        let idx = i + 1;
-                        // PostgreSQL requires explicit type casting for some types
-                        if temporal::is_temporal_type(sql_type) {
-                            // Use temporal module for type casting
-                            format!("${}{}", idx, temporal::get_postgres_type_cast(sql_type))
-                        } else {
-                            match *sql_type {
-                                "UUID" => format!("${}::UUID", idx),
-                                "JSONB" | "jsonb" => format!("${}::JSONB", idx),
-                                s if s.ends_with("[]") => format!("${}::{}", idx, s),
-                                _ => format!("${}", idx),
-                            }
+            // Strip the "r#" prefix if present (for Rust keywords used as field names)
+            let col_name_clean = col_name.strip_prefix("r#").unwrap_or(&col_name).to_snake_case();
+            target_columns.push(format!("\"{}\"", col_name_clean));
+
+            // Find the SQL type for this column
+            let sql_type = col_info.map(|c| c.sql_type).unwrap_or("TEXT");
+
+            bindings.push((value, sql_type));
+        }
+
+        // Generate placeholders with proper type casting for PostgreSQL
+        let placeholders: Vec<String> = bindings
+            .iter()
+            .enumerate()
+            .map(|(i, (_, sql_type))| match self.driver {
+                Drivers::Postgres => {
+                    let idx = i + 1;
+                    // PostgreSQL requires explicit type casting for some types
+                    if temporal::is_temporal_type(sql_type) {
+                        // Use temporal module for type casting
+                        format!("${}{}", idx, temporal::get_postgres_type_cast(sql_type))
+                    } else {
+                        match *sql_type {
+                            "UUID" => format!("${}::UUID", idx),
+                            "NUMERIC" | "DECIMAL" => format!("${}::NUMERIC", idx),
+                            s if is_postgres_text_type(s) => format!("${}::{}", idx, s),
+                            "JSONB" | "jsonb" => format!("${}::JSONB", idx),
+                            s if s.ends_with("[]") => format!("${}::{}", idx, s),
+                            _ => format!("${}", idx),
                         }
                     }
-                    // MySQL and SQLite use simple ? placeholders
-                    _ => "?".to_string(),
-                })
-                .collect();
-
-            // Construct the INSERT query
-            let query_str = format!(
+                }
+                // MySQL and SQLite use simple ? placeholders
+                _ => "?".to_string(),
+            })
+            .collect();
+
+        // Construct the INSERT query
+        let query_str = if target_columns.is_empty() {
+            // Every column is generated by the database
+            match self.driver {
+                Drivers::MySQL => format!("INSERT INTO \"{}\" () VALUES ()", table_name),
+                _ => format!("INSERT INTO \"{}\" DEFAULT VALUES", table_name),
+            }
+        } else {
+            format!(
                 "INSERT INTO \"{}\" ({}) VALUES ({})",
                 table_name,
                 target_columns.join(", "),
                 placeholders.join(", ")
-            );
-
-            if self.debug_mode {
-                log::debug!("SQL: {}", query_str);
-            }
+            )
+        };
 
-            let mut args = AnyArguments::default();
+        let mut args = AnyArguments::default();
 
-            // Bind values using the optimized value_binding module
-            for (val_opt, sql_type) in bindings {
-                if let Some(val_str) = val_opt {
-                    if args.bind_value(&val_str, sql_type, &self.driver).is_err() {
-                        let _ = args.add(val_str);
-                    }
-                } else {
-                    match sql_type {
-                        "INTEGER" | "INT" | "INT4" | "SERIAL" => { let _ = args.add(None::<i32>); }
-                        "BIGINT" | "INT8" | "BIGSERIAL" => { let _ = args.add(None::<i64>); }
-                        "REAL" | "FLOAT4" => { let _ = args.add(None::<f32>); }
-                        "DOUBLE PRECISION" | "FLOAT8" | "FLOAT" => { let _ = args.add(None::<f64>); }
-                        "BOOLEAN" | "BOOL" => { let _ = args.add(None::<bool>); }
-                        _ => { let _ = args.add(None::<String>); }
-                    }
+        // Bind values using the optimized value_binding module
+        for (val_opt, sql_type) in bindings {
+            if let Some(val_str) = val_opt {
+                if args.bind_value(&val_str, sql_type, &self.driver).is_err() {
+                    let _ = args.add(val_str);
+                }
+            } else {
+                match sql_type {
+                    "INTEGER" | "INT" | "INT4" | "SERIAL" => { let _ = args.add(None::<i32>); }
+                    "BIGINT" | "INT8" | "BIGSERIAL" => { let _ = args.add(None::<i64>); }
+                    "REAL" | "FLOAT4" => { let _ = args.add(None::<f32>); }
+                    "DOUBLE PRECISION" | "FLOAT8" | "FLOAT" => { let _ = args.add(None::<f64>); }
+                    "BOOLEAN" | "BOOL" => { let _ = args.add(None::<bool>); }
+                    _ => { let _ = args.add(None::<String>); }
                 }
             }
+        }
 
-            // Execute the INSERT query
-            self.tx.execute(&query_str, args).await?;
-            Ok(())
-        })
+        Some((query_str, args))
     }
 
-    /// Inserts multiple records into the database in a single batch operation.
+    /// Inserts multiple records into the database in batch operations.
     ///
     /// This is significantly faster than performing individual inserts in a loop
-    /// as it generates a single SQL statement with multiple VALUES groups.
+    /// as it generates multi-row INSERT statements with one VALUES group per record.
+    ///
+    /// # Chunking
+    ///
+    /// Each statement binds one parameter per column per record, and drivers cap the
+    /// number of parameters a statement may carry (999 on SQLite, 65535 on PostgreSQL
+    /// and MySQL). Large batches are split into as many statements as needed and run
+    /// one after another. On a `Database` they run inside a transaction opened for
+    /// the purpose, so either every record is inserted or none is; on a `Transaction`
+    /// they simply join it.
     ///
     /// # Type Binding Strategy
     ///
@@ -2347,89 +3950,265 @@ where
                 return Ok(());
             }
 
-            let table_name = self.table_name.to_snake_case();
             let columns_info = <T as Model>::columns();
+            let rows_per_statement = (self.driver.max_bind_params() / columns_info.len().max(1)).max(1);
+            let statements: Vec<(String, AnyArguments<'_>)> = models
+                .chunks(rows_per_statement)
+                .map(|chunk| self.batch_insert_statement(chunk, &columns_info))
+                .collect();
 
-            // Collect all column names for the INSERT statement
-            // We use all columns defined in the model to ensure consistency across the batch
-            let target_columns: Vec<String> = columns_info
-                .iter()
-                .map(|c| {
-                    let col_name_clean = c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case();
-                    format!("\"{}\"", col_name_clean)
+            if statements.len() == 1 || self.tx.in_transaction() {
+                for (query_str, args) in statements {
+                    self.tx.execute(&query_str, args).await?;
+                }
+            } else {
+                // Keep a multi-statement batch atomic and on one connection
+                let db = self.tx.clone_db();
+                let tx = db.begin().await.map_err(|e| match e {
+                    crate::Error::DatabaseError(e) => e,
+                    other => sqlx::Error::Configuration(other.to_string().into()),
+                })?;
+                for (query_str, args) in statements {
+                    tx.execute(&query_str, args).await?;
+                }
+                tx.commit().await?;
+            }
+            self.invalidate_cache();
+            Ok(())
+        })
+    }
+
+    /// Inserts multiple records and returns the stored rows mapped to `R`.
+    ///
+    /// Works like [`batch_insert`](Self::batch_insert), including its chunking and
+    /// atomicity, but reads the inserted rows back so generated keys and column defaults
+    /// are available, e.g. to insert child rows right away. Leave auto-increment keys as
+    /// `0` to have them generated. PostgreSQL and SQLite append `RETURNING` to each
+    /// statement. MySQL has no `RETURNING`: it reads back the range of keys starting at
+    /// the statement's last insert id, which requires a single auto-increment primary key
+    /// left unset in every record and consecutive ids (the default
+    /// `innodb_autoinc_lock_mode` for multi-row inserts).
+    ///
+    /// When a single auto-increment primary key is generated for every record and `R`
+    /// includes it, the rows come back in insertion order (sorted by that key, since
+    /// SQLite returns `RETURNING` rows in no particular order). Otherwise the order is
+    /// unspecified; match rows on a key or unique column.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let posts: Vec<Post> = db.model::<Post>()
+    ///     .batch_insert_returning(&[
+    ///         Post { id: 0, title: "First".into() },
+    ///         Post { id: 0, title: "Second".into() },
+    ///     ])
+    ///     .await?;
+    /// let comments: Vec<Comment> = posts.iter().map(|p| Comment::welcome(p.id)).collect();
+    /// db.model::<Comment>().batch_insert(&comments).await?;
+    /// ```
+    pub fn batch_insert_returning<'b, R>(&'b mut self, models: &'b [T]) -> BoxFuture<'b, Result<Vec<R>, sqlx::Error>>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin + 'b,
+    {
+        Box::pin(async move {
+            if models.is_empty() {
+                return Ok(Vec::new());
+            }
+            self.check_select_coverage::<R>()?;
+
+            let columns_info = <T as Model>::columns();
+            let returning = self.returning_sql::<R>();
+            let sort_key = generated_key(models, &columns_info);
+            let generated_key = match (self.driver, sort_key) {
+                (Drivers::MySQL, Some(key)) => Some(key),
+                (Drivers::MySQL, None) => {
+                    return Err(sqlx::Error::Configuration(
+                        format!(
+                            "batch_insert_returning on MySQL needs a single auto-increment primary key left unset on '{}'",
+                            self.table_name
+                        )
+                        .into(),
+                    ));
+                }
+                _ => None,
+            };
+            let rows_per_statement = (self.driver.max_bind_params() / columns_info.len().max(1)).max(1);
+            let statements: Vec<(String, AnyArguments<'_>, usize)> = models
+                .chunks(rows_per_statement)
+                .map(|chunk| {
+                    let (mut query_str, args) = self.batch_insert_statement(chunk, &columns_info);
+                    if generated_key.is_none() {
+                        query_str.push_str(&format!(" RETURNING {}", returning));
+                    }
+                    (query_str, args, chunk.len())
                 })
                 .collect();
 
-            let mut value_groups = Vec::new();
-            let mut bind_index = 1;
+            let rows = if statements.len() == 1 || self.tx.in_transaction() {
+                self.insert_chunks_returning(&self.tx, statements, &returning, generated_key).await?
+            } else {
+                // Keep a multi-statement batch atomic and on one connection
+                let db = self.tx.clone_db();
+                let tx = db.begin().await.map_err(|e| match e {
+                    crate::Error::DatabaseError(e) => e,
+                    other => sqlx::Error::Configuration(other.to_string().into()),
+                })?;
+                let rows = self.insert_chunks_returning(&tx, statements, &returning, generated_key).await?;
+                tx.commit().await?;
+                rows
+            };
+            self.invalidate_cache();
+            let mut rows: Vec<R> = rows.iter().map(R::from_any_row).collect::<Result<_, _>>()?;
+            // Generated keys increase in insertion order, unlike the order of RETURNING rows
+            if let Some(key) = sort_key {
+                rows.sort_by_cached_key(|row| AnyImpl::to_map(row).remove(key).flatten().and_then(|v| v.parse::<i64>().ok()));
+            }
+            Ok(rows)
+        })
+    }
 
-            // Generate placeholders for all models
-            for _ in models {
-                let mut placeholders = Vec::new();
-                for col in &columns_info {
-                    match self.driver {
-                        Drivers::Postgres => {
-                            let p = if temporal::is_temporal_type(col.sql_type) {
-                                format!("${}{}", bind_index, temporal::get_postgres_type_cast(col.sql_type))
-                            } else {
-                                match col.sql_type {
-                                    "UUID" => format!("${}::UUID", bind_index),
-                                    "JSONB" | "jsonb" => format!("${}::JSONB", bind_index),
-                                    _ => format!("${}", bind_index),
-                                }
-                            };
-                            placeholders.push(p);
-                            bind_index += 1;
-                        }
-                        _ => {
-                            placeholders.push("?".to_string());
-                        }
-                    }
-                }
-                value_groups.push(format!("({})", placeholders.join(", ")));
+    /// Runs the statements of a `batch_insert_returning` on `conn` and collects the inserted rows.
+    async fn insert_chunks_returning<C: Connection>(
+        &self,
+        conn: &C,
+        statements: Vec<(String, AnyArguments<'_>, usize)>,
+        returning: &str,
+        generated_key: Option<&str>,
+    ) -> Result<Vec<AnyRow>, sqlx::Error> {
+        let mut rows = Vec::new();
+        for (query_str, args, count) in statements {
+            if self.debug_mode {
+                log::debug!("SQL: {}", query_str);
             }
 
-            let query_str = format!(
-                "INSERT INTO \"{}\" ({}) VALUES {}",
-                table_name,
-                target_columns.join(", "),
-                value_groups.join(", ")
+            let Some(key) = generated_key else {
+                rows.extend(conn.fetch_all(&query_str, args).await?);
+                continue;
+            };
+
+            // MySQL reports the first id of a multi-row insert; the rest follow it
+            let (_, first_id) = conn.execute_with_insert_id(&query_str, args).await?;
+            let first_id = first_id.ok_or_else(|| {
+                sqlx::Error::Configuration(format!("No generated id returned for '{}'", self.table_name).into())
+            })?;
+            let select_str = format!(
+                "SELECT {} FROM \"{}\" WHERE \"{}\" >= ? AND \"{}\" < ? ORDER BY \"{}\"",
+                returning, self.table_name, key, key, key
             );
+            let mut select_args = AnyArguments::default();
+            let _ = select_args.add(first_id);
+            let _ = select_args.add(first_id + count as i64);
 
             if self.debug_mode {
-                log::debug!("SQL Batch: {}", query_str);
+                log::debug!("SQL: {}", select_str);
             }
 
-            let mut args = AnyArguments::default();
+            rows.extend(conn.fetch_all(&select_str, select_args).await?);
+        }
+        Ok(rows)
+    }
+
+    /// Builds one multi-row INSERT statement and its arguments for `batch_insert`.
+    ///
+    /// Auto-increment columns left unset (`0` or `None`) in every record are left out
+    /// so the database generates them.
+    fn batch_insert_statement(&self, models: &[T], columns_info: &[ColumnInfo]) -> (String, AnyArguments<'static>) {
+        let table_name = self.table_name.to_string();
+        let data_maps: Vec<_> = models.iter().map(Model::to_map).collect();
+        let inserted: Vec<&ColumnInfo> = columns_info
+            .iter()
+            .filter(|c| !(c.auto_increment && data_maps.iter().all(|m| is_unset_key(m.get(c.name)))))
+            .collect();
+        // A multi-row VALUES list needs at least one column
+        let columns_info: Vec<&ColumnInfo> =
+            if inserted.is_empty() { columns_info.iter().collect() } else { inserted };
+
+        // Collect all column names for the INSERT statement
+        // We use the same columns for every record to keep the batch consistent
+        let target_columns: Vec<String> = columns_info
+            .iter()
+            .map(|c| {
+                let col_name_clean = c.name.strip_prefix("r#").unwrap_or(c.name).to_snake_case();
+                format!("\"{}\"", col_name_clean)
+            })
+            .collect();
+
+        let mut value_groups = Vec::new();
+        let mut bind_index = 1;
+
+        // Generate placeholders for all models
+        for _ in models {
+            let mut placeholders = Vec::new();
+            for col in &columns_info {
+                match self.driver {
+                    Drivers::Postgres => {
+                        let p = if temporal::is_temporal_type(col.sql_type) {
+                            format!("${}{}", bind_index, temporal::get_postgres_type_cast(col.sql_type))
+                        } else {
+                            match col.sql_type {
+                                "UUID" => format!("${}::UUID", bind_index),
+                                "NUMERIC" | "DECIMAL" => format!("${}::NUMERIC", bind_index),
+                                s if is_postgres_text_type(s) => format!("${}::{}", bind_index, s),
+                                "JSONB" | "jsonb" => format!("${}::JSONB", bind_index),
+                                _ => format!("${}", bind_index),
+                            }
+                        };
+                        placeholders.push(p);
+                        bind_index += 1;
+                    }
+                    _ => {
+                        placeholders.push("?".to_string());
+                    }
+                }
+            }
+            value_groups.push(format!("({})", placeholders.join(", ")));
+        }
 
-            for model in models {
-                let data_map = Model::to_map(model);
-                for col in &columns_info {
-                    let val_opt = data_map.get(col.name);
-                    let sql_type = col.sql_type;
+        let query_str = format!(
+            "INSERT INTO \"{}\" ({}) VALUES {}",
+            table_name,
+            target_columns.join(", "),
+            value_groups.join(", ")
+        );
 
-                    if let Some(Some(val_str)) = val_opt {
-                        if args.bind_value(val_str, sql_type, &self.driver).is_err() {
-                            let _ = args.add(val_str.clone());
-                        }
-                    } else {
-                        // Bind NULL for missing or None values
-                        match sql_type {
-                            "INTEGER" | "INT" | "INT4" | "SERIAL" => { let _ = args.add(None::<i32>); }
-                            "BIGINT" | "INT8" | "BIGSERIAL" => { let _ = args.add(None::<i64>); }
-                            "REAL" | "FLOAT4" => { let _ = args.add(None::<f32>); }
-                            "DOUBLE PRECISION" | "FLOAT8" | "FLOAT" => { let _ = args.add(None::<f64>); }
-                            "BOOLEAN" | "BOOL" => { let _ = args.add(None::<bool>); }
-                            _ => { let _ = args.add(None::<String>); }
-                        }
+        if self.debug_mode {
+            log::debug!("SQL Batch: {}", query_str);
+        }
+
+        let mut args = AnyArguments::default();
+
+        for data_map in &data_maps {
+            for col in &columns_info {
+                let sql_type = col.sql_type;
+                let mut val_opt = data_map.get(col.name).cloned();
+
+                // Stamp unset `create_time`/`update_time` columns with the current time
+                if (col.create_time || col.update_time)
+                    && temporal::is_temporal_type(sql_type)
+                    && temporal::is_unset_timestamp(val_opt.as_ref().and_then(|v| v.as_deref()))
+                {
+                    val_opt = Some(Some(temporal::current_timestamp_value(sql_type)));
+                }
+
+                if let Some(Some(val_str)) = &val_opt {
+                    if args.bind_value(val_str, sql_type, &self.driver).is_err() {
+                        let _ = args.add(val_str.clone());
+                    }
+                } else {
+                    // Bind NULL for missing or None values
+                    match sql_type {
+                        "INTEGER" | "INT" | "INT4" | "SERIAL" => { let _ = args.add(None::<i32>); }
+                        "BIGINT" | "INT8" | "BIGSERIAL" => { let _ = args.add(None::<i64>); }
+                        "REAL" | "FLOAT4" => { let _ = args.add(None::<f32>); }
+                        "DOUBLE PRECISION" | "FLOAT8" | "FLOAT" => { let _ = args.add(None::<f64>); }
+                        "BOOLEAN" | "BOOL" => { let _ = args.add(None::<bool>); }
+                        _ => { let _ = args.add(None::<String>); }
                     }
                 }
             }
+        }
 
-            // Execute the batch INSERT query
-            self.tx.execute(&query_str, args).await?;
-            Ok(())
-        })
+        (query_str, args)
     }
 
     /// Inserts a record or updates it if a conflict occurs (UPSERT).
@@ -2437,11 +4216,16 @@ where
     /// This method provides a cross-database way to perform "Insert or Update" operations.
     /// It uses `ON CONFLICT` for PostgreSQL and SQLite, and `ON DUPLICATE KEY UPDATE` for MySQL.
     ///
+    /// On MySQL each updated column is set to `VALUES(col)`, the value the INSERT
+    /// tried to write. MySQL picks the conflicting key itself, so `conflict_columns`
+    /// only narrows the default update list there.
+    ///
     /// # Arguments
     ///
     /// * `model` - The model instance to insert or update
     /// * `conflict_columns` - Columns that trigger the conflict (e.g., primary key or unique columns)
-    /// * `update_columns` - Columns to update when a conflict occurs
+    /// * `update_columns` - Columns to update when a conflict occurs. Pass an empty slice to
+    ///   update every column except the conflict columns and the primary key.
     ///
     /// # Returns
     ///
@@ -2455,6 +4239,9 @@ where
     ///
     /// // If id 1 exists, update username and age
     /// db.model::<User>().upsert(&user, &["id"], &["username", "age"]).await?;
+    ///
+    /// // If the username exists, update every other non-key column
+    /// db.model::<User>().upsert(&user, &["username"], &[]).await?;
     /// ```
     pub fn upsert<'b>(
         &'b mut self,
@@ -2463,21 +4250,35 @@ where
         update_columns: &'b [&'b str],
     ) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
         Box::pin(async move {
-            let data_map = Model::to_map(model);
-            if data_map.is_empty() {
+            let data_pairs = Model::to_ordered_pairs(model);
+            if data_pairs.is_empty() {
                 return Ok(0);
             }
 
-            let table_name = self.table_name.to_snake_case();
+            let table_name = self.table_name.to_string();
             let columns_info = <T as Model>::columns();
 
+            // Default SET list: every column that is neither a conflict target nor the primary key
+            let default_update_columns: Vec<&str>;
+            let update_columns: &[&str] = if update_columns.is_empty() {
+                default_update_columns = columns_info
+                    .iter()
+                    .filter(|c| !c.is_primary_key)
+                    .filter(|c| !conflict_columns.iter().any(|cc| cc.to_snake_case() == c.name.to_snake_case()))
+                    .map(|c| c.name)
+                    .collect();
+                &default_update_columns
+            } else {
+                update_columns
+            };
+
             let mut target_columns = Vec::new();
             let mut bindings: Vec<(Option<String>, &str)> = Vec::new();
 
             // Build INSERT part
-            for (col_name, value) in &data_map {
+            for (col_name, value) in &data_pairs {
                 let col_name_clean = col_name.strip_prefix("r#").unwrap_or(col_name).to_snake_case();
-                target_columns.push(format!("\"{}\"", col_name_clean));
+                target_columns.push(self.driver.quote_identifier(&col_name_clean));
 
                 let sql_type = columns_info.iter().find(|c| {
                     let c_clean = c.name.strip_prefix("r#").unwrap_or(c.name);
@@ -2496,6 +4297,8 @@ where
                         } else {
                             match *sql_type {
                                 "UUID" => format!("${}::UUID", arg_counter),
+                                "NUMERIC" | "DECIMAL" => format!("${}::NUMERIC", arg_counter),
+                                s if is_postgres_text_type(s) => format!("${}::{}", arg_counter, s),
                                 "JSONB" | "jsonb" => format!("${}::JSONB", arg_counter),
                                 _ => format!("${}", arg_counter),
                             }
@@ -2510,8 +4313,8 @@ where
             }
 
             let mut query_str = format!(
-                "INSERT INTO \"{}\" ({}) VALUES ({})",
-                table_name,
+                "INSERT INTO {} ({}) VALUES ({})",
+                self.driver.quote_identifier(&table_name),
                 target_columns.join(", "),
                 placeholders.join(", ")
             );
@@ -2521,7 +4324,7 @@ where
                 Drivers::Postgres | Drivers::SQLite => {
                     let conflict_cols_str = conflict_columns
                         .iter()
-                        .map(|c| format!("\"{}\"", c.to_snake_case()))
+                        .map(|c| self.driver.quote_identifier(&c.to_snake_case()))
                         .collect::<Vec<_>>()
                         .join(", ");
                     
@@ -2532,8 +4335,8 @@ where
 
                     for col in update_columns {
                         let col_snake = col.to_snake_case();
-                        if let Some((_key, val_opt)) = data_map.iter().find(|(k, _)| {
-                            let k_clean = k.strip_prefix("r#").unwrap_or(*k);
+                        if let Some((_key, val_opt)) = data_pairs.iter().find(|(k, _)| {
+                            let k_clean = k.strip_prefix("r#").unwrap_or(k);
                             k_clean == *col || k_clean.to_snake_case() == col_snake
                         }) {
                             let sql_type_opt = columns_info.iter().find(|c| {
@@ -2553,6 +4356,8 @@ where
                                     } else {
                                         match sql_type {
                                             "UUID" => format!("${}::UUID", arg_counter),
+                                            "NUMERIC" | "DECIMAL" => format!("${}::NUMERIC", arg_counter),
+                                            s if is_postgres_text_type(s) => format!("${}::{}", arg_counter, s),
                                             "JSONB" | "jsonb" => format!("${}::JSONB", arg_counter),
                                             _ => format!("${}", arg_counter),
                                         }
@@ -2562,7 +4367,7 @@ where
                                 }
                                 _ => "?".to_string(),
                             };
-                            update_clauses.push(format!("\"{}\" = {}", col_snake, placeholder));
+                            update_clauses.push(format!("{} = {}", self.driver.quote_identifier(&col_snake), placeholder));
                             update_bindings.push((val_opt.clone(), sql_type));
                         }
                     }
@@ -2576,9 +4381,10 @@ where
                 Drivers::MySQL => {
                     query_str.push_str(" ON DUPLICATE KEY UPDATE ");
                     let mut update_clauses = Vec::new();
+                    // Each column takes the value the INSERT attempted to write
                     for col in update_columns {
-                        let col_snake = col.to_snake_case();
-                        update_clauses.push(format!("\"{}\" = VALUES(\"{}\")", col_snake, col_snake));
+                        let col_quoted = self.driver.quote_identifier(&col.to_snake_case());
+                        update_clauses.push(format!("{} = VALUES({})", col_quoted, col_quoted));
                     }
                     query_str.push_str(&update_clauses.join(", "));
                 }
@@ -2607,6 +4413,7 @@ where
             }
 
             let result = self.tx.execute(&query_str, args).await?;
+            self.invalidate_cache();
             Ok(result.rows_affected())
         })
     }
@@ -2666,9 +4473,9 @@ where
             if matches!(self.driver, Drivers::Postgres) {
                 let mut args = Vec::new();
                 for s in &self.select_columns {
-                    for sub in s.split(',') {
-                        let s_trim = sub.trim();
-                        if s_trim.contains(' ') || s_trim.contains('(') {
+                    for sub in split_top_level(s) {
+                        let s_trim = sub.as_str();
+                        if !is_plain_column(&s_trim.replace('"', "")) {
                             args.push(s_trim.to_string());
                             continue;
                         }
@@ -2681,18 +4488,20 @@ where
 
                         let c_snake = c.to_snake_case();
                         let mut is_temporal = false;
+                        let mut is_text = false;
                         
                         // Check if this column is known to be temporal
                         if let Some(info) = self.columns_info.iter().find(|info| {
                             info.name.to_snake_case() == c_snake
                         }) {
-                            if is_temporal_type(info.sql_type) {
-                                is_temporal = true;
-                            }
+                            is_temporal = is_temporal_type(info.sql_type);
+                            is_text = is_postgres_text_type(info.sql_type);
                         }
 
                         if is_temporal {
                             args.push(format!("to_json(\"{}\".\"{}\") #>> '{{}}' AS \"{}\"", t, c, c));
+                        } else if is_text {
+                            args.push(format!("\"{}\".\"{}\"::TEXT AS \"{}\"", t, c, c));
                         } else {
                             args.push(format!("\"{}\".\"{}\"", t, c));
                         }
@@ -2705,7 +4514,7 @@ where
         }
         let mut flat_selects = Vec::new();
         for s in &self.select_columns {
-            for sub in s.split(',') { flat_selects.push(sub.trim().to_string()); }
+            flat_selects.extend(split_top_level(s));
         }
         let mut expanded_tables = HashSet::new();
         for s in &flat_selects {
@@ -2730,7 +4539,7 @@ where
                 let mut m = false;
                 if let Some((_, alias)) = s_lower.split_once(" as ") {
                     let ca = alias.trim().trim_matches('"').trim_matches('\'');
-                    if ca == col_info.column || ca == &col_snake { m = true; }
+                    if ca == col_info.column || ca == col_snake { m = true; }
                 } else if s == col_info.column || s == &col_snake || s.ends_with(&format!(".{}", col_info.column)) || s.ends_with(&format!(".{}", col_snake)) {
                     m = true;
                 }
@@ -2741,12 +4550,19 @@ where
         let mut args = Vec::new();
         if self.select_columns.is_empty() {
             for (s_idx, col_info) in struct_cols.iter().enumerate() {
+                if self.is_omitted(col_info, &main_table_snake) {
+                    // An omitted Option field keeps its slot and decodes as None
+                    if col_info.is_nullable && !self.is_default_omitted(col_info) {
+                        args.push(format!("NULL AS \"{}\"", self.select_field_alias::<R>(s_idx, &main_table_snake, &col_counts, is_tuple)));
+                    }
+                    continue;
+                }
                 let mut t_use = table_id.clone();
                 if !col_info.table.is_empty() {
                     let c_snake = col_info.table.to_snake_case();
                     if c_snake == main_table_snake { t_use = table_id.clone(); }
                     else if let Some(alias) = self.join_aliases.get(&c_snake) { t_use = alias.clone(); }
-                    else if self.join_aliases.values().any(|a| a == &col_info.table) { t_use = col_info.table.to_string(); }
+                    else if self.join_aliases.values().any(|a| a == col_info.table) { t_use = col_info.table.to_string(); }
                 }
                 args.push(self.format_select_field::<R>(s_idx, &t_use, &main_table_snake, &col_counts, is_tuple));
             }
@@ -2763,7 +4579,7 @@ where
                             let c_snake = col_info.table.to_snake_case();
                             if c_snake == main_table_snake { t_col = table_id.clone(); known = true; }
                             else if let Some(alias) = self.join_aliases.get(&c_snake) { t_col = alias.clone(); known = true; }
-                            else if self.join_aliases.values().any(|a| a == &col_info.table) { t_col = col_info.table.to_string(); known = true; }
+                            else if self.join_aliases.values().any(|a| a == col_info.table) { t_col = col_info.table.to_string(); known = true; }
                         }
                         if !known && !t_exp.is_empty() && flat_selects.iter().filter(|x| x.ends_with(".*") || *x == "*").count() == 1 { t_col = t_exp.clone(); known = true; }
                         if (t_exp.is_empty() && known) || (!t_exp.is_empty() && t_col == t_exp) {
@@ -2779,7 +4595,7 @@ where
                         args.push(self.format_select_field::<R>(*s_idx, &t, &main_table_snake, &col_counts, is_tuple));
                     }
                 } else {
-                    if !s_trim.contains(' ') && !s_trim.contains('(') {
+                    if is_plain_column(&s_trim.replace('"', "")) {
                         if let Some((t, c)) = s_trim.split_once('.') { args.push(format!("\"{}\".\"{}\"", t.trim().trim_matches('"'), c.trim().trim_matches('"'))); }
                         else { args.push(format!("\"{}\"", s_trim.trim_matches('"'))); }
                     } else { args.push(s_trim.to_string()); }
@@ -2789,21 +4605,257 @@ where
         if args.is_empty() { vec!["*".to_string()] } else { args }
     }
 
+    /// Builds the column list read back after an insert.
+    ///
+    /// The INSERT (and MySQL's follow-up `SELECT`) names the bare table, where an
+    /// alias qualifier is unknown, so columns are returned as plain quoted names.
+    fn returning_sql<R: AnyImpl>(&self) -> String {
+        let table_prefix = format!("\"{}\".", self.get_table_identifier());
+        self.select_args_sql::<R>().iter().map(|c| c.replace(&table_prefix, "")).collect::<Vec<_>>().join(", ")
+    }
+
+    /// Returns whether a result column was excluded through `omit()`.
+    fn is_omitted(&self, col_info: &AnyInfo, main_table_snake: &str) -> bool {
+        let col_snake = col_info.column.strip_prefix("r#").unwrap_or(col_info.column).to_snake_case();
+        self.omit_columns.contains(&col_snake)
+            && (col_info.table.is_empty() || col_info.table.to_snake_case() == main_table_snake)
+    }
+
+    /// Returns whether a model column is `#[orm(omit)]`, which the derived decoders (by name
+    /// and positional) fill with `Default::default()` when it is not selected, or report as
+    /// `ColumnNotFound` when the field type has no `Default`.
+    fn is_default_omitted(&self, col_info: &AnyInfo) -> bool {
+        col_info.table == self.table_name
+            && self.columns_info.iter().any(|c| c.omit && c.name == col_info.column)
+    }
+
+    /// Returns the plain columns selected next to an aggregate that no `group_by()` covers.
+    ///
+    /// Empty when the select list has no aggregate, no plain column, or when the query
+    /// is grouped by a primary key (other columns are then functionally dependent).
+    fn ungrouped_select_columns(&self) -> Vec<String> {
+        const AGGREGATES: [&str; 8] =
+            ["COUNT(", "SUM(", "AVG(", "MIN(", "MAX(", "GROUP_CONCAT(", "STRING_AGG(", "ARRAY_AGG("];
+
+        let items: Vec<String> = self.select_columns.iter().flat_map(|s| split_top_level(s)).collect();
+        let has_aggregate = items.iter().any(|item| {
+            let upper = item.to_uppercase().replace(' ', "");
+            // Window functions (`COUNT(*) OVER (...)`) keep one row per input row
+            AGGREGATES.iter().any(|agg| upper.contains(agg)) && !upper.contains(")OVER")
+        });
+        if !has_aggregate {
+            return Vec::new();
+        }
+
+        let (grouped, by_primary_key) = self.grouped_columns();
+        if by_primary_key {
+            return Vec::new();
+        }
+
+        items
+            .into_iter()
+            .filter_map(|item| {
+                let (expr, _) = split_select_alias(&item);
+                let bare = expr.replace('"', "");
+                if !is_plain_column(&bare) {
+                    return None;
+                }
+                let covered = grouped.iter().any(|g| *g == bare || unqualified(g) == unqualified(&bare));
+                (!covered).then_some(expr)
+            })
+            .collect()
+    }
+
+    /// Returns the unquoted `group_by()` columns and whether one of them is a primary key.
+    fn grouped_columns(&self) -> (Vec<String>, bool) {
+        let grouped: Vec<String> = self
+            .group_by_clauses
+            .iter()
+            .flat_map(|s| split_top_level(s))
+            .map(|g| g.replace(['"', '`'], ""))
+            .collect();
+        let by_primary_key =
+            grouped.iter().any(|g| self.columns_info.iter().any(|c| c.is_primary_key && unqualified(g) == c.name));
+        (grouped, by_primary_key)
+    }
+
+    /// Rejects decoding `R` from a grouped query when one of its columns is neither
+    /// grouped nor computed by an aggregate.
+    ///
+    /// Such a column holds the value of an arbitrary row of its group (or fails to
+    /// run on strict databases), so scanning the full model after `group_by()` would
+    /// return silently wrong data. Grouping by the primary key is accepted since every
+    /// other column then has a single value per group.
+    fn check_grouped_columns<R: AnyImpl>(&self) -> Result<(), sqlx::Error> {
+        if self.group_by_clauses.is_empty() {
+            return Ok(());
+        }
+        let (grouped, by_primary_key) = self.grouped_columns();
+        if by_primary_key {
+            return Ok(());
+        }
+
+        let items: Vec<String> = self.select_columns.iter().flat_map(|s| split_top_level(s)).collect();
+        for col_info in R::columns() {
+            let col_snake = col_info.column.strip_prefix("r#").unwrap_or(col_info.column).to_snake_case();
+            if grouped.iter().any(|g| unqualified(g) == col_snake) {
+                continue;
+            }
+            let computed = items.iter().any(|item| {
+                let (expr, alias) = split_select_alias(item);
+                let bare = expr.replace('"', "");
+                let name = alias.unwrap_or_else(|| unqualified(&bare));
+                name == col_snake && !is_plain_column(&bare)
+            });
+            if !computed {
+                return Err(sqlx::Error::Configuration(
+                    format!(
+                        "Column '{}' of '{}' is neither grouped nor aggregated, so its value would come from an \
+                         arbitrary row; scan_as() into a type holding only grouped and aggregate columns",
+                        col_snake,
+                        std::any::type_name::<R>()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects selects that mix aggregates and plain columns without a matching `group_by()`.
+    fn check_group_by(&self) -> Result<(), sqlx::Error> {
+        if self.auto_group_by {
+            return Ok(());
+        }
+        if let Some(col) = self.ungrouped_select_columns().first() {
+            return Err(sqlx::Error::Configuration(
+                format!(
+                    "Column '{}' is selected alongside an aggregate but is not in group_by(); \
+                     add .group_by(\"{}\") or call .auto_group_by()",
+                    col, col
+                )
+                .into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Warns that a multi-row read has no ORDER BY, so its row order is unspecified.
+    fn warn_if_unordered(&self) {
+        if self.order_clauses.is_empty() {
+            log::warn!(
+                "Scanning \"{}\" without ORDER BY; row order is unspecified and may change between runs",
+                self.table_name
+            );
+        }
+    }
+
+    /// Reports the first error found while the query was built.
+    pub(crate) fn check_deferred_error(&self) -> Result<(), sqlx::Error> {
+        match &self.deferred_error {
+            Some(msg) => Err(sqlx::Error::Configuration(msg.clone().into())),
+            None => Ok(()),
+        }
+    }
+
+    /// Rejects `distinct_on()` on drivers without `DISTINCT ON` support.
+    fn check_distinct_on(&self) -> Result<(), sqlx::Error> {
+        if !self.distinct_on.is_empty() && self.driver != Drivers::Postgres {
+            return Err(sqlx::Error::Configuration(
+                format!("distinct_on() requires PostgreSQL; {:?} has no DISTINCT ON", self.driver).into(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns whether the query groups its rows, explicitly or through `auto_group_by()`.
+    pub(crate) fn is_grouped(&self) -> bool {
+        !self.group_by_clauses.is_empty() || (self.auto_group_by && !self.ungrouped_select_columns().is_empty())
+    }
+
+    /// Verifies that the select list covers every column `R` needs to decode.
+    ///
+    /// Runs before the query is sent so a missing field (dropped by `omit()` or left out
+    /// of an explicit `select()`) is reported by name instead of as an opaque decode error.
+    /// `Option` fields dropped by `omit()` pass, since they are selected as NULL.
+    fn check_select_coverage<R: AnyImpl>(&self) -> Result<(), sqlx::Error> {
+        let struct_cols = R::columns();
+        if struct_cols.is_empty() {
+            return Ok(());
+        }
+        let main_table_snake = self.table_name.to_snake_case();
+        let flat_selects: Vec<String> = self
+            .select_columns
+            .iter()
+            .flat_map(|s| split_top_level(s))
+            .collect();
+
+        if flat_selects.iter().any(|s| s == "*" || s.ends_with(".*")) {
+            return Ok(());
+        }
+
+        for col_info in &struct_cols {
+            let col_snake = col_info.column.strip_prefix("r#").unwrap_or(col_info.column).to_snake_case();
+            let missing = if flat_selects.is_empty() {
+                self.is_omitted(col_info, &main_table_snake)
+                    && !self.is_default_omitted(col_info)
+                    && !col_info.is_nullable
+            } else {
+                !flat_selects.iter().any(|s| {
+                    let s_lower = s.to_lowercase();
+                    if let Some((_, alias)) = s_lower.rsplit_once(" as ") {
+                        let alias = alias.trim().trim_matches('"').trim_matches('\'');
+                        alias == col_info.column || alias == col_snake
+                    } else {
+                        let s_clean = s.replace('"', "");
+                        s_clean == col_info.column
+                            || s_clean == col_snake
+                            || s_clean.ends_with(&format!(".{}", col_info.column))
+                            || s_clean.ends_with(&format!(".{}", col_snake))
+                    }
+                })
+            };
+
+            if missing {
+                return Err(sqlx::Error::Configuration(
+                    format!(
+                        "Column '{}' required by '{}' is not in the select list (omitted or not selected); \
+                         select it or scan into a type without that field",
+                        col_snake,
+                        std::any::type_name::<R>()
+                    )
+                    .into(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn format_select_field<R: AnyImpl>(&self, s_idx: usize, table_to_use: &str, main_table_snake: &str, col_counts: &HashMap<String, usize>, is_tuple: bool) -> String {
         let col_info = &R::columns()[s_idx];
         let col_snake = col_info.column.strip_prefix("r#").unwrap_or(col_info.column).to_snake_case();
-        let has_collision = *col_counts.get(&col_snake).unwrap_or(&0) > 1;
-        let alias = if is_tuple || has_collision {
-            let t_alias = if !col_info.table.is_empty() { col_info.table.to_snake_case() } else { main_table_snake.to_string() };
-            format!("{}__{}", t_alias.to_lowercase(), col_snake.to_lowercase())
-        } else { col_snake.to_lowercase() };
+        let alias = self.select_field_alias::<R>(s_idx, main_table_snake, col_counts, is_tuple);
         if is_temporal_type(col_info.sql_type) && matches!(self.driver, Drivers::Postgres) {
             format!("to_json(\"{}\".\"{}\") #>> '{{}}' AS \"{}\"", table_to_use, col_snake, alias)
+        } else if is_postgres_text_type(col_info.sql_type) && matches!(self.driver, Drivers::Postgres) {
+            format!("\"{}\".\"{}\"::TEXT AS \"{}\"", table_to_use, col_snake, alias)
         } else {
             format!("\"{}\".\"{}\" AS \"{}\"", table_to_use, col_snake, alias)
         }
     }
 
+    /// Returns the name a result column is selected as: `table__column` in tuples or
+    /// when two tables share the column name, the plain column name otherwise.
+    fn select_field_alias<R: AnyImpl>(&self, s_idx: usize, main_table_snake: &str, col_counts: &HashMap<String, usize>, is_tuple: bool) -> String {
+        let col_info = &R::columns()[s_idx];
+        let col_snake = col_info.column.strip_prefix("r#").unwrap_or(col_info.column).to_snake_case();
+        let has_collision = *col_counts.get(&col_snake).unwrap_or(&0) > 1;
+        if is_tuple || has_collision {
+            let t_alias = if !col_info.table.is_empty() { col_info.table.to_snake_case() } else { main_table_snake.to_string() };
+            format!("{}__{}", t_alias.to_lowercase(), col_snake.to_lowercase())
+        } else { col_snake.to_lowercase() }
+    }
+
     /// Executes the query and returns a list of results.
     ///
     /// This method builds and executes a SELECT query with all accumulated filters,
@@ -2831,6 +4883,12 @@ where
     where
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        self.check_select_coverage::<R>()?;
+        self.check_grouped_columns::<R>()?;
+        self.check_group_by()?;
+        self.check_distinct_on()?;
+        self.check_unions::<R>()?;
+        self.check_deferred_error()?;
         self.apply_soft_delete_filter();
         let mut query = String::new();
         let mut args = AnyArguments::default();
@@ -2839,10 +4897,11 @@ where
         self.write_select_sql::<R>(&mut query, &mut args, &mut arg_counter);
 
         if self.debug_mode {
+            self.warn_if_unordered();
             log::debug!("SQL: {}", query);
         }
 
-        let rows = self.tx.fetch_all(&query, args).await?;
+        let rows = self.fetch_all_cached(&query, args).await?;
         let mut result = Vec::with_capacity(rows.len());
         for row in rows {
             result.push(R::from_any_row(&row)?);
@@ -2850,6 +4909,63 @@ where
         Ok(result)
     }
 
+    /// Executes the query and yields the rows one at a time instead of collecting them.
+    ///
+    /// Rows are decoded into `R` as they arrive from the database, so memory stays
+    /// bounded however many rows match. The stream owns the builder and keeps its
+    /// connection busy until it is dropped; on a `Transaction` the transaction is
+    /// locked for that whole time, so other queries on it wait until the stream is
+    /// finished or dropped. Query timeouts and the result cache do not apply.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut rows = db.model::<Event>().order("id ASC").scan_stream::<Event>();
+    /// while let Some(event) = rows.try_next().await? {
+    ///     export(&event)?;
+    /// }
+    /// ```
+    pub fn scan_stream<'s, R>(mut self) -> BoxStream<'s, Result<R, sqlx::Error>>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin + 's,
+        T: 's,
+        E: 's,
+    {
+        crate::database::channel_stream(move |mut sender| async move {
+            let checked = self
+                .check_select_coverage::<R>()
+                .and_then(|_| self.check_grouped_columns::<R>())
+                .and_then(|_| self.check_group_by())
+                .and_then(|_| self.check_distinct_on())
+                .and_then(|_| self.check_unions::<R>())
+                .and_then(|_| self.check_deferred_error());
+            if let Err(e) = checked {
+                let _ = sender.send(Err(e)).await;
+                return;
+            }
+            self.apply_soft_delete_filter();
+            let mut query = String::new();
+            let mut args = AnyArguments::default();
+            let mut arg_counter = 1;
+
+            self.write_select_sql::<R>(&mut query, &mut args, &mut arg_counter);
+
+            if self.debug_mode {
+                self.warn_if_unordered();
+                log::debug!("SQL: {}", query);
+            }
+
+            let mut rows = self.tx.fetch_stream(&query, args);
+            while let Some(row) = rows.next().await {
+                if sender.send(row.and_then(|row| R::from_any_row(&row))).await.is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
     /// Executes the query and eager loads the requested relationships.
     pub async fn scan_with(self) -> Result<Vec<T>, sqlx::Error>
     where
@@ -2910,6 +5026,95 @@ where
         Ok(results)
     }
 
+    /// Executes the query and loads each row's children from table `C` in batched queries.
+    ///
+    /// Unlike [`with`](Self::with), this needs no relation field on the model: the
+    /// children are matched on `C.foreign_key = T.<primary key>` and returned next to
+    /// their parent. Besides the main query, the children are read with
+    /// `WHERE foreign_key IN (...)`, one query per batch of parent keys that fits
+    /// the driver's bind parameter limit (999 on SQLite), so usually two statements
+    /// run in total (one when there are no parents). Soft-deleted children are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let users: Vec<Loaded<User, Vec<Post>>> = db.model::<User>()
+    ///     .order("id ASC")
+    ///     .scan_with_many::<Post>("user_id")
+    ///     .await?;
+    /// for Loaded { parent, children } in users {
+    ///     println!("{} wrote {} posts", parent.username, children.len());
+    /// }
+    /// ```
+    pub async fn scan_with_many<C>(self, foreign_key: &'static str) -> Result<Vec<Loaded<T, Vec<C>>>, sqlx::Error>
+    where
+        T: FromAnyRow,
+        C: Model + FromAnyRow + AnyImpl + Send + Sync + Unpin,
+        E: Clone,
+    {
+        let Some(primary_key) = <T as Model>::columns().into_iter().find(|c| c.is_primary_key) else {
+            return Err(sqlx::Error::Configuration(
+                format!("scan_with_many requires a primary key on \"{}\"", self.table_name).into(),
+            ));
+        };
+        let child_columns = <C as Model>::columns();
+        let Some(fk_type) = child_columns.iter().find(|c| c.name == foreign_key).map(|c| c.sql_type) else {
+            return Err(sqlx::Error::Configuration(
+                format!("\"{}\" has no column \"{}\"", C::table_name(), foreign_key).into(),
+            ));
+        };
+
+        let tx = self.tx.clone();
+        let driver = self.driver;
+        let parents: Vec<T> = self.scan().await?;
+        if parents.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let keys: Vec<Option<String>> =
+            parents.iter().map(|p| Model::to_map(p).remove(primary_key.name).flatten()).collect();
+        let mut ids: Vec<String> = keys.iter().flatten().cloned().collect();
+        ids.sort();
+        ids.dedup();
+
+        // One IN (...) per chunk keeps each statement under the driver's bind parameter cap
+        let mut children: Vec<C> = Vec::new();
+        for chunk in ids.chunks(driver.max_bind_params()) {
+            let chunk = chunk.to_vec();
+            let columns = C::active_columns().iter().map(|c| c.strip_prefix("r#").unwrap_or(c).to_snake_case()).collect();
+            let mut child_query = QueryBuilder::<C, E>::new(tx.clone(), driver, C::table_name(), child_columns.clone(), columns);
+            let table_id = child_query.get_table_identifier();
+            let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+                query.push_str(&format!(" AND \"{}\".\"{}\" IN (", table_id, foreign_key));
+                for (i, id) in chunk.iter().enumerate() {
+                    if i > 0 {
+                        query.push_str(", ");
+                    }
+                    push_typed_value(query, args, driver, arg_counter, id, fk_type);
+                }
+                query.push(')');
+            });
+            child_query.where_clauses.push(clause);
+            children.extend(child_query.scan().await?);
+        }
+
+        let mut by_parent: HashMap<String, Vec<C>> = HashMap::new();
+        for child in children {
+            if let Some(key) = Model::to_map(&child).remove(foreign_key).flatten() {
+                by_parent.entry(key).or_default().push(child);
+            }
+        }
+
+        Ok(parents
+            .into_iter()
+            .zip(keys)
+            .map(|(parent, key)| {
+                let children = key.and_then(|k| by_parent.remove(&k)).unwrap_or_default();
+                Loaded { parent, children }
+            })
+            .collect())
+    }
+
     /// Executes the query and maps the result to a custom DTO.
     ///
     /// Useful for queries that return only a subset of columns or join multiple tables.
@@ -2936,6 +5141,12 @@ where
     where
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        self.check_select_coverage::<R>()?;
+        self.check_grouped_columns::<R>()?;
+        self.check_group_by()?;
+        self.check_distinct_on()?;
+        self.check_unions::<R>()?;
+        self.check_deferred_error()?;
         self.apply_soft_delete_filter();
         let mut query = String::new();
         let mut args = AnyArguments::default();
@@ -2944,10 +5155,11 @@ where
         self.write_select_sql::<R>(&mut query, &mut args, &mut arg_counter);
 
         if self.debug_mode {
+            self.warn_if_unordered();
             log::debug!("SQL: {}", query);
         }
 
-        let rows = self.tx.fetch_all(&query, args).await?;
+        let rows = self.fetch_all_cached(&query, args).await?;
         let mut result = Vec::with_capacity(rows.len());
         for row in rows {
             result.push(R::from_any_row(&row)?);
@@ -2981,6 +5193,49 @@ where
     where
         R: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        let (query, args) = self.first_sql::<R>()?;
+        let row = self.fetch_one_cached(&query, args).await?;
+        R::from_any_row(&row)
+    }
+
+    /// Executes the query and returns the first result, or `None` if nothing matches.
+    ///
+    /// Behaves like [`first`](Self::first) but maps the "no row" case to `Ok(None)`
+    /// instead of `Err(sqlx::Error::RowNotFound)`, which suits existence checks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let existing: Option<User> = db.model::<User>()
+    ///     .filter("email", Op::Eq, email)
+    ///     .first_optional()
+    ///     .await?;
+    /// if existing.is_some() {
+    ///     return Err(AppError::EmailTaken);
+    /// }
+    /// ```
+    pub async fn first_optional<R>(mut self) -> Result<Option<R>, sqlx::Error>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        let (query, args) = self.first_sql::<R>()?;
+        match self.fetch_optional_cached(&query, args).await? {
+            Some(row) => Ok(Some(R::from_any_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Validates the builder and renders the `LIMIT 1` SELECT used by `first` and `first_optional`.
+    fn first_sql<R>(&mut self) -> Result<(String, AnyArguments<'static>), sqlx::Error>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        self.check_select_coverage::<R>()?;
+        self.check_grouped_columns::<R>()?;
+        self.check_group_by()?;
+        self.check_distinct_on()?;
+        self.check_unions::<R>()?;
+        self.check_deferred_error()?;
         self.apply_soft_delete_filter();
         let mut query = String::new();
         let mut args = AnyArguments::default();
@@ -3011,8 +5266,61 @@ where
             log::debug!("SQL: {}", query);
         }
 
-        let row = self.tx.fetch_one(&query, args).await?;
-        R::from_any_row(&row)
+        Ok((query, args))
+    }
+
+    /// Fetches the record whose primary key equals `pk_value`.
+    ///
+    /// The primary key column is read from the model, and the value is bound
+    /// according to that column's SQL type, so integer, `String` and `Uuid` keys
+    /// all work. Other filters on the builder still apply.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(R)` - The matching record
+    /// * `Err(sqlx::Error::RowNotFound)` - If no record has that key
+    /// * `Err(sqlx::Error::Configuration)` - If the model has no primary key or a composite one
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let user: User = db.model::<User>().find(user_id).await?;
+    /// // SQL: SELECT ... FROM "user" WHERE "user"."id" = $1 LIMIT 1
+    /// ```
+    pub async fn find<R, V>(mut self, pk_value: V) -> Result<R, sqlx::Error>
+    where
+        R: FromAnyRow + AnyImpl + Send + Unpin,
+        V: ToUpdateValue,
+    {
+        let primary_keys: Vec<&ColumnInfo> = self.columns_info.iter().filter(|c| c.is_primary_key).collect();
+        let pk = match primary_keys.as_slice() {
+            [pk] => (*pk).clone(),
+            [] => {
+                return Err(sqlx::Error::Configuration(
+                    format!("Model '{}' has no primary key", self.table_name).into(),
+                ));
+            }
+            _ => {
+                return Err(sqlx::Error::Configuration(
+                    format!("find() needs a single primary key, but '{}' has a composite one", self.table_name).into(),
+                ));
+            }
+        };
+
+        let column = format!("\"{}\".\"{}\"", self.get_table_identifier(), pk.name);
+        let value = pk_value.to_update_value();
+        let clause: FilterFn = Box::new(move |query, args, driver, arg_counter| {
+            query.push_str(" AND ");
+            query.push_str(&column);
+            let Some(value) = &value else {
+                query.push_str(" IS NULL");
+                return;
+            };
+            query.push_str(" = ");
+            push_typed_value(query, args, driver, arg_counter, value, pk.sql_type);
+        });
+        self.where_clauses.push(clause);
+        self.first::<R>().await
     }
 
     /// Executes the query and returns a single scalar value.
@@ -3044,6 +5352,10 @@ where
     where
         O: FromAnyRow + AnyImpl + Send + Unpin,
     {
+        self.check_group_by()?;
+        self.check_distinct_on()?;
+        self.check_unions::<O>()?;
+        self.check_deferred_error()?;
         self.apply_soft_delete_filter();
         let mut query = String::new();
         let mut args = AnyArguments::default();
@@ -3060,10 +5372,35 @@ where
             log::debug!("SQL: {}", query);
         }
 
-        let row = self.tx.fetch_one(&query, args).await?;
+        let row = self.fetch_one_cached(&query, args).await?;
         O::from_any_row(&row)
     }
 
+    /// Returns the values of a single column for every matching row.
+    ///
+    /// Selects only `column` and decodes each row into `V`, keeping filters, joins
+    /// and ordering. Lighter than `scan_as` into a one-field DTO. `column` must be
+    /// one of the model's columns or a known `table.column`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let names: Vec<String> = db.model::<User>()
+    ///     .order("username ASC")
+    ///     .pluck("username")
+    ///     .await?;
+    /// ```
+    pub async fn pluck<V>(mut self, column: &str) -> Result<Vec<V>, sqlx::Error>
+    where
+        V: FromAnyRow + AnyImpl + Send + Unpin,
+    {
+        if !self.is_orderable_column(column) {
+            return Err(sqlx::Error::Configuration(format!("Cannot pluck unknown column '{}'", column).into()));
+        }
+        self.select_columns = vec![self.driver.quote_identifier(column)];
+        self.scan::<V>().await
+    }
+
     /// Updates a single column in the database for all rows matching the filters.
     ///
     /// # Arguments
@@ -3087,15 +5424,15 @@ where
     where
         V: ToUpdateValue + Send + Sync,
     {
-        let mut map = std::collections::HashMap::new();
-        map.insert(col.to_string(), value.to_update_value());
-        self.execute_update(map)
+        self.execute_update(vec![(col.to_string(), value.to_update_value())])
     }
 
     /// Updates columns based on a model instance for all rows matching the filters.
     ///
     /// This method updates the table with values from the provided model.
-    /// Note: It updates ALL columns present in the model's `to_map()`.
+    /// Every column is written except the primary key and any column passed to
+    /// `omit()`. Values are bound in the model's column order. `update_time`
+    /// columns get the current time rather than the model's value.
     ///
     /// # Arguments
     ///
@@ -3115,7 +5452,13 @@ where
     ///     .await?;
     /// ```
     pub fn updates<'b>(&'b mut self, model: &T) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
-        self.execute_update(Model::to_map(model))
+        let skipped: Vec<&str> =
+            self.columns_info.iter().filter(|c| c.is_primary_key || c.update_time).map(|c| c.name).collect();
+        let assignments = Model::to_ordered_pairs(model)
+            .into_iter()
+            .filter(|(col, _)| !skipped.contains(&col.as_str()) && !self.omit_columns.contains(&col.to_snake_case()))
+            .collect();
+        self.execute_update(assignments)
     }
 
     /// Updates columns based on a partial model (struct implementing AnyImpl).
@@ -3147,7 +5490,115 @@ where
     ///     .await?;
     /// ```
     pub fn update_partial<'b, P: AnyImpl>(&'b mut self, partial: &P) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
-        self.execute_update(AnyImpl::to_map(partial))
+        let assignments = self.ordered_assignments(AnyImpl::to_map(partial));
+        self.execute_update(assignments)
+    }
+
+    /// Updates only the listed fields of `model` for all rows matching the filters.
+    ///
+    /// Unlike [`updates`](Self::updates), columns that are not listed keep their
+    /// current database values. `fields` may hold Rust field names or column names;
+    /// an unknown name fails the update before anything is sent to the database.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of rows affected
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// user.username = "new_name".to_string();
+    /// db.model::<User>()
+    ///     .filter("id", Op::Eq, user.id)
+    ///     .update_fields(&user, &["username"])
+    ///     .await?;
+    /// // SQL: UPDATE "user" SET "username" = $1 WHERE "id" = $2
+    /// ```
+    pub fn update_fields<'b>(&'b mut self, model: &T, fields: &[&str]) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
+        let unknown = fields.iter().find(|name| Model::update_map(model, &[**name]).is_empty());
+        if let Some(name) = unknown {
+            let message = format!("Cannot update unknown field '{}' on '{}'", name, self.table_name);
+            return Box::pin(async move { Err(sqlx::Error::Configuration(message.into())) });
+        }
+
+        let assignments = self.ordered_assignments(Model::update_map(model, fields));
+        self.execute_update(assignments)
+    }
+
+    /// Queues a column assignment for [`update_set`](Self::update_set).
+    ///
+    /// Assignments are applied in the order they were added.
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column name to update
+    /// * `value` - The new value (supports primitive types and Option for NULL)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<User>()
+    ///     .filter("id", Op::Eq, 1)
+    ///     .set("age", 31)
+    ///     .set("active", true)
+    ///     .update_set()
+    ///     .await?;
+    /// ```
+    pub fn set<V: ToUpdateValue>(mut self, col: &str, value: V) -> Self {
+        self.set_values.push((col.to_string(), value.to_update_value()));
+        self
+    }
+
+    /// Runs an UPDATE with the assignments queued by [`set`](Self::set).
+    ///
+    /// Every row matching the filters is updated. The assigned values are bound in the
+    /// order they were `set`, before the WHERE values. A column that is not part of the
+    /// model fails the update before anything is sent to the database.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of rows affected (0 when nothing was `set`)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // UPDATE "user" SET "active" = $1 WHERE 1=1 AND "user"."last_login" < $2
+    /// let deactivated = db.model::<User>()
+    ///     .filter("last_login", Op::Lt, cutoff)
+    ///     .set("active", false)
+    ///     .update_set()
+    ///     .await?;
+    /// ```
+    pub fn update_set<'b>(&'b mut self) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
+        let assignments = std::mem::take(&mut self.set_values);
+        let unknown = assignments.iter().find(|(col, _)| {
+            let clean = col.strip_prefix("r#").unwrap_or(col).to_snake_case();
+            !self.columns_info.iter().any(|c| c.name == col || c.name == clean)
+        });
+        if let Some((col, _)) = unknown {
+            let message = format!("Cannot update unknown column '{}' on '{}'", col, self.table_name);
+            return Box::pin(async move { Err(sqlx::Error::Configuration(message.into())) });
+        }
+        self.execute_update(assignments)
+    }
+
+    /// Orders a `to_map()` result by the model's column order.
+    ///
+    /// Keys that are not model columns are kept at the end in a stable order.
+    fn ordered_assignments(
+        &self,
+        mut data_map: std::collections::HashMap<String, Option<String>>,
+    ) -> Vec<(String, Option<String>)> {
+        let mut assignments = Vec::with_capacity(data_map.len());
+        for col in &self.columns_info {
+            if let Some((key, value)) = data_map.remove_entry(col.name) {
+                assignments.push((key, value));
+            }
+        }
+        let mut rest: Vec<_> = data_map.into_iter().collect();
+        rest.sort_by(|a, b| a.0.cmp(&b.0));
+        assignments.extend(rest);
+        assignments
     }
 
     /// Updates a column using a raw SQL expression.
@@ -3185,6 +5636,9 @@ where
     where
         V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
     {
+        if let Err(e) = self.check_deferred_error() {
+            return Box::pin(async move { Err(e) });
+        }
         self.apply_soft_delete_filter();
 
         let col_name_clean = col.strip_prefix("r#").unwrap_or(col).to_snake_case();
@@ -3192,7 +5646,7 @@ where
         let value_owned = value.clone();
 
         Box::pin(async move {
-            let table_name = self.table_name.to_snake_case();
+            let table_name = self.table_name.to_string();
             let mut query = format!("UPDATE \"{}\" ", table_name);
             if let Some(alias) = &self.alias {
                 query.push_str(&format!("AS {} ", alias));
@@ -3232,34 +5686,109 @@ where
             }
 
             let result = self.tx.execute(&query, args).await?;
+            self.invalidate_cache();
             Ok(result.rows_affected())
         })
     }
 
+    /// Adds `by` to a numeric column for all rows matching the filters.
+    ///
+    /// Runs a single `UPDATE ... SET col = col + ?`, so concurrent increments never
+    /// lose each other's writes the way a read-modify-write would. The column must be
+    /// one of the model's columns; an unknown name fails before anything is sent.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of rows affected
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<Post>()
+    ///     .filter(post_fields::ID, Op::Eq, post_id)
+    ///     .increment(post_fields::VIEWS, 1)
+    ///     .await?;
+    /// // SQL: UPDATE "post" SET "views" = "views" + $1 WHERE 1=1 AND "post"."id" = $2
+    /// ```
+    pub fn increment<'b, V>(&'b mut self, column: &str, by: V) -> BoxFuture<'b, Result<u64, sqlx::Error>>
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.update_counter(column, "+", by)
+    }
+
+    /// Subtracts `by` from a numeric column for all rows matching the filters.
+    ///
+    /// The counterpart of [`increment`](Self::increment), emitting `col = col - ?`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<Product>()
+    ///     .filter(product_fields::ID, Op::Eq, product_id)
+    ///     .filter(product_fields::STOCK, Op::Gte, quantity)
+    ///     .decrement(product_fields::STOCK, quantity)
+    ///     .await?;
+    /// ```
+    pub fn decrement<'b, V>(&'b mut self, column: &str, by: V) -> BoxFuture<'b, Result<u64, sqlx::Error>>
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        self.update_counter(column, "-", by)
+    }
+
+    fn update_counter<'b, V>(&'b mut self, column: &str, sign: &str, by: V) -> BoxFuture<'b, Result<u64, sqlx::Error>>
+    where
+        V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+    {
+        let col_name_clean = column.strip_prefix("r#").unwrap_or(column).to_snake_case();
+        if !self.columns_info.iter().any(|c| c.name == col_name_clean) {
+            let message = format!("Cannot update unknown column '{}' on '{}'", column, self.table_name);
+            return Box::pin(async move { Err(sqlx::Error::Configuration(message.into())) });
+        }
+        let expr = format!("\"{}\" {} ?", col_name_clean, sign);
+        self.update_raw(&col_name_clean, &expr, by)
+    }
+
     /// Internal helper to apply soft delete filter to where clauses if necessary.
     fn apply_soft_delete_filter(&mut self) {
-        if !self.with_deleted {
-            if let Some(soft_delete_col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name) {
-                let col_owned = soft_delete_col.to_string();
-                let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| {
-                    query.push_str(" AND ");
-                    query.push_str(&format!("\"{}\"", col_owned));
-                    query.push_str(" IS NULL");
-                });
-                self.where_clauses.push(clause);
-            }
+        if !self.with_deleted
+            && let Some(soft_delete_col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name)
+        {
+            let col_owned = soft_delete_col.to_string();
+            // Qualified so the filter stays unambiguous when a joined table has the same column
+            let table_id = self.get_table_identifier();
+            let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| {
+                query.push_str(" AND ");
+                query.push_str(&format!("\"{}\".\"{}\"", table_id, col_owned));
+                query.push_str(" IS NULL");
+            });
+            self.where_clauses.push(clause);
         }
     }
 
-    /// Internal helper to execute an UPDATE query from a map of values.
+    /// Internal helper to execute an UPDATE query from ordered column assignments.
+    ///
+    /// `update_time` columns that are not assigned explicitly are set to the current time.
     fn execute_update<'b>(
         &'b mut self,
-        data_map: std::collections::HashMap<String, Option<String>>,
+        mut assignments: Vec<(String, Option<String>)>,
     ) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
+        if let Err(e) = self.check_deferred_error() {
+            return Box::pin(async move { Err(e) });
+        }
         self.apply_soft_delete_filter();
 
+        if !assignments.is_empty() {
+            for col in self.columns_info.iter().filter(|c| c.update_time && temporal::is_temporal_type(c.sql_type)) {
+                if !assignments.iter().any(|(name, _)| name.strip_prefix("r#").unwrap_or(name).to_snake_case() == col.name) {
+                    assignments.push((col.name.to_string(), Some(temporal::current_timestamp_value(col.sql_type))));
+                }
+            }
+        }
+
         Box::pin(async move {
-            let table_name = self.table_name.to_snake_case();
+            let table_name = self.table_name.to_string();
             let mut query = format!("UPDATE \"{}\" ", table_name);
             if let Some(alias) = &self.alias {
                 query.push_str(&format!("{} ", alias));
@@ -3273,7 +5802,7 @@ where
             let mut arg_counter = 1;
 
             // Build SET clause
-            for (col_name, value) in data_map {
+            for (col_name, value) in assignments {
                 // Strip the "r#" prefix if present
                 let col_name_clean = col_name.strip_prefix("r#").unwrap_or(&col_name).to_snake_case();
 
@@ -3300,6 +5829,8 @@ where
                         } else {
                             match sql_type {
                                 "UUID" => format!("${}::UUID", idx),
+                                "NUMERIC" | "DECIMAL" => format!("${}::NUMERIC", idx),
+                                s if is_postgres_text_type(s) => format!("${}::{}", idx, s),
                                 "JSONB" | "jsonb" => format!("${}::JSONB", idx),
                                 s if s.ends_with("[]") => format!("${}::{}", idx, s),
                                 _ => format!("${}", idx),
@@ -3355,6 +5886,7 @@ where
 
             // Execute the UPDATE query
             let result = self.tx.execute(&query, args).await?;
+            self.invalidate_cache();
 
             Ok(result.rows_affected())
         })
@@ -3363,7 +5895,8 @@ where
     /// Executes a DELETE query based on the current filters.
     ///
     /// Performs a soft delete if the model has a soft delete column,
-    /// otherwise performs a permanent hard delete.
+    /// otherwise performs a permanent hard delete. A soft delete only stamps rows
+    /// that are not already deleted, so the original deletion time is kept.
     ///
     /// # Returns
     ///
@@ -3380,13 +5913,16 @@ where
     /// // SQL (Soft): UPDATE "user" SET "deleted_at" = NOW() WHERE "id" = 1
     /// // SQL (Hard): DELETE FROM "user" WHERE "id" = 1
     /// ```
-    pub async fn delete(self) -> Result<u64, sqlx::Error> {
+    pub async fn delete(mut self) -> Result<u64, sqlx::Error> {
+        self.check_deferred_error()?;
         // Check for soft delete column
         let soft_delete_col = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name);
 
         if let Some(col) = soft_delete_col {
+            self.apply_soft_delete_filter();
+
             // Soft Delete: Update the column to current timestamp
-            let table_name = self.table_name.to_snake_case();
+            let table_name = self.table_name.to_string();
             let mut query = format!("UPDATE \"{}\" ", table_name);
             if let Some(alias) = &self.alias {
                 query.push_str(&format!("{} ", alias));
@@ -3415,11 +5951,12 @@ where
             }
 
             let result = self.tx.execute(&query, args).await?;
+            self.invalidate_cache();
             Ok(result.rows_affected())
         } else {
             // Standard Delete (no soft delete column)
             let mut query = String::from("DELETE FROM \"");
-            query.push_str(&self.table_name.to_snake_case());
+            query.push_str(self.table_name);
             query.push_str("\" WHERE 1=1");
 
             let mut args = AnyArguments::default();
@@ -3435,10 +5972,50 @@ where
             }
 
             let result = self.tx.execute(&query, args).await?;
+            self.invalidate_cache();
             Ok(result.rows_affected())
         }
     }
 
+    /// Restores soft-deleted records by setting their soft delete column back to NULL.
+    ///
+    /// Only rows that are currently deleted and match the filters are touched.
+    /// Fails if the model has no `#[orm(soft_delete)]` column.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The number of rows restored
+    /// * `Err(sqlx::Error)` - Database error
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// db.model::<User>()
+    ///     .filter("id", Op::Eq, 1)
+    ///     .restore()
+    ///     .await?;
+    /// // SQL: UPDATE "user" SET "deleted_at" = NULL WHERE "id" = 1 AND "deleted_at" IS NOT NULL
+    /// ```
+    pub fn restore<'b>(&'b mut self) -> BoxFuture<'b, Result<u64, sqlx::Error>> {
+        let Some(col) = self.columns_info.iter().find(|c| c.soft_delete).map(|c| c.name.to_string()) else {
+            let table_name = self.table_name;
+            return Box::pin(async move {
+                Err(sqlx::Error::Configuration(
+                    format!("Model '{}' has no soft delete column to restore", table_name).into(),
+                ))
+            });
+        };
+
+        self.with_deleted = true;
+        let col_owned = col.clone();
+        let table_id = self.get_table_identifier();
+        let clause: FilterFn = Box::new(move |query, _args, _driver, _arg_counter| {
+            query.push_str(&format!(" AND \"{}\".\"{}\" IS NOT NULL", table_id, col_owned));
+        });
+        self.where_clauses.push(clause);
+        self.execute_update(vec![(col, None)])
+    }
+
     /// Permanently removes records from the database.
     ///
     /// # Returns
@@ -3456,8 +6033,9 @@ where
     /// // SQL: DELETE FROM "user" WHERE "id" = 1
     /// ```
     pub async fn hard_delete(self) -> Result<u64, sqlx::Error> {
+        self.check_deferred_error()?;
         let mut query = String::from("DELETE FROM \"");
-        query.push_str(&self.table_name.to_snake_case());
+        query.push_str(self.table_name);
         query.push_str("\" WHERE 1=1");
 
         let mut args = AnyArguments::default();
@@ -3473,6 +6051,152 @@ where
         }
 
         let result = self.tx.execute(&query, args).await?;
+        self.invalidate_cache();
         Ok(result.rows_affected())
     }
 }
+
+/// Returns whether values of `sql_type` travel as text on PostgreSQL.
+///
+/// Such columns (`TSTZRANGE`, `POINT`) have no `Any` driver mapping, so values are
+/// bound as strings cast to the column type and selected with a `::TEXT` cast.
+fn is_postgres_text_type(sql_type: &str) -> bool {
+    // Quoted names are native enum types (`#[orm(pg_enum)]`)
+    matches!(sql_type, "TSTZRANGE" | "POINT") || sql_type.starts_with('"')
+}
+
+/// Appends a placeholder for `value` and binds it according to the column's SQL type.
+///
+/// PostgreSQL placeholders get the cast the column type needs (`::UUID`,
+/// `::TIMESTAMPTZ`, ...) so a string value compares against the typed column.
+pub(crate) fn push_typed_value(
+    query: &mut String,
+    args: &mut AnyArguments<'_>,
+    driver: &Drivers,
+    arg_counter: &mut usize,
+    value: &str,
+    sql_type: &str,
+) {
+    match driver {
+        Drivers::Postgres => {
+            let cast = if temporal::is_temporal_type(sql_type) {
+                temporal::get_postgres_type_cast(sql_type)
+            } else {
+                match sql_type {
+                    "UUID" => "::UUID",
+                    "NUMERIC" | "DECIMAL" => "::NUMERIC",
+                    s if is_postgres_text_type(s) => &format!("::{}", s),
+                    _ => "",
+                }
+            };
+            query.push_str(&format!("${}{}", arg_counter, cast));
+            *arg_counter += 1;
+        }
+        _ => query.push('?'),
+    }
+    if args.bind_value(value, sql_type, driver).is_err() {
+        let _ = args.add(value.to_string());
+    }
+}
+
+/// Looks up the row where `column = value`, inserting `default` when none exists.
+///
+/// Shared by `Database::get_or_create` and `Transaction::get_or_create`. If the
+/// insert loses a race and hits a unique violation, the winner's row is read back.
+pub(crate) async fn get_or_create<T, E, V>(
+    make: impl Fn() -> QueryBuilder<T, E>,
+    column: &'static str,
+    value: V,
+    default: &T,
+) -> Result<(T, bool), sqlx::Error>
+where
+    T: Model + FromAnyRow + AnyImpl + Send + Sync + Unpin,
+    E: Connection,
+    V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+{
+    if let Some(existing) = make().filter(column, Op::Eq, Bind(value.clone())).first_optional::<T>().await? {
+        return Ok((existing, false));
+    }
+
+    let created = match make().insert(default).await {
+        Ok(()) => true,
+        Err(sqlx::Error::Database(e)) if e.is_unique_violation() => false,
+        Err(e) => return Err(e),
+    };
+    let row = make().filter(column, Op::Eq, Bind(value)).first::<T>().await?;
+    Ok((row, created))
+}
+
+/// Writes one query of a UNION so its ORDER BY and LIMIT stay scoped to it.
+///
+/// SQLite rejects parenthesized compound members, so there it selects from a subquery.
+fn write_union_member(driver: Drivers, query: &mut String, write: impl FnOnce(&mut String)) {
+    match driver {
+        Drivers::SQLite => query.push_str("SELECT * FROM ("),
+        _ => query.push('('),
+    }
+    write(query);
+    query.push(')');
+}
+
+/// Returns whether a serialized key value leaves an auto-increment column to the database.
+fn is_unset_key(value: Option<&Option<String>>) -> bool {
+    value.is_none_or(|v| v.as_deref().is_none_or(|v| v == "0"))
+}
+
+/// Returns the single auto-increment primary key that the database generates for every record.
+fn generated_key<T: Model>(models: &[T], columns_info: &[ColumnInfo]) -> Option<&'static str> {
+    let keys: Vec<&ColumnInfo> = columns_info.iter().filter(|c| c.is_primary_key).collect();
+    match keys.as_slice() {
+        [key] if key.auto_increment && models.iter().all(|m| is_unset_key(Model::to_map(m).get(key.name))) => Some(key.name),
+        _ => None,
+    }
+}
+
+/// Strips the table qualifier from a column reference.
+fn unqualified(column: &str) -> String {
+    column.rsplit('.').next().unwrap_or(column).to_string()
+}
+
+/// Returns whether an unquoted select expression is a bare (optionally qualified) column.
+fn is_plain_column(bare: &str) -> bool {
+    !bare.is_empty()
+        && !bare.starts_with(|c: char| c.is_ascii_digit())
+        && bare.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.')
+}
+
+/// Splits a select item into its expression and its unquoted `AS` alias, if any.
+fn split_select_alias(item: &str) -> (String, Option<String>) {
+    match item.to_lowercase().rfind(" as ") {
+        Some(pos) => (
+            item[..pos].trim().to_string(),
+            Some(item[pos + 4..].trim().trim_matches('"').trim_matches('`').to_string()),
+        ),
+        None => (item.to_string(), None),
+    }
+}
+
+/// Splits a comma-separated SQL list, ignoring commas nested inside parentheses.
+fn split_top_level(list: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut depth = 0usize;
+    let mut current = String::new();
+
+    for c in list.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        items.push(current.trim().to_string());
+    }
+    items
+}
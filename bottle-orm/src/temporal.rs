@@ -389,3 +389,29 @@ pub fn format_naive_datetime_for_driver(value: &NaiveDateTime, driver: &Drivers)
         Drivers::SQLite => value.format("%Y-%m-%d %H:%M:%S%.f").to_string(),
     }
 }
+
+// ============================================================================
+// Automatic Timestamps
+// ============================================================================
+
+/// Returns the current time formatted for a `create_time`/`update_time` column.
+///
+/// The string matches the column's SQL type so it can go through the regular
+/// value binding path.
+pub(crate) fn current_timestamp_value(sql_type: &str) -> String {
+    let now = Utc::now();
+    match sql_type.to_uppercase().as_str() {
+        "DATE" | "NAIVEDATE" => now.date_naive().format("%Y-%m-%d").to_string(),
+        "TIME" | "NAIVETIME" => now.time().format("%H:%M:%S%.f").to_string(),
+        "TIMESTAMP" | "TIMESTAMP WITHOUT TIME ZONE" | "NAIVEDATETIME" => {
+            now.naive_utc().format("%Y-%m-%d %H:%M:%S%.f").to_string()
+        }
+        _ => now.to_rfc3339(),
+    }
+}
+
+/// Returns `true` when a timestamp value counts as unset: `NULL`, or the Unix
+/// epoch that chrono's `Default` produces.
+pub(crate) fn is_unset_timestamp(value: Option<&str>) -> bool {
+    value.is_none_or(|v| parse_datetime_utc(v).is_ok_and(|dt| dt.timestamp() == 0 && dt.timestamp_subsec_nanos() == 0))
+}
@@ -8,17 +8,27 @@
 // ============================================================================
 
 use heck::ToSnakeCase;
-use std::sync::Arc;
+use std::{
+    ops::Deref,
+    sync::{atomic::AtomicU64, Arc},
+    time::Duration,
+};
 use tokio::sync::Mutex;
-use futures::future::BoxFuture;
-use sqlx::any::AnyArguments;
+use futures::{future::BoxFuture, stream::BoxStream, SinkExt, StreamExt};
+use sqlx::{any::AnyArguments, Encode, Type};
 
 // ============================================================================
 // Internal Crate Imports
 // ============================================================================
 
 use crate::{
-    database::{Connection, Drivers, RawQuery},
+    any_struct::FromAnyRow,
+    cache::QueryCache,
+    database::{
+        channel_stream, observe, observe_stream, record_query, sqlite_execute_with_rowid, with_timeout, Connection, Drivers,
+        QueryHook, RawQuery,
+    },
+    dynamic::DynamicQuery,
     Model, QueryBuilder,
 };
 
@@ -30,11 +40,26 @@ use crate::{
 ///
 /// Provides a way to execute multiple queries atomically. If any query fails,
 /// the transaction can be rolled back. If all succeed, it can be committed.
+///
+/// # Dropping
+///
+/// A transaction that is dropped without calling [`commit`](Self::commit) or
+/// [`rollback`](Self::rollback) is rolled back. Clones share the same underlying
+/// transaction, so the rollback happens when the last clone (including any
+/// `QueryBuilder` created from it) goes away. The `ROLLBACK` is queued on the
+/// connection and runs before the connection is handed out by the pool again,
+/// so forgetting to commit never persists partial work.
 #[derive(Debug, Clone)]
 pub struct Transaction<'a> {
-    pub(crate) tx: Arc<Mutex<Option<sqlx::Transaction<'a, sqlx::Any>>>>,
+    pub(crate) tx: Arc<SharedTransaction<'a>>,
     pub(crate) pool: sqlx::AnyPool,
     pub(crate) driver: Drivers,
+    pub(crate) cache: QueryCache,
+    /// Tables written in this transaction, invalidated in `cache` on commit
+    pub(crate) pending_invalidations: Arc<std::sync::Mutex<Vec<String>>>,
+    pub(crate) query_counter: Option<Arc<AtomicU64>>,
+    pub(crate) query_timeout: Option<Duration>,
+    pub(crate) on_query: Option<QueryHook>,
 }
 
 // Transaction is Send and Sync because it uses Arc<Mutex>.
@@ -50,7 +75,8 @@ impl Connection for Transaction<'_> {
         Box::pin(async move {
             let mut guard = self.tx.lock().await;
             if let Some(tx) = guard.as_mut() {
-                sqlx::query_with(sql, args).execute(&mut **tx).await
+                record_query(&self.query_counter);
+                observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).execute(&mut **tx))).await
             } else {
                 Err(sqlx::Error::WorkerCrashed)
             }
@@ -61,7 +87,8 @@ impl Connection for Transaction<'_> {
         Box::pin(async move {
             let mut guard = self.tx.lock().await;
             if let Some(tx) = guard.as_mut() {
-                sqlx::query_with(sql, args).fetch_all(&mut **tx).await
+                record_query(&self.query_counter);
+                observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).fetch_all(&mut **tx))).await
             } else {
                 Err(sqlx::Error::WorkerCrashed)
             }
@@ -72,7 +99,8 @@ impl Connection for Transaction<'_> {
         Box::pin(async move {
             let mut guard = self.tx.lock().await;
             if let Some(tx) = guard.as_mut() {
-                sqlx::query_with(sql, args).fetch_one(&mut **tx).await
+                record_query(&self.query_counter);
+                observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).fetch_one(&mut **tx))).await
             } else {
                 Err(sqlx::Error::WorkerCrashed)
             }
@@ -83,7 +111,43 @@ impl Connection for Transaction<'_> {
         Box::pin(async move {
             let mut guard = self.tx.lock().await;
             if let Some(tx) = guard.as_mut() {
-                sqlx::query_with(sql, args).fetch_optional(&mut **tx).await
+                record_query(&self.query_counter);
+                observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlx::query_with(sql, args).fetch_optional(&mut **tx))).await
+            } else {
+                Err(sqlx::Error::WorkerCrashed)
+            }
+        })
+    }
+
+    fn fetch_stream<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxStream<'a, Result<sqlx::any::AnyRow, sqlx::Error>> {
+        // The lock is held until the stream is dropped, since the rows borrow the connection
+        channel_stream(move |mut sender| async move {
+            let mut guard = self.tx.lock().await;
+            let Some(tx) = guard.as_mut() else {
+                let _ = sender.send(Err(sqlx::Error::WorkerCrashed)).await;
+                return;
+            };
+            record_query(&self.query_counter);
+            let mut rows = observe_stream(self.on_query.clone(), sql, sqlx::query_with(sql, args).fetch(&mut **tx));
+            while let Some(row) = rows.next().await {
+                if sender.send(row).await.is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
+    fn execute_with_insert_id<'a, 'q: 'a>(&'a self, sql: &'q str, args: AnyArguments<'q>) -> BoxFuture<'a, Result<(sqlx::any::AnyQueryResult, Option<i64>), sqlx::Error>> {
+        Box::pin(async move {
+            if self.driver != Drivers::SQLite {
+                let result = self.execute(sql, args).await?;
+                let id = result.last_insert_id();
+                return Ok((result, id));
+            }
+            let mut guard = self.tx.lock().await;
+            if let Some(tx) = guard.as_mut() {
+                record_query(&self.query_counter);
+                observe(&self.on_query, sql, with_timeout(self.query_timeout, sqlite_execute_with_rowid(tx, sql, args))).await
             } else {
                 Err(sqlx::Error::WorkerCrashed)
             }
@@ -94,6 +158,67 @@ impl Connection for Transaction<'_> {
         crate::Database {
             pool: self.pool.clone(),
             driver: self.driver,
+            cache: self.cache.clone(),
+            query_counter: self.query_counter.clone(),
+            query_timeout: self.query_timeout,
+            on_query: self.on_query.clone(),
+        }
+    }
+
+    // Uncommitted rows must not reach the shared cache, so reads bypass it
+    fn query_cache(&self) -> Option<&QueryCache> {
+        None
+    }
+
+    fn invalidate_cached(&self, table: &str) {
+        let mut pending = self.pending_invalidations.lock().unwrap_or_else(|e| e.into_inner());
+        if !pending.iter().any(|t| t == table) {
+            pending.push(table.to_string());
+        }
+    }
+
+    fn in_transaction(&self) -> bool {
+        true
+    }
+
+    fn set_query_timeout(&mut self, timeout: Option<Duration>) {
+        self.query_timeout = timeout;
+    }
+}
+
+// ============================================================================
+// Drop Implementation
+// ============================================================================
+
+/// The sqlx transaction shared by every clone of a [`Transaction`].
+///
+/// The slot is emptied by `commit` and `rollback`, so it still holds the
+/// transaction only if neither ran. It is dropped exactly once, with the last
+/// clone, and rolls back an uncompleted transaction at that point.
+#[derive(Debug)]
+pub(crate) struct SharedTransaction<'a>(Mutex<Option<sqlx::Transaction<'a, sqlx::Any>>>);
+
+impl<'a> SharedTransaction<'a> {
+    pub(crate) fn new(tx: sqlx::Transaction<'a, sqlx::Any>) -> Self {
+        Self(Mutex::new(Some(tx)))
+    }
+}
+
+impl<'a> Deref for SharedTransaction<'a> {
+    type Target = Mutex<Option<sqlx::Transaction<'a, sqlx::Any>>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for SharedTransaction<'_> {
+    fn drop(&mut self) {
+        if let Some(tx) = self.0.get_mut().take() {
+            log::warn!("Transaction dropped without commit or rollback; rolling back");
+            // Dropping an open sqlx transaction queues a ROLLBACK that is flushed
+            // when the connection is returned to the pool.
+            drop(tx);
         }
     }
 }
@@ -103,6 +228,11 @@ impl Connection for Transaction<'_> {
 // ============================================================================
 
 impl<'a> Transaction<'a> {
+    /// Returns the driver of the database this transaction runs on.
+    pub fn driver(&self) -> Drivers {
+        self.driver
+    }
+
     /// Starts building a query within this transaction.
     pub fn model<T: Model + Send + Sync + Unpin + crate::AnyImpl>(
         &self,
@@ -117,19 +247,102 @@ impl<'a> Transaction<'a> {
         QueryBuilder::new(self.clone(), self.driver, T::table_name(), <T as Model>::columns(), columns)
     }
 
+    /// Returns the row where `column = value`, inserting `default` if there is none.
+    ///
+    /// See [`Database::get_or_create`](crate::Database::get_or_create). On
+    /// PostgreSQL a failed insert aborts the transaction, so the race fallback
+    /// only helps outside a transaction there.
+    pub async fn get_or_create<T, V>(
+        &self,
+        column: &'static str,
+        value: V,
+        default: &T,
+    ) -> Result<(T, bool), crate::Error>
+    where
+        T: Model + FromAnyRow + crate::AnyImpl + Send + Sync + Unpin,
+        V: 'static + for<'q> Encode<'q, sqlx::Any> + Type<sqlx::Any> + Send + Sync + Clone,
+    {
+        Ok(crate::query_builder::get_or_create(|| self.model::<T>(), column, value, default).await?)
+    }
+
+    /// Creates a temporary table for `T` on this transaction's connection.
+    ///
+    /// See [`Database::create_temp_table`](crate::Database::create_temp_table).
+    pub async fn create_temp_table<T: Model>(&self) -> Result<(), crate::Error> {
+        for statement in self.clone_db().create_table_statements::<T>(true)? {
+            self.execute(&statement, AnyArguments::default()).await?;
+        }
+        Ok(())
+    }
+
     /// Creates a raw SQL query builder attached to this transaction.
     pub fn raw<'b>(&self, sql: &'b str) -> RawQuery<'b, Self> {
         RawQuery::new(self.clone(), sql)
     }
 
+    /// Starts a query on a table known only by name, within this transaction.
+    pub fn table(&self, name: &str) -> DynamicQuery<Self> {
+        DynamicQuery::new(self.clone(), self.driver, name)
+    }
+
+    /// Creates a savepoint named `name` inside the transaction.
+    ///
+    /// Work done after the savepoint can be undone with [`rollback_to`](Self::rollback_to)
+    /// without aborting the rest of the transaction. Names may only contain ASCII
+    /// letters, digits and `_`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// tx.savepoint("profile").await?;
+    /// if tx.model::<Profile>().insert(&profile).await.is_err() {
+    ///     tx.rollback_to("profile").await?;
+    /// } else {
+    ///     tx.release_savepoint("profile").await?;
+    /// }
+    /// tx.commit().await?;
+    /// ```
+    pub async fn savepoint(&self, name: &str) -> Result<(), sqlx::Error> {
+        self.savepoint_statement("SAVEPOINT", name).await
+    }
+
+    /// Undoes everything done since the savepoint `name` was created.
+    ///
+    /// The savepoint stays active, so it can be rolled back to again.
+    pub async fn rollback_to(&self, name: &str) -> Result<(), sqlx::Error> {
+        self.savepoint_statement("ROLLBACK TO SAVEPOINT", name).await
+    }
+
+    /// Releases the savepoint `name`, keeping the work done since it was created.
+    pub async fn release_savepoint(&self, name: &str) -> Result<(), sqlx::Error> {
+        self.savepoint_statement("RELEASE SAVEPOINT", name).await
+    }
+
+    async fn savepoint_statement(&self, statement: &str, name: &str) -> Result<(), sqlx::Error> {
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(sqlx::Error::Configuration(
+                format!("invalid savepoint name `{}`: only ASCII letters, digits and `_` are allowed", name).into(),
+            ));
+        }
+        let sql = format!("{} {}", statement, self.driver.quote_identifier(name));
+        self.execute(&sql, AnyArguments::default()).await?;
+        Ok(())
+    }
+
     /// Commits the transaction.
+    ///
+    /// Cached reads of the tables written in the transaction are dropped once the
+    /// commit succeeds.
     pub async fn commit(self) -> Result<(), sqlx::Error> {
         let mut guard = self.tx.lock().await;
         if let Some(tx) = guard.take() {
-            tx.commit().await
-        } else {
-            Ok(())
+            tx.commit().await?;
+            let pending = std::mem::take(&mut *self.pending_invalidations.lock().unwrap_or_else(|e| e.into_inner()));
+            for table in pending {
+                self.cache.invalidate(&table);
+            }
         }
+        Ok(())
     }
 
     /// Rolls back the transaction.
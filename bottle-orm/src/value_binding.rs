@@ -14,11 +14,191 @@
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use sqlx::any::AnyArguments;
-use sqlx::Arguments;
+use sqlx::{Any, Arguments, Encode, Type};
 use uuid::Uuid;
 
 use crate::{database::Drivers, temporal, Error};
 
+// ============================================================================
+// Filter Values
+// ============================================================================
+
+/// A value that can be passed to `QueryBuilder::filter` and its variants.
+///
+/// Filters run after the builder is assembled, so every value is stored as an
+/// owned copy. Implemented for the types the `Any` driver encodes (`bool`,
+/// `i16`, `i32`, `i64`, `f32`, `f64`, `String`, `Vec<u8>`), their `Option`s,
+/// `&str`, `&[u8]` and their `Option`s, and references to any implementor, so callers can pass
+/// `&str` or `&user.name` without cloning first. Other types that implement
+/// sqlx's `Encode` and `Type` for `Any` can be passed wrapped in [`Bind`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// db.model::<User>()
+///     .filter("username", Op::Eq, "alice")
+///     .filter("email", Op::Eq, &form.email)
+///     .scan()
+///     .await?;
+/// ```
+pub trait IntoBindValue {
+    /// The owned value that gets bound to the query
+    type Value: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone;
+
+    /// Converts into the owned value.
+    fn into_bind_value(self) -> Self::Value;
+}
+
+/// Wraps any `Encode`-able value so it can be used as a filter value.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// db.model::<Ticket>().filter("status", Op::Eq, Bind(status)).scan().await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bind<V>(pub V);
+
+impl<V> IntoBindValue for Bind<V>
+where
+    V: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone,
+{
+    type Value = V;
+
+    fn into_bind_value(self) -> V {
+        self.0
+    }
+}
+
+macro_rules! impl_into_bind_value {
+    ($($t:ty),*) => {
+        $(
+            impl IntoBindValue for $t {
+                type Value = $t;
+
+                fn into_bind_value(self) -> $t {
+                    self
+                }
+            }
+
+            impl IntoBindValue for Option<$t> {
+                type Value = Option<$t>;
+
+                fn into_bind_value(self) -> Option<$t> {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_into_bind_value!(bool, i16, i32, i64, f32, f64, String, Vec<u8>);
+
+impl<T: IntoBindValue + Clone> IntoBindValue for &T {
+    type Value = T::Value;
+
+    fn into_bind_value(self) -> T::Value {
+        self.clone().into_bind_value()
+    }
+}
+
+impl IntoBindValue for &str {
+    type Value = String;
+
+    fn into_bind_value(self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoBindValue for &[u8] {
+    type Value = Vec<u8>;
+
+    fn into_bind_value(self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+impl IntoBindValue for Option<&str> {
+    type Value = Option<String>;
+
+    fn into_bind_value(self) -> Option<String> {
+        self.map(str::to_string)
+    }
+}
+
+impl IntoBindValue for Option<&[u8]> {
+    type Value = Option<Vec<u8>>;
+
+    fn into_bind_value(self) -> Option<Vec<u8>> {
+        self.map(<[u8]>::to_vec)
+    }
+}
+
+/// What `QueryBuilder::filter` accepts as its value: one value, a list, or none.
+///
+/// Every [`IntoBindValue`] is a single value. `Vec<T>`, arrays and slices of
+/// them are lists, for [`Op::In`](crate::Op::In) and [`Op::NotIn`](crate::Op::NotIn);
+/// `Vec<u8>` and `&[u8]` stay single byte-string values. `()` is no value, for
+/// [`Op::IsNull`](crate::Op::IsNull) and [`Op::IsNotNull`](crate::Op::IsNotNull).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// db.model::<User>()
+///     .filter("age", Op::Gte, 18)
+///     .filter("role", Op::In, ["admin", "editor"])
+///     .filter("deleted_at", Op::IsNull, ())
+///     .scan()
+///     .await?;
+/// ```
+pub trait FilterValue {
+    /// The owned type of each bound value
+    type Value: 'static + for<'q> Encode<'q, Any> + Type<Any> + Send + Sync + Clone;
+
+    /// Converts into the values to bind, in order.
+    fn into_filter_values(self) -> Vec<Self::Value>;
+}
+
+impl<T: IntoBindValue> FilterValue for T {
+    type Value = T::Value;
+
+    fn into_filter_values(self) -> Vec<T::Value> {
+        vec![self.into_bind_value()]
+    }
+}
+
+impl<T: IntoBindValue> FilterValue for Vec<T> {
+    type Value = T::Value;
+
+    fn into_filter_values(self) -> Vec<T::Value> {
+        self.into_iter().map(IntoBindValue::into_bind_value).collect()
+    }
+}
+
+impl<T: IntoBindValue, const N: usize> FilterValue for [T; N] {
+    type Value = T::Value;
+
+    fn into_filter_values(self) -> Vec<T::Value> {
+        self.into_iter().map(IntoBindValue::into_bind_value).collect()
+    }
+}
+
+impl<T: IntoBindValue + Clone> FilterValue for &[T] {
+    type Value = T::Value;
+
+    fn into_filter_values(self) -> Vec<T::Value> {
+        self.iter().cloned().map(IntoBindValue::into_bind_value).collect()
+    }
+}
+
+impl FilterValue for () {
+    type Value = bool;
+
+    fn into_filter_values(self) -> Vec<bool> {
+        Vec::new()
+    }
+}
+
 // ============================================================================
 // Value Binding Trait
 // ============================================================================
@@ -129,13 +309,27 @@ impl ValueBinder for AnyArguments<'_> {
             // ================================================================
             // Floating-Point Types
             // ================================================================
-            "DOUBLE PRECISION" | "FLOAT" | "float8" | "NUMERIC" | "DECIMAL" => {
+            "DOUBLE PRECISION" | "FLOAT" | "float8" => {
                 let val: f64 =
                     value_str.parse().map_err(|e| Error::Conversion(format!("Failed to parse f64: {}", e)))?;
                 self.bind_f64(val);
                 Ok(())
             }
 
+            // ================================================================
+            // Exact Numeric Types
+            // ================================================================
+            // Bound as text so no precision is lost going through f64; the
+            // database converts the literal into its exact NUMERIC/DECIMAL.
+            "NUMERIC" | "DECIMAL" | "numeric" | "decimal" => {
+                value_str
+                    .trim()
+                    .parse::<f64>()
+                    .map_err(|e| Error::Conversion(format!("Failed to parse decimal: {}", e)))?;
+                self.bind_string(value_str.trim().to_string());
+                Ok(())
+            }
+
             "REAL" | "float4" => {
                 let val: f32 =
                     value_str.parse().map_err(|e| Error::Conversion(format!("Failed to parse f32: {}", e)))?;
@@ -227,7 +421,7 @@ impl ValueBinder for AnyArguments<'_> {
             // ================================================================
             // Text and Default Types
             // ================================================================
-            "TEXT" | "VARCHAR" | "CHAR" | "STRING" | _ => {
+            _ => {
                 self.bind_string(value_str.to_string());
                 Ok(())
             }
@@ -345,7 +539,7 @@ pub fn bind_typed_value(
 /// * `sql_type` - SQL type identifier
 /// * `driver` - Database driver
 pub fn bind_typed_value_or_string(args: &mut AnyArguments<'_>, value_str: &str, sql_type: &str, driver: &Drivers) {
-    if let Err(_) = args.bind_value(value_str, sql_type, driver) {
+    if args.bind_value(value_str, sql_type, driver).is_err() {
         // Fallback: bind as string
         let _ = args.add(value_str.to_string());
     }
@@ -0,0 +1,40 @@
+use bottle_orm::Model;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::time::Duration;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Session {
+    #[orm(primary_key)]
+    id: i32,
+    last_seen: DateTime<Utc>,
+}
+
+#[tokio::test]
+async fn test_older_than_and_newer_than() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+    db.migrator().register::<Session>().run().await?;
+
+    let now = Utc::now();
+    db.model::<Session>()
+        .batch_insert(&[
+            Session { id: 1, last_seen: now - ChronoDuration::minutes(90) },
+            Session { id: 2, last_seen: now - ChronoDuration::minutes(45) },
+            Session { id: 3, last_seen: now - ChronoDuration::minutes(5) },
+        ])
+        .await?;
+
+    let stale: Vec<Session> =
+        db.model::<Session>().older_than("last_seen", Duration::from_secs(30 * 60)).order("id ASC").scan().await?;
+    assert_eq!(stale.iter().map(|s| s.id).collect::<Vec<_>>(), vec![1, 2]);
+
+    let very_stale = db.model::<Session>().older_than("last_seen", Duration::from_secs(60 * 60)).count().await?;
+    assert_eq!(very_stale, 1);
+
+    let recent: Vec<Session> = db.model::<Session>().newer_than("last_seen", Duration::from_secs(10 * 60)).scan().await?;
+    assert_eq!(recent.len(), 1);
+    assert_eq!(recent[0].id, 3);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Reading {
+    #[orm(primary_key)]
+    id: i32,
+    sensor: String,
+    value: i64,
+}
+
+#[tokio::test]
+async fn test_aggregates_respect_filters_and_empty_sets() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Reading>().run().await?;
+
+    for (id, sensor, value) in [(1, "a", 4), (2, "a", 6), (3, "b", 10), (4, "b", 1)] {
+        db.model::<Reading>().insert(&Reading { id, sensor: sensor.to_string(), value }).await?;
+    }
+
+    let total: i64 = db.model::<Reading>().filter("sensor", Op::Eq, "a".to_string()).sum("value").await?;
+    assert_eq!(total, 10);
+    let avg: f64 = db.model::<Reading>().filter("sensor", Op::Eq, "a".to_string()).avg("value").await?;
+    assert_eq!(avg, 5.0);
+    let min: i64 = db.model::<Reading>().filter("sensor", Op::Eq, "b".to_string()).min("reading.value").await?;
+    assert_eq!(min, 1);
+    let max: i64 = db.model::<Reading>().max("value").await?;
+    assert_eq!(max, 10);
+
+    // Grouped: the aggregate of the first group
+    let first_group: i64 = db.model::<Reading>().group_by("sensor").order("sensor DESC").sum("value").await?;
+    assert_eq!(first_group, 11);
+
+    // No matching rows: NULL comes back as None instead of an error
+    let none = db.model::<Reading>().filter("sensor", Op::Eq, "z".to_string());
+    assert_eq!(none.avg::<Option<f64>>("value").await?, None);
+    let none = db.model::<Reading>().filter("sensor", Op::Eq, "z".to_string());
+    assert_eq!(none.sum::<Option<i64>>("value").await?, None);
+    let none = db.model::<Reading>().filter("sensor", Op::Eq, "z".to_string());
+    assert_eq!(none.max::<Option<i64>>("value").await?, None);
+
+    let err = db.model::<Reading>().sum::<i64>("value) FROM reading; --").await.unwrap_err();
+    assert!(err.to_string().contains("unknown column"), "unexpected error: {}", err);
+    assert!(db.model::<Reading>().max::<i64>("other.value").await.is_err());
+    Ok(())
+}
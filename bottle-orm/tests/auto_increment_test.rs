@@ -0,0 +1,47 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Note {
+    #[orm(primary_key, auto_increment)]
+    id: i32,
+    body: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Tick {
+    #[orm(primary_key, auto_increment)]
+    id: i64,
+}
+
+#[tokio::test]
+async fn test_auto_increment_primary_key_is_generated() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+    db.migrator().register::<Note>().register::<Tick>().run().await?;
+
+    assert!(Note::columns()[0].auto_increment);
+
+    let first = db.model::<Note>().insert_get_id(&Note { id: 0, body: "first".to_string() }).await?;
+    let second = db.model::<Note>().insert_get_id(&Note { id: 0, body: "second".to_string() }).await?;
+    assert_eq!(first, 1);
+    assert_eq!(second, 2);
+
+    // Plain insert omits the unset key as well.
+    db.model::<Note>().insert(&Note { id: 0, body: "third".to_string() }).await?;
+    // An explicit key is still honoured.
+    db.model::<Note>().insert(&Note { id: 10, body: "tenth".to_string() }).await?;
+
+    let notes: Vec<Note> = db.model::<Note>().order("id ASC").scan().await?;
+    assert_eq!(notes.iter().map(|n| n.id).collect::<Vec<_>>(), vec![1, 2, 3, 10]);
+
+    let second_note: Note = db.model::<Note>().filter("id", Op::Eq, second as i32).first().await?;
+    assert_eq!(second_note.body, "second");
+
+    // A model made only of a generated key falls back to DEFAULT VALUES.
+    let tick = db.model::<Tick>().insert_get_id(&Tick { id: 0 }).await?;
+    assert_eq!(tick, 1);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use bottle_orm::{Database, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Reading {
+    #[orm(primary_key)]
+    id: i32,
+    sensor: String,
+    value: f64,
+    samples: i64,
+    note: Option<String>,
+}
+
+fn readings(ids: std::ops::RangeInclusive<i32>) -> Vec<Reading> {
+    ids.map(|id| Reading { id, sensor: format!("s{}", id % 7), value: id as f64 / 2.0, samples: i64::from(id) * 3, note: None })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_batch_insert_splits_past_parameter_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).count_queries(true).connect("sqlite::memory:").await?;
+    db.migrator().register::<Reading>().run().await?;
+
+    // 1,000 rows x 5 columns = 5,000 parameters, far past SQLite's 999.
+    let rows = readings(1..=1000);
+    let before = db.query_count();
+    db.model::<Reading>().batch_insert(&rows).await?;
+    assert!(db.query_count() - before > 1, "expected the batch to be split");
+    assert_eq!(db.model::<Reading>().count().await?, 1000);
+
+    let last: Reading = db.model::<Reading>().equals("id", 1000).first().await?;
+    assert_eq!(last, rows[999]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chunked_batch_insert_is_atomic() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Reading>().run().await?;
+
+    // The duplicate id lands in the last chunk; earlier chunks must not persist.
+    let mut rows = readings(1..=600);
+    rows.push(rows[0].clone());
+    assert!(db.model::<Reading>().batch_insert(&rows).await.is_err());
+    assert_eq!(db.model::<Reading>().count().await?, 0);
+
+    // Inside a transaction the chunks join it instead of opening another.
+    let tx = db.begin().await?;
+    tx.model::<Reading>().batch_insert(&readings(1..=600)).await?;
+    tx.commit().await?;
+    assert_eq!(db.model::<Reading>().count().await?, 600);
+    Ok(())
+}
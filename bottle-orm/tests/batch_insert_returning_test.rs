@@ -0,0 +1,89 @@
+use bottle_orm::{FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Thread {
+    #[orm(primary_key, auto_increment)]
+    id: i32,
+    title: String,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct ThreadId {
+    id: i32,
+}
+
+fn thread(title: &str) -> Thread {
+    Thread { id: 0, title: title.to_string() }
+}
+
+#[tokio::test]
+async fn test_batch_insert_returning_generated_ids_in_order() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Thread>().run().await?;
+    db.model::<Thread>().insert(&thread("existing")).await?;
+
+    let saved: Vec<Thread> =
+        db.model::<Thread>().batch_insert_returning(&[thread("a"), thread("b"), thread("c")]).await?;
+    assert_eq!(saved.iter().map(|t| (t.id, t.title.as_str())).collect::<Vec<_>>(), [(2, "a"), (3, "b"), (4, "c")]);
+
+    let ids: Vec<ThreadId> = db.model::<Thread>().batch_insert_returning(&[thread("d"), thread("e")]).await?;
+    assert_eq!(ids.iter().map(|t| t.id).collect::<Vec<_>>(), [5, 6]);
+    assert_eq!(db.model::<Thread>().count().await?, 6);
+
+    let none: Vec<Thread> = db.model::<Thread>().batch_insert_returning(&[]).await?;
+    assert!(none.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_insert_returning_across_chunks() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Thread>().run().await?;
+
+    // SQLite allows 999 parameters per statement, so this spans several statements
+    let threads: Vec<Thread> = (0..1000).map(|i| thread(&format!("t{}", i))).collect();
+    let saved: Vec<ThreadId> = db.model::<Thread>().batch_insert_returning(&threads).await?;
+    assert_eq!(saved.len(), 1000);
+    assert!(saved.iter().enumerate().all(|(i, t)| t.id == i as i32 + 1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_batch_insert_returning_reads_the_key_range() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::mysql().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS thread").execute().await?;
+    db.raw("CREATE TABLE thread (id INT AUTO_INCREMENT PRIMARY KEY, title VARCHAR(64) NOT NULL)").execute().await?;
+    db.model::<Thread>().insert(&thread("existing")).await?;
+
+    let saved: Vec<Thread> =
+        db.model::<Thread>().batch_insert_returning(&[thread("a"), thread("b"), thread("c")]).await?;
+    assert_eq!(saved.iter().map(|t| (t.id, t.title.as_str())).collect::<Vec<_>>(), [(2, "a"), (3, "b"), (4, "c")]);
+
+    // Without a generated key there is no range to read back
+    let err = db.model::<Thread>().batch_insert_returning::<Thread>(&[Thread { id: 9, title: "x".into() }]).await.unwrap_err();
+    assert!(err.to_string().contains("auto-increment primary key left unset"), "unexpected error: {}", err);
+
+    db.raw("DROP TABLE thread").execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_batch_insert_returning_with_alias() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS thread").execute().await?;
+    db.migrator().register::<Thread>().run().await?;
+
+    let saved: Vec<Thread> = db.model::<Thread>().alias("t").batch_insert_returning(&[thread("a"), thread("b")]).await?;
+    assert_eq!(saved.iter().map(|t| t.title.as_str()).collect::<Vec<_>>(), ["a", "b"]);
+
+    db.raw("DROP TABLE thread").execute().await?;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+const READ: i64 = 0b001;
+const WRITE: i64 = 0b010;
+const ADMIN: i64 = 0b100;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    permissions: i64,
+}
+
+#[tokio::test]
+async fn test_filter_bitand_selects_rows_with_permission_bit() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+
+    let members = vec![
+        Member { id: 1, name: "reader".to_string(), permissions: READ },
+        Member { id: 2, name: "writer".to_string(), permissions: READ | WRITE },
+        Member { id: 3, name: "admin".to_string(), permissions: READ | WRITE | ADMIN },
+    ];
+    db.model::<Member>().batch_insert(&members).await?;
+
+    let writers: Vec<Member> =
+        db.model::<Member>().filter_bitand("permissions", WRITE, Op::Eq, WRITE).order("id ASC").scan().await?;
+    assert_eq!(writers.iter().map(|m| m.id).collect::<Vec<_>>(), vec![2, 3]);
+
+    let non_admins: Vec<Member> = db
+        .model::<Member>()
+        .filter_bitand("permissions", ADMIN, Op::Eq, 0i64)
+        .filter("id", Op::Gt, 1)
+        .scan()
+        .await?;
+    assert_eq!(non_admins.len(), 1);
+    assert_eq!(non_admins[0].name, "writer");
+
+    let count = db.model::<Member>().filter_bitand("permissions", WRITE | ADMIN, Op::Eq, WRITE | ADMIN).count().await?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use bottle_orm::{Error, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Note {
+    #[orm(primary_key)]
+    id: i32,
+    body: String,
+}
+
+#[tokio::test]
+async fn test_operations_fail_after_close() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Note>().run().await?;
+    db.model::<Note>().insert(&Note { id: 1, body: "hello".to_string() }).await?;
+
+    let clone = db.clone();
+    db.close().await;
+    assert!(db.is_closed());
+    assert!(clone.is_closed());
+
+    let scanned: Result<Vec<Note>, _> = clone.model::<Note>().scan().await;
+    assert!(matches!(scanned, Err(sqlx::Error::PoolClosed)), "got {:?}", scanned);
+    assert!(matches!(db.raw("SELECT 1").execute().await, Err(Error::DatabaseError(sqlx::Error::PoolClosed))));
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use bottle_orm::{ColumnInfo, Model};
+use std::collections::HashMap;
+
+mod common;
+
+struct Invoice {
+    id: i32,
+    number: String,
+    memo: Option<String>,
+}
+
+impl Model for Invoice {
+    fn table_name() -> &'static str {
+        "invoice"
+    }
+
+    fn columns() -> Vec<ColumnInfo> {
+        vec![
+            ColumnInfo::builder("id", "INTEGER").primary_key().build(),
+            ColumnInfo::builder("number", "TEXT").unique().index().build(),
+            ColumnInfo::builder("memo", "TEXT").nullable().default("none").build(),
+        ]
+    }
+
+    fn column_names() -> Vec<String> {
+        vec!["id".to_string(), "number".to_string(), "memo".to_string()]
+    }
+
+    fn active_columns() -> Vec<&'static str> {
+        vec!["id", "number", "memo"]
+    }
+
+    fn to_map(&self) -> HashMap<String, Option<String>> {
+        let mut map = HashMap::new();
+        map.insert("id".to_string(), Some(self.id.to_string()));
+        map.insert("number".to_string(), Some(self.number.clone()));
+        map.insert("memo".to_string(), self.memo.clone());
+        map
+    }
+}
+
+#[test]
+fn test_builder_sets_only_requested_attributes() {
+    let col = ColumnInfo::builder("author_id", "INTEGER")
+        .foreign_key("user", "id")
+        .on_delete("cascade")
+        .index()
+        .build();
+    assert_eq!(col.name, "author_id");
+    assert_eq!(col.sql_type, "INTEGER");
+    assert_eq!(col.foreign_table, Some("user"));
+    assert_eq!(col.foreign_key, Some("id"));
+    assert_eq!(col.on_delete, Some("cascade"));
+    assert!(col.index);
+    assert!(!col.is_primary_key && !col.is_nullable && !col.unique && !col.omit && !col.soft_delete);
+    assert_eq!(col.on_update, None);
+    assert_eq!(col.default, None);
+
+    let pk = ColumnInfo::builder("id", "BIGINT").primary_key().auto_increment().build();
+    assert!(pk.is_primary_key && pk.auto_increment);
+}
+
+#[tokio::test]
+async fn test_builder_columns_drive_migrations() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.create_table::<Invoice>().await?;
+
+    assert_eq!(db.get_table_columns("invoice").await?.len(), 3);
+    db.raw("INSERT INTO invoice (id, number) VALUES (1, 'A-1')").execute().await?;
+    let (memo,): (String,) = db.raw("SELECT memo FROM invoice WHERE id = 1").fetch_one().await?;
+    assert_eq!(memo, "none");
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use bottle_orm::{FromAnyRow, Model, Op};
+use chrono::{DateTime, TimeZone, Utc};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Event {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(column = "evt_title", size = 80)]
+    title: String,
+    #[orm(column = "created_ts")]
+    created_at: DateTime<Utc>,
+    #[orm(column = "notes_txt")]
+    notes: Option<String>,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct EventSummary {
+    #[orm(column = "evt_title")]
+    headline: String,
+}
+
+#[tokio::test]
+async fn test_renamed_columns_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+
+    db.migrator().register::<Event>().run().await?;
+
+    let columns = db.get_table_columns("event").await?;
+    assert!(columns.contains(&"evt_title".to_string()));
+    assert!(columns.contains(&"created_ts".to_string()));
+    assert!(!columns.contains(&"created_at".to_string()));
+
+    let title_col = Event::columns().into_iter().find(|c| c.name == "evt_title").unwrap();
+    assert_eq!(title_col.sql_type, "VARCHAR(80)");
+    assert_eq!(event_fields::CREATED_AT, "created_ts");
+
+    let created_at = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    let event = Event { id: 1, title: "launch".to_string(), created_at, notes: None };
+    assert!(event.to_map().contains_key("created_ts"));
+
+    db.model::<Event>().insert(&event).await?;
+    db.model::<Event>()
+        .batch_insert(&[Event { id: 2, title: "retro".to_string(), created_at, notes: Some("late".to_string()) }])
+        .await?;
+
+    let events: Vec<Event> = db.model::<Event>().order("id ASC").scan().await?;
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0], event);
+    assert_eq!(events[1].notes.as_deref(), Some("late"));
+
+    let found: Event = db.model::<Event>().filter(event_fields::TITLE, Op::Eq, "retro".to_string()).first().await?;
+    assert_eq!(found.id, 2);
+
+    let summaries: Vec<EventSummary> = db.model::<Event>().order("id ASC").scan_as::<EventSummary>().await?;
+    assert_eq!(summaries[0].headline, "launch");
+    assert_eq!(summaries[1].headline, "retro");
+
+    Ok(())
+}
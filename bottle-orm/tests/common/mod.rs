@@ -0,0 +1,35 @@
+//! Fixtures shared by the integration tests.
+//!
+//! Tests against PostgreSQL or MySQL run only when `POSTGRES_URL` or `MYSQL_URL`
+//! is set, e.g. `POSTGRES_URL=postgres://localhost/bottle_test cargo test`; without
+//! it the helpers return `None` and the test passes without doing anything.
+
+#![allow(dead_code)]
+
+use bottle_orm::{Database, DatabaseBuilder, Error};
+
+/// Opens a fresh in-memory SQLite database.
+///
+/// The pool holds a single connection, since every SQLite connection would
+/// otherwise get its own empty in-memory database.
+pub async fn sqlite() -> Result<Database, Error> {
+    Database::builder().max_connections(1).connect("sqlite::memory:").await
+}
+
+/// Connects to the PostgreSQL server named by `POSTGRES_URL`, if it is set.
+pub async fn postgres() -> Result<Option<Database>, Error> {
+    from_env("POSTGRES_URL", Database::builder().max_connections(1)).await
+}
+
+/// Connects to the MySQL server named by `MYSQL_URL`, if it is set.
+pub async fn mysql() -> Result<Option<Database>, Error> {
+    from_env("MYSQL_URL", Database::builder().max_connections(1)).await
+}
+
+/// Connects `builder` to the URL in the environment variable `var`, if it is set.
+pub async fn from_env(var: &str, builder: DatabaseBuilder) -> Result<Option<Database>, Error> {
+    match std::env::var(var) {
+        Ok(url) => Ok(Some(builder.connect(&url).await?)),
+        Err(_) => Ok(None),
+    }
+}
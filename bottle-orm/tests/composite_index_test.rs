@@ -0,0 +1,82 @@
+use bottle_orm::{IndexInfo, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(index(columns = "user_id, created_at"))]
+#[orm(table = "event", index(columns = "user_id, kind", unique))]
+struct Event {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    kind: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "event")]
+struct EventV1 {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    kind: String,
+    created_at: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(index(columns = "kind, stamp, user_id"))]
+struct AuditEntry {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    #[orm(column = "entry_kind")]
+    kind: String,
+    #[orm(column = "createdAt")]
+    stamp: String,
+}
+
+#[tokio::test]
+async fn test_composite_index_columns_use_column_names() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(AuditEntry::indexes(), vec![IndexInfo { columns: vec!["entry_kind", "created_at", "user_id"], unique: false }]);
+
+    let db = common::sqlite().await?;
+    db.migrator().register::<AuditEntry>().run().await?;
+    assert!(db.diff_table::<AuditEntry>().await?.is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_composite_indexes_are_created() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(
+        Event::indexes(),
+        vec![
+            IndexInfo { columns: vec!["user_id", "created_at"], unique: false },
+            IndexInfo { columns: vec!["user_id", "kind"], unique: true },
+        ]
+    );
+
+    let db = common::sqlite().await?;
+    db.migrator().register::<Event>().run().await?;
+
+    let indexes = db.get_table_indexes("event").await?;
+    assert!(indexes.contains(&"idx_event_user_id_created_at".to_string()), "{:?}", indexes);
+    assert!(indexes.contains(&"unique_event_user_id_kind".to_string()), "{:?}", indexes);
+
+    db.raw("INSERT INTO event (id, user_id, kind, created_at) VALUES (1, 7, 'login', 'now')").execute().await?;
+    assert!(db.raw("INSERT INTO event (id, user_id, kind, created_at) VALUES (2, 7, 'login', 'later')").execute().await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_table_adds_composite_indexes() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.sync_table::<EventV1>().await?;
+    assert!(db.get_table_indexes("event").await?.is_empty());
+
+    db.sync_table::<Event>().await?;
+    let indexes = db.get_table_indexes("event").await?;
+    assert!(indexes.contains(&"idx_event_user_id_created_at".to_string()), "{:?}", indexes);
+    assert!(indexes.contains(&"unique_event_user_id_kind".to_string()), "{:?}", indexes);
+    assert!(db.sync_table_sql::<Event>().await?.is_empty());
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use bottle_orm::{Database, Model};
 use uuid::Uuid;
 
+mod common;
+
 #[derive(Debug, Clone, Model, PartialEq)]
 struct RolePermission {
     #[orm(primary_key)]
@@ -11,7 +13,7 @@ struct RolePermission {
 
 #[tokio::test]
 async fn test_composite_primary_key_migration() -> Result<(), Box<dyn std::error::Error>> {
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
 
     // This should fail if the bug is present
     let result = db.migrator().register::<RolePermission>().run().await;
@@ -27,3 +29,45 @@ async fn test_composite_primary_key_migration() -> Result<(), Box<dyn std::error
         }
     }
 }
+
+/// Inserts two rows sharing one key column, then a duplicate pair that must fail.
+async fn assert_rejects_duplicate_pair(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    db.raw("DROP TABLE IF EXISTS role_permission").execute().await?;
+    db.migrator().register::<RolePermission>().run().await?;
+
+    let role = Uuid::new_v4();
+    let read = Uuid::new_v4();
+    let write = Uuid::new_v4();
+
+    db.model::<RolePermission>().insert(&RolePermission { role_id: role, permission_id: read }).await?;
+    // Sharing one half of the key is fine.
+    db.model::<RolePermission>().insert(&RolePermission { role_id: role, permission_id: write }).await?;
+
+    let duplicate = db.model::<RolePermission>().insert(&RolePermission { role_id: role, permission_id: read }).await;
+    assert!(duplicate.is_err(), "duplicate composite key must violate the table-level PRIMARY KEY");
+
+    assert_eq!(db.model::<RolePermission>().count().await?, 2);
+    db.raw("DROP TABLE role_permission").execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_composite_primary_key_rejects_duplicate_pair() -> Result<(), Box<dyn std::error::Error>> {
+    assert_rejects_duplicate_pair(&common::sqlite().await?).await
+}
+
+#[tokio::test]
+async fn test_postgres_composite_primary_key_rejects_duplicate_pair() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    assert_rejects_duplicate_pair(&db).await
+}
+
+#[tokio::test]
+async fn test_mysql_composite_primary_key_rejects_duplicate_pair() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::mysql().await? else {
+        return Ok(());
+    };
+    assert_rejects_duplicate_pair(&db).await
+}
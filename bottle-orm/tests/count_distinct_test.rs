@@ -0,0 +1,50 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Comment {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    post_id: i32,
+}
+
+#[tokio::test]
+async fn test_count_distinct_ignores_duplicates() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Comment>().run().await?;
+    // (user_id, post_id): user 1 comments three times, user 2 twice, user 3 once.
+    let pairs = [(1, 10), (1, 10), (1, 11), (2, 10), (2, 12), (3, 11)];
+    let comments: Vec<Comment> =
+        pairs.iter().zip(1..).map(|(&(user_id, post_id), id)| Comment { id, user_id, post_id }).collect();
+    db.model::<Comment>().batch_insert(&comments).await?;
+
+    assert_eq!(db.model::<Comment>().count().await?, 6);
+    assert_eq!(db.model::<Comment>().count_distinct("user_id").await?, 3);
+    assert_eq!(db.model::<Comment>().filter("post_id", Op::Eq, 10).count_distinct("user_id").await?, 2);
+    assert_eq!(db.model::<Comment>().count_distinct("comment.post_id").await?, 3);
+
+    // Grouped queries count over the grouped rows: users with more than one comment.
+    let repeat = db
+        .model::<Comment>()
+        .select("user_id, COUNT(*) AS total")
+        .group_by("user_id")
+        .having("COUNT(*)", Op::Gt, 1)
+        .count_distinct("user_id")
+        .await?;
+    assert_eq!(repeat, 2);
+
+    // Values bound by a join come before the filter's in the statement.
+    let joined = db
+        .model::<Comment>()
+        .join_raw("comment c2", "c2.id = comment.id AND c2.post_id = ?", 10)
+        .filter("user_id", Op::Gt, 1)
+        .count_distinct("user_id")
+        .await?;
+    assert_eq!(joined, 1);
+
+    let err = db.model::<Comment>().count_distinct("user_id) FROM comment; --").await.unwrap_err();
+    assert!(err.to_string().contains("unknown column"), "unexpected error: {}", err);
+    Ok(())
+}
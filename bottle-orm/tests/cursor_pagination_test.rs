@@ -0,0 +1,83 @@
+use bottle_orm::{CursorPage, CursorPagination, Database, FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Entry {
+    #[orm(primary_key)]
+    id: i32,
+    label: String,
+}
+
+#[derive(Debug, FromAnyRow)]
+struct EntryLabel {
+    label: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Entry>().run().await?;
+    // Inserted out of key order, so only the cursor column decides the walk.
+    for i in (1..=50).rev() {
+        db.model::<Entry>().insert(&Entry { id: i, label: format!("entry {}", i) }).await?;
+    }
+    Ok(db)
+}
+
+async fn walk(db: &Database, pagination: CursorPagination) -> Result<(Vec<i32>, usize), Box<dyn std::error::Error>> {
+    let mut ids = Vec::new();
+    let mut pages = 0;
+    let mut cursor = None;
+    loop {
+        let page: CursorPage<Entry> = pagination.clone().after(cursor).paginate(db.model::<Entry>()).await?;
+        pages += 1;
+        ids.extend(page.data.iter().map(|e| e.id));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok((ids, pages))
+}
+
+#[tokio::test]
+async fn test_cursor_walk_has_no_gaps_or_overlaps() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let (ids, pages) = walk(&db, CursorPagination::new("id", 7)).await?;
+    assert_eq!(ids, (1..=50).collect::<Vec<_>>());
+    assert_eq!(pages, 8);
+
+    // An exact multiple of the page size does not produce a trailing empty page.
+    let (ids, pages) = walk(&db, CursorPagination::new("id", 10)).await?;
+    assert_eq!(ids.len(), 50);
+    assert_eq!(pages, 5);
+
+    let (ids, _) = walk(&db, CursorPagination::new("id", 9).descending()).await?;
+    assert_eq!(ids, (1..=50).rev().collect::<Vec<_>>());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cursor_apply_and_unknown_column() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let rows: Vec<Entry> = CursorPagination::new("id", 3).after(Some("10".to_string())).apply(db.model::<Entry>()).scan().await?;
+    assert_eq!(rows.iter().map(|e| e.id).collect::<Vec<_>>(), [11, 12, 13]);
+
+    let err = CursorPagination::new("nope", 3).paginate::<Entry, _, Entry>(db.model::<Entry>()).await.unwrap_err();
+    assert!(err.to_string().contains("unknown column 'nope'"), "unexpected error: {}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cursor_column_missing_from_rows_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = CursorPagination::new("id", 3)
+        .paginate::<Entry, _, EntryLabel>(db.model::<Entry>().select("label"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Cursor column 'id' is not part"), "unexpected error: {}", err);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "tbl_AppUsers")]
+struct AppUser {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(index)]
+    username: String,
+    age: Option<i32>,
+}
+
+#[tokio::test]
+async fn test_custom_table_name_is_used_verbatim() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+
+    assert_eq!(AppUser::table_name(), "tbl_AppUsers");
+
+    db.migrator().register::<AppUser>().run().await?;
+    assert!(db.table_exists("tbl_AppUsers").await?);
+    assert!(!db.table_exists("tbl_app_users").await?);
+
+    // Running the migration again goes through sync_table on the existing table.
+    db.migrator().register::<AppUser>().run().await?;
+
+    db.model::<AppUser>().insert(&AppUser { id: 1, username: "alice".to_string(), age: Some(30) }).await?;
+    db.model::<AppUser>().insert(&AppUser { id: 2, username: "bob".to_string(), age: None }).await?;
+
+    let adults: Vec<AppUser> = db.model::<AppUser>().filter("age", Op::Gte, 18).scan().await?;
+    assert_eq!(adults.len(), 1);
+    assert_eq!(adults[0].username, "alice");
+
+    db.model::<AppUser>().filter("id", Op::Eq, 2).update("username", "bobby".to_string()).await?;
+    let bob: AppUser = db.model::<AppUser>().filter("id", Op::Eq, 2).first().await?;
+    assert_eq!(bob.username, "bobby");
+
+    let count = db.model::<AppUser>().count().await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct UserProfile {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(index)]
+    bio: String,
+}
+
+#[tokio::test]
+async fn test_introspection_falls_back_to_snake_case_names() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<UserProfile>().run().await?;
+
+    // Struct-style names still resolve to the snake_case table, as they used to.
+    assert!(db.table_exists("UserProfile").await?);
+    assert_eq!(db.get_table_columns("UserProfile").await?, db.get_table_columns("user_profile").await?);
+    assert_eq!(db.get_table_indexes("UserProfile").await?, db.get_table_indexes("user_profile").await?);
+    assert!(!db.get_table_indexes("UserProfile").await?.is_empty());
+    assert!(!db.table_exists("MissingTable").await?);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "decimal")]
+
+use bottle_orm::{Model, Op};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Product {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    price: Decimal,
+    discount: Option<Decimal>,
+}
+
+#[tokio::test]
+async fn test_decimal_roundtrip_sum_and_filter() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+
+    db.migrator().register::<Product>().run().await?;
+
+    let cols = Product::columns();
+    let price_col = cols.iter().find(|c| c.name == "price").unwrap();
+    assert_eq!(price_col.sql_type, "NUMERIC");
+
+    let products = vec![
+        Product { id: 1, name: "pen".to_string(), price: Decimal::from_str("0.10")?, discount: None },
+        Product { id: 2, name: "book".to_string(), price: Decimal::from_str("0.20")?, discount: Some(Decimal::from_str("0.05")?) },
+        Product { id: 3, name: "lamp".to_string(), price: Decimal::from_str("19.99")?, discount: None },
+    ];
+    for p in &products {
+        let map = p.to_map();
+        assert_eq!(map.get("price").cloned().flatten(), Some(p.price.to_string()));
+        db.model::<Product>().insert(p).await?;
+    }
+
+    let fetched: Vec<Product> = db.model::<Product>().order("id ASC").scan().await?;
+    assert_eq!(fetched[0].price, Decimal::from_str("0.10")?);
+    assert_eq!(fetched[1].discount, Some(Decimal::from_str("0.05")?));
+    assert_eq!(fetched[2].discount, None);
+
+    // 0.1 + 0.2 must be exactly 0.3, which an f64 column would not give back.
+    let cheap_total: Decimal =
+        fetched.iter().filter(|p| p.price < Decimal::ONE).map(|p| p.price).sum();
+    assert_eq!(cheap_total, Decimal::from_str("0.3")?);
+
+    let total: Decimal = db.model::<Product>().select("SUM(price)").scalar().await?;
+    assert_eq!(total, Decimal::from_str("20.29")?);
+
+    // SQLite sums in floating point; the REAL result is read back to 15 significant digits
+    let cheap_sum: Decimal = db.model::<Product>().select("SUM(price)").filter("price", Op::Lt, "1".to_string()).scalar().await?;
+    assert_eq!(cheap_sum.to_string(), "0.3");
+
+    let expensive: Vec<Product> = db.model::<Product>().filter("price", Op::Gt, "1.00".to_string()).scan().await?;
+    assert_eq!(expensive.len(), 1);
+    assert_eq!(expensive[0].name, "lamp");
+    assert_eq!(expensive[0].price, Decimal::from_str("19.99")?);
+
+    Ok(())
+}
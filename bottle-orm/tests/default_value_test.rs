@@ -0,0 +1,74 @@
+use bottle_orm::Model;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(default = "0")]
+    priority: i32,
+    #[orm(default = "pending")]
+    status: String,
+    #[orm(default = "it's new")]
+    note: String,
+    #[orm(default = "CURRENT_TIMESTAMP")]
+    opened_at: String,
+    #[orm(default = "lower('OPEN')")]
+    queue: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "ticket")]
+struct TicketV2 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(default = "0")]
+    priority: i32,
+    #[orm(default = "pending")]
+    status: String,
+    #[orm(default = "it's new")]
+    note: String,
+    #[orm(default = "CURRENT_TIMESTAMP")]
+    opened_at: String,
+    #[orm(default = "lower('OPEN')")]
+    queue: String,
+    #[orm(default = "support")]
+    team: String,
+    #[orm(default = "-1.5e3")]
+    budget: f64,
+    #[orm(default = "Infinity")]
+    mood: String,
+}
+
+#[tokio::test]
+async fn test_defaults_in_create_table_and_alter() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+
+    let status = Ticket::columns().into_iter().find(|c| c.name == "status").unwrap();
+    assert_eq!(status.default, Some("pending"));
+    assert_eq!(Ticket::columns()[0].default, None);
+
+    db.migrator().register::<Ticket>().run().await?;
+    db.raw("INSERT INTO ticket (id) VALUES (1)").execute().await?;
+
+    let row: (i64, String, String, String, String) =
+        db.raw("SELECT priority, status, note, opened_at, queue FROM ticket WHERE id = 1").fetch_one().await?;
+    assert_eq!(row.0, 0);
+    assert_eq!(row.1, "pending");
+    assert_eq!(row.2, "it's new");
+    assert!(!row.3.is_empty() && row.3 != "CURRENT_TIMESTAMP");
+    assert_eq!(row.4, "open");
+
+    // The ALTER TABLE ADD COLUMN path uses the declared default for existing rows.
+    db.sync_table::<TicketV2>().await?;
+    let team: (String,) = db.raw("SELECT team FROM ticket WHERE id = 1").fetch_one().await?;
+    assert_eq!(team.0, "support");
+
+    // Only plain numbers stay unquoted; words that parse as floats are strings.
+    let row: (f64, String) = db.raw("SELECT budget, mood FROM ticket WHERE id = 1").fetch_one().await?;
+    assert_eq!(row, (-1500.0, "Infinity".to_string()));
+
+    Ok(())
+}
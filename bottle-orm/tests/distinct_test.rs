@@ -0,0 +1,57 @@
+use bottle_orm::{Database, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Owner {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Pet {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Owner::id")]
+    owner_id: i32,
+    species: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Owner>().register::<Pet>().run().await?;
+
+    db.model::<Owner>().insert(&Owner { id: 1, name: "ana".to_string() }).await?;
+    db.model::<Owner>().insert(&Owner { id: 2, name: "bo".to_string() }).await?;
+    db.model::<Owner>().insert(&Owner { id: 3, name: "cy".to_string() }).await?;
+    for (id, owner_id, species) in [(1, 1, "cat"), (2, 1, "cat"), (3, 1, "dog"), (4, 2, "cat")] {
+        db.model::<Pet>().insert(&Pet { id, owner_id, species: species.to_string() }).await?;
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_distinct_removes_duplicate_parents_from_join() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let joined: Vec<Owner> = db.model::<Owner>().join("pet", "pet.owner_id = owner.id").scan().await?;
+    assert_eq!(joined.len(), 4);
+
+    let owners: Vec<Owner> =
+        db.model::<Owner>().join("pet", "pet.owner_id = owner.id").distinct().order("owner.id ASC").scan().await?;
+    assert_eq!(owners.iter().map(|o| o.id).collect::<Vec<_>>(), vec![1, 2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_distinct_on_is_postgres_only() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let sql = db.model::<Pet>().distinct_on(&["owner_id"]).order("owner_id, id DESC").to_sql();
+    assert!(sql.starts_with("SELECT DISTINCT ON (owner_id) "), "unexpected SQL: {}", sql);
+
+    let err = db.model::<Pet>().distinct_on(&["owner_id"]).scan::<Pet>().await.unwrap_err();
+    assert!(err.to_string().contains("requires PostgreSQL"), "unexpected error: {}", err);
+    Ok(())
+}
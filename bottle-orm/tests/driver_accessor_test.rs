@@ -0,0 +1,14 @@
+use bottle_orm::Drivers;
+
+mod common;
+
+#[tokio::test]
+async fn test_driver_is_public_on_database_and_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    assert_eq!(db.driver(), Drivers::SQLite);
+
+    let tx = db.begin().await?;
+    assert_eq!(tx.driver(), Drivers::SQLite);
+    tx.rollback().await?;
+    Ok(())
+}
@@ -0,0 +1,12 @@
+//! Runs only in builds without the `postgres` feature, e.g.
+//! `cargo test --no-default-features --features sqlite`.
+#![cfg(not(feature = "postgres"))]
+
+use bottle_orm::{database::Drivers, Database, Error};
+
+#[tokio::test]
+async fn test_connect_reports_missing_driver_feature() {
+    let err = Database::connect("postgres://localhost/app").await.unwrap_err();
+    assert!(matches!(err, Error::DriverNotCompiled { driver: Drivers::Postgres }), "unexpected error: {:?}", err);
+    assert!(err.to_string().contains("enable the `postgres` feature"), "unexpected message: {}", err);
+}
@@ -0,0 +1,29 @@
+use bottle_orm::Model;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct AuditLog {
+    #[orm(primary_key)]
+    id: i32,
+    message: String,
+}
+
+#[tokio::test]
+async fn test_truncate_then_drop_table() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.create_table::<AuditLog>().await?;
+    let logs: Vec<AuditLog> = (1..=3).map(|id| AuditLog { id, message: format!("event {}", id) }).collect();
+    db.model::<AuditLog>().batch_insert(&logs).await?;
+
+    db.truncate::<AuditLog>().await?;
+    assert_eq!(db.model::<AuditLog>().count().await?, 0);
+    assert!(db.table_exists("audit_log").await?);
+
+    db.drop_table::<AuditLog>().await?;
+    assert!(!db.table_exists("audit_log").await?);
+
+    // Dropping a missing table is not an error
+    db.drop_table::<AuditLog>().await?;
+    Ok(())
+}
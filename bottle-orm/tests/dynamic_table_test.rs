@@ -0,0 +1,94 @@
+use bottle_orm::{Model, Op};
+use serde_json::Value;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Gadget {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    price: f64,
+    note: Option<String>,
+}
+
+#[tokio::test]
+async fn test_query_table_by_name() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+    db.migrator().register::<Gadget>().run().await?;
+    db.model::<Gadget>()
+        .batch_insert(&[
+            Gadget { id: 1, name: "lamp".to_string(), price: 19.5, note: None },
+            Gadget { id: 2, name: "desk".to_string(), price: 120.0, note: Some("oak".to_string()) },
+            Gadget { id: 3, name: "chair".to_string(), price: 45.25, note: None },
+        ])
+        .await?;
+
+    // From here on only the table name is known.
+    let table = "gadget";
+
+    let rows = db.table(table).filter("price", Op::Gt, 20.0).order("id ASC").scan_dynamic().await?;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["id"], Value::from(2));
+    assert_eq!(rows[0]["name"], Value::from("desk"));
+    assert_eq!(rows[0]["price"], Value::from(120.0));
+    assert_eq!(rows[0]["note"], Value::from("oak"));
+    assert_eq!(rows[1]["note"], Value::Null);
+
+    let names = db.table(table).select("name").order("id DESC").limit(1).scan_dynamic().await?;
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[0].len(), 1);
+    assert_eq!(names[0]["name"], Value::from("chair"));
+
+    assert_eq!(db.table(table).count().await?, 3);
+    assert_eq!(db.table(table).filter("name", Op::Eq, "lamp".to_string()).delete().await?, 1);
+    assert_eq!(db.table(table).count().await?, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_table_name_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Gadget>().run().await?;
+    db.model::<Gadget>().insert(&Gadget { id: 1, name: "lamp".to_string(), price: 19.5, note: None }).await?;
+
+    let err = db.table("gadget; DROP TABLE gadget").scan_dynamic().await.unwrap_err();
+    assert!(err.to_string().contains("invalid table name"), "unexpected error: {}", err);
+
+    for name in ["gadget\"", "", "main..gadget", "gadget --"] {
+        assert!(db.table(name).count().await.is_err(), "{:?} should be rejected", name);
+        assert!(db.table(name).delete().await.is_err(), "{:?} should be rejected", name);
+    }
+
+    // Nothing reached the database and schema-qualified names still work.
+    assert_eq!(db.table("main.gadget").count().await?, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_columns_are_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Gadget>().run().await?;
+    db.model::<Gadget>().insert(&Gadget { id: 1, name: "lamp".to_string(), price: 19.5, note: None }).await?;
+
+    let err = db.table("gadget").select("name, (SELECT 1)").scan_dynamic().await.unwrap_err();
+    assert!(err.to_string().contains("unknown column"), "unexpected error: {}", err);
+    assert!(db.table("gadget").filter("1=1 OR name", Op::Eq, 1).delete().await.is_err());
+    assert!(db.table("gadget").filter("other.name", Op::Eq, 1).count().await.is_err());
+    assert!(db.table("gadget").order("id; DROP TABLE gadget").scan_dynamic().await.is_err());
+    assert!(db.table("gadget").order("price DESC NULLS").scan_dynamic().await.is_err());
+    assert!(db.table("gadget").order("missing").scan_dynamic().await.is_err());
+    assert_eq!(db.table("gadget").count().await?, 1);
+
+    // Known columns still work, qualified or not
+    let rows = db
+        .table("main.gadget")
+        .select("gadget.name, price")
+        .filter("gadget.id", Op::Eq, 1)
+        .order("price desc, id")
+        .scan_dynamic()
+        .await?;
+    assert_eq!(rows[0]["name"], Value::from("lamp"));
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use bottle_orm::{BottleEnum, FromAnyRow, Model};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+#[derive(BottleEnum, Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum TicketStatus {
+    Open,
+    Closed,
+}
+
+#[derive(Model, Debug, Clone, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(column = "status_code", enum)]
+    status: Option<TicketStatus>,
+    #[orm(enum, column = "prev_status", size = 20)]
+    previous: TicketStatus,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct TicketView {
+    id: i32,
+    #[orm(enum, column = "status_code")]
+    state: Option<TicketStatus>,
+}
+
+#[tokio::test]
+async fn test_enum_with_column_and_nullable_attributes() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Ticket>().run().await?;
+
+    let columns = db.get_table_columns("ticket").await?;
+    assert!(columns.contains(&"status_code".to_string()));
+    assert!(columns.contains(&"prev_status".to_string()));
+    assert!(!columns.contains(&"status".to_string()));
+
+    let status_col = Ticket::columns().into_iter().find(|c| c.name == "status_code").unwrap();
+    assert!(status_col.is_nullable);
+    let prev_col = Ticket::columns().into_iter().find(|c| c.name == "prev_status").unwrap();
+    assert!(!prev_col.is_nullable);
+    assert_eq!(prev_col.sql_type, "VARCHAR(20)");
+
+    let open = Ticket { id: 1, status: Some(TicketStatus::Open), previous: TicketStatus::Closed };
+    let unset = Ticket { id: 2, status: None, previous: TicketStatus::Open };
+    db.model::<Ticket>().insert(&open).await?;
+    db.model::<Ticket>().insert(&unset).await?;
+
+    let tickets: Vec<Ticket> = db.model::<Ticket>().order("id ASC").scan().await?;
+    assert_eq!(tickets, vec![open, unset]);
+
+    let views: Vec<TicketView> = db.model::<Ticket>().order("id ASC").scan_as().await?;
+    assert_eq!(views[0].id, 1);
+    assert_eq!(views[0].state, Some(TicketStatus::Open));
+    assert_eq!(views[1].state, None);
+    Ok(())
+}
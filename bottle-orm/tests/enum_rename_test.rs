@@ -0,0 +1,58 @@
+use bottle_orm::{BottleEnum, Model};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+#[derive(BottleEnum, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[bottle(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Role {
+    Admin,
+    PowerUser,
+    #[bottle(rename = "guest-user")]
+    Guest,
+}
+
+#[derive(BottleEnum, Debug, Clone, Copy, PartialEq)]
+#[bottle(rename_all = "kebab-case")]
+enum Plan {
+    FreeTier,
+    #[bottle(rename = "ADMIN")]
+    Admin,
+}
+
+#[derive(Model, Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct RoleHolder {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(enum)]
+    role: Role,
+}
+
+#[test]
+fn test_renamed_variants_round_trip() {
+    for role in [Role::Admin, Role::PowerUser, Role::Guest] {
+        assert_eq!(role.to_string().parse::<Role>(), Ok(role));
+    }
+    assert_eq!(Role::Admin.to_string(), "ADMIN");
+    assert_eq!(Role::PowerUser.to_string(), "POWER_USER");
+    assert_eq!(Role::Guest.to_string(), "guest-user");
+    assert_eq!("ADMIN".parse::<Role>(), Ok(Role::Admin));
+    assert!("admin".parse::<Role>().is_err());
+
+    assert_eq!(Plan::FreeTier.to_string(), "free-tier");
+    assert_eq!("ADMIN".parse::<Plan>(), Ok(Plan::Admin));
+}
+
+#[tokio::test]
+async fn test_renamed_variant_is_stored_and_loaded() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<RoleHolder>().run().await?;
+    db.model::<RoleHolder>().insert(&RoleHolder { id: 1, role: Role::Admin }).await?;
+
+    let (stored,): (String,) = db.raw("SELECT role FROM role_holder WHERE id = 1").fetch_one().await?;
+    assert_eq!(stored, "ADMIN");
+
+    let loaded: RoleHolder = db.model::<RoleHolder>().equals("id", 1).first().await?;
+    assert_eq!(loaded.role, Role::Admin);
+    Ok(())
+}
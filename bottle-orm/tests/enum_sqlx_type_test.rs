@@ -0,0 +1,37 @@
+use bottle_orm::{BottleEnum, Model, Op};
+
+mod common;
+
+#[derive(BottleEnum, Debug, Clone, Copy, PartialEq)]
+#[bottle(sqlx_type, rename_all = "SCREAMING_SNAKE_CASE")]
+enum Status {
+    Active,
+    Suspended,
+}
+
+#[derive(Model, Debug, Clone, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(enum)]
+    status: Status,
+}
+
+#[tokio::test]
+async fn test_enum_binds_without_stringifying() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+
+    db.raw("INSERT INTO member (id, status) VALUES (?, ?)").bind(1).bind(Status::Active).execute().await?;
+    db.raw("INSERT INTO member (id, status) VALUES (?, ?)").bind(2).bind(Status::Suspended).execute().await?;
+    db.model::<Member>().insert(&Member { id: 3, status: Status::Active }).await?;
+
+    let active: Vec<Member> = db.model::<Member>().filter("status", Op::Eq, Status::Active).order("id ASC").scan().await?;
+    assert_eq!(active.iter().map(|m| m.id).collect::<Vec<_>>(), [1, 3]);
+
+    let (stored,): (String,) = db.raw("SELECT status FROM member WHERE id = ?").bind(2).fetch_one().await?;
+    assert_eq!(stored, "SUSPENDED");
+    let (decoded,): (Status,) = db.raw("SELECT status FROM member WHERE id = ?").bind(2).fetch_one().await?;
+    assert_eq!(decoded, Status::Suspended);
+    Ok(())
+}
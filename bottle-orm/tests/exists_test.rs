@@ -0,0 +1,53 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Subscriber {
+    #[orm(primary_key)]
+    id: i32,
+    email: String,
+    plan: String,
+}
+
+#[tokio::test]
+async fn test_exists_reports_matching_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Subscriber>().run().await?;
+
+    assert!(!db.model::<Subscriber>().exists().await?);
+
+    db.model::<Subscriber>()
+        .insert(&Subscriber { id: 1, email: "a@example.com".to_string(), plan: "pro".to_string() })
+        .await?;
+    db.model::<Subscriber>()
+        .insert(&Subscriber { id: 2, email: "b@example.com".to_string(), plan: "free".to_string() })
+        .await?;
+
+    assert!(db.model::<Subscriber>().exists().await?);
+    assert!(
+        db.model::<Subscriber>()
+            .filter(subscriber_fields::EMAIL, Op::Eq, "b@example.com".to_string())
+            .exists()
+            .await?
+    );
+    assert!(
+        !db.model::<Subscriber>()
+            .filter(subscriber_fields::EMAIL, Op::Eq, "c@example.com".to_string())
+            .exists()
+            .await?
+    );
+
+    // Select list, ordering and pagination are ignored
+    assert!(
+        db.model::<Subscriber>()
+            .select("email")
+            .filter("plan", Op::Eq, "free".to_string())
+            .order("id DESC")
+            .limit(1)
+            .offset(5)
+            .exists()
+            .await?
+    );
+    Ok(())
+}
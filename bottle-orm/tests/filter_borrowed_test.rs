@@ -0,0 +1,46 @@
+use bottle_orm::{Bind, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    username: String,
+    score: Option<i64>,
+}
+
+#[tokio::test]
+async fn test_filter_accepts_borrowed_values() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+    let accounts = vec![
+        Account { id: 1, username: "alice".to_string(), score: Some(10) },
+        Account { id: 2, username: "bob".to_string(), score: None },
+    ];
+    db.model::<Account>().batch_insert(&accounts).await?;
+
+    let by_literal: Vec<Account> = db.model::<Account>().filter("username", Op::Eq, "alice").scan().await?;
+    assert_eq!(by_literal, [accounts[0].clone()]);
+
+    let wanted = "bob".to_string();
+    let by_ref: Vec<Account> = db.model::<Account>().filter("username", Op::Eq, &wanted).scan().await?;
+    assert_eq!(by_ref, [accounts[1].clone()]);
+    // `wanted` is still usable after the builder ran
+    assert_eq!(wanted, "bob");
+
+    let by_field = db.model::<Account>().equals("username", &accounts[0].username).count().await?;
+    assert_eq!(by_field, 1);
+
+    let min_score = 5_i64;
+    let scored = db.model::<Account>().filter("score", Op::Gte, min_score).or_filter("username", Op::Eq, "bob").count().await?;
+    assert_eq!(scored, 2);
+
+    let wrapped = db.model::<Account>().filter("id", Op::Eq, Bind(2_i32)).count().await?;
+    assert_eq!(wrapped, 1);
+
+    let maybe_name: Option<&'static str> = Some("alice");
+    let by_option = db.model::<Account>().filter("username", Op::Eq, maybe_name).count().await?;
+    assert_eq!(by_option, 1);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use bottle_orm::{Database, Model, Op};
+use chrono::{DateTime, Duration, Utc};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct ApiToken {
+    #[orm(primary_key)]
+    id: i32,
+    expires_at: DateTime<Utc>,
+}
+
+async fn assert_selects_unexpired(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    db.migrator().register::<ApiToken>().run().await?;
+    let now = Utc::now();
+    let tokens = vec![
+        ApiToken { id: 1, expires_at: now - Duration::hours(1) },
+        ApiToken { id: 2, expires_at: now + Duration::hours(1) },
+        ApiToken { id: 3, expires_at: now - Duration::seconds(5) },
+        ApiToken { id: 4, expires_at: now + Duration::days(30) },
+    ];
+    db.model::<ApiToken>().batch_insert(&tokens).await?;
+
+    let live: Vec<ApiToken> = db.model::<ApiToken>().filter_now("expires_at", Op::Gt).order("id ASC").scan().await?;
+    assert_eq!(live.iter().map(|t| t.id).collect::<Vec<_>>(), [2, 4]);
+
+    let expired = db.model::<ApiToken>().filter_now("expires_at", Op::Lte).count().await?;
+    assert_eq!(expired, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_now_uses_database_clock_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    assert_selects_unexpired(&db).await
+}
+
+#[tokio::test]
+async fn test_filter_now_uses_database_clock_postgres() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS \"api_token\"").execute().await?;
+    assert_selects_unexpired(&db).await?;
+    db.raw("DROP TABLE \"api_token\"").execute().await?;
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use bottle_orm::{Database, Drivers, Model, Op, QueryBuilder};
+use heck::ToSnakeCase;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    age: i32,
+    deleted_at: Option<String>,
+}
+
+fn query_for(db: &Database, driver: Drivers) -> QueryBuilder<Member, Database> {
+    let columns = Member::active_columns().iter().map(|c| c.to_snake_case()).collect();
+    QueryBuilder::new(db.clone(), driver, Member::table_name(), <Member as Model>::columns(), columns)
+}
+
+fn ids(members: &[Member]) -> Vec<i32> {
+    members.iter().map(|m| m.id).collect()
+}
+
+#[tokio::test]
+async fn test_filter_renders_every_operator() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let sql = query_for(&db, Drivers::Postgres)
+        .filter("age", Op::Gte, 18)
+        .filter("id", Op::In, vec![1, 2, 3])
+        .filter("deleted_at", Op::IsNull, ())
+        .filter("age", Op::Lte, 65)
+        .to_sql();
+    assert!(sql.contains("\"member\".\"age\" >= $1"), "{}", sql);
+    assert!(sql.contains("\"member\".\"id\" IN ($2, $3, $4)"), "{}", sql);
+    assert!(sql.contains("\"member\".\"deleted_at\" IS NULL"), "{}", sql);
+    assert!(sql.contains("\"member\".\"age\" <= $5"), "{}", sql);
+
+    let sql = query_for(&db, Drivers::MySQL).filter("id", Op::NotIn, [4, 5]).filter("deleted_at", Op::IsNotNull, ()).to_sql();
+    assert!(sql.contains("\"member\".\"id\" NOT IN (?, ?)"), "{}", sql);
+    assert!(sql.contains("\"member\".\"deleted_at\" IS NOT NULL"), "{}", sql);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_filter_operators_match_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+
+    for (id, age, deleted_at) in [(1, 15, None), (2, 18, None), (3, 40, Some("2024-01-01")), (4, 70, None)] {
+        db.model::<Member>().insert(&Member { id, age, deleted_at: deleted_at.map(str::to_string) }).await?;
+    }
+
+    let found: Vec<Member> = db.model::<Member>().filter("age", Op::Gte, 18).filter("deleted_at", Op::IsNull, ()).order("id ASC").scan().await?;
+    assert_eq!(ids(&found), [2, 4]);
+
+    let found: Vec<Member> = db.model::<Member>().filter("deleted_at", Op::IsNotNull, ()).scan().await?;
+    assert_eq!(ids(&found), [3]);
+
+    let found: Vec<Member> = db.model::<Member>().filter("id", Op::In, vec![1, 3, 9]).order("id ASC").scan().await?;
+    assert_eq!(ids(&found), [1, 3]);
+
+    let wanted: &[i32] = &[1, 2];
+    let found: Vec<Member> = db.model::<Member>().filter("id", Op::NotIn, wanted).filter("age", Op::Ne, 70).scan().await?;
+    assert_eq!(ids(&found), [3]);
+
+    // An empty list matches nothing for IN and everything for NOT IN
+    assert_eq!(db.model::<Member>().filter("id", Op::In, Vec::<i32>::new()).count().await?, 0);
+    assert_eq!(db.model::<Member>().filter("id", Op::NotIn, Vec::<i32>::new()).count().await?, 4);
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use bottle_orm::{Model, Op, SqlType};
+use chrono::{DateTime, Duration, Utc};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    id: i32,
+    code: String,
+}
+
+#[tokio::test]
+async fn test_filter_typed_compares_text_column_numerically() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Ticket>().run().await?;
+    let tickets = vec![
+        Ticket { id: 1, code: "9".to_string() },
+        Ticket { id: 2, code: "10".to_string() },
+        Ticket { id: 3, code: "100".to_string() },
+    ];
+    db.model::<Ticket>().batch_insert(&tickets).await?;
+
+    // The untyped parameter takes the column's TEXT affinity: "9" > "20" but "100" < "20"
+    let untyped: Vec<Ticket> = db.model::<Ticket>().filter("code", Op::Gt, 20).order("id ASC").scan().await?;
+    assert_eq!(untyped.iter().map(|t| t.id).collect::<Vec<_>>(), [1]);
+
+    let typed: Vec<Ticket> =
+        db.model::<Ticket>().filter_typed("code", Op::Gt, 20, SqlType::Integer).order("id ASC").scan().await?;
+    assert_eq!(typed.iter().map(|t| t.id).collect::<Vec<_>>(), [3]);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Launch {
+    #[orm(primary_key)]
+    id: i32,
+    starts_at: DateTime<Utc>,
+}
+
+#[tokio::test]
+async fn test_filter_typed_casts_timestamp_string_postgres() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS \"launch\"").execute().await?;
+    db.migrator().register::<Launch>().run().await?;
+    let now = Utc::now();
+    let launches =
+        vec![Launch { id: 1, starts_at: now - Duration::days(1) }, Launch { id: 2, starts_at: now + Duration::days(1) }];
+    db.model::<Launch>().batch_insert(&launches).await?;
+
+    // A text parameter cannot be compared with TIMESTAMPTZ without a cast
+    let untyped: Result<Vec<Launch>, _> = db.model::<Launch>().filter("starts_at", Op::Gt, now.to_rfc3339()).scan().await;
+    assert!(untyped.is_err());
+
+    let typed: Vec<Launch> =
+        db.model::<Launch>().filter_typed("starts_at", Op::Gt, now.to_rfc3339(), SqlType::TimestampTz).scan().await?;
+    assert_eq!(typed.iter().map(|l| l.id).collect::<Vec<_>>(), [2]);
+
+    db.raw("DROP TABLE \"launch\"").execute().await?;
+    Ok(())
+}
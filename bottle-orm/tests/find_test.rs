@@ -0,0 +1,57 @@
+use bottle_orm::{Model, Op};
+use uuid::Uuid;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Country {
+    #[orm(primary_key)]
+    code: String,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Device {
+    #[orm(primary_key)]
+    id: Uuid,
+    label: String,
+}
+
+#[tokio::test]
+async fn test_find_by_primary_key() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().register::<Country>().register::<Device>().run().await?;
+
+    for (id, name) in [(1, "ann"), (2, "bob")] {
+        db.model::<Member>().insert(&Member { id, name: name.to_string() }).await?;
+    }
+    db.model::<Country>().insert(&Country { code: "BR".to_string(), name: "Brazil".to_string() }).await?;
+    db.model::<Country>().insert(&Country { code: "PT".to_string(), name: "Portugal".to_string() }).await?;
+    let device_id = Uuid::new_v4();
+    db.model::<Device>().insert(&Device { id: device_id, label: "router".to_string() }).await?;
+    db.model::<Device>().insert(&Device { id: Uuid::new_v4(), label: "switch".to_string() }).await?;
+
+    let member: Member = db.model::<Member>().find(2).await?;
+    assert_eq!(member.name, "bob");
+
+    let country: Country = db.model::<Country>().find("PT").await?;
+    assert_eq!(country.name, "Portugal");
+
+    let device: Device = db.model::<Device>().find(device_id).await?;
+    assert_eq!(device.label, "router");
+
+    // Other filters still apply.
+    let err = db.model::<Member>().filter("name", Op::Eq, "ann".to_string()).find::<Member, _>(2).await.unwrap_err();
+    assert!(matches!(err, sqlx::Error::RowNotFound));
+
+    let err = db.model::<Member>().find::<Member, _>(99).await.unwrap_err();
+    assert!(matches!(err, sqlx::Error::RowNotFound));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    email: String,
+}
+
+#[tokio::test]
+async fn test_first_optional_maps_missing_row_to_none() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+    db.model::<Account>().insert(&Account { id: 1, email: "a@example.com".to_string() }).await?;
+
+    let found: Option<Account> =
+        db.model::<Account>().filter("email", Op::Eq, "a@example.com".to_string()).first_optional().await?;
+    assert_eq!(found.map(|a| a.id), Some(1));
+
+    let missing: Option<Account> =
+        db.model::<Account>().filter("email", Op::Eq, "b@example.com".to_string()).first_optional().await?;
+    assert!(missing.is_none());
+
+    // Validation errors are still errors.
+    assert!(db.model::<Account>().order("id; DROP TABLE account").first_optional::<Account>().await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_first_optional_inside_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+
+    let tx = db.begin().await?;
+    let before: Option<Account> = tx.model::<Account>().filter("id", Op::Eq, 7).first_optional().await?;
+    assert!(before.is_none());
+
+    tx.model::<Account>().insert(&Account { id: 7, email: "t@example.com".to_string() }).await?;
+    let after: Option<Account> = tx.model::<Account>().filter("id", Op::Eq, 7).first_optional().await?;
+    assert_eq!(after.map(|a| a.email), Some("t@example.com".to_string()));
+    tx.commit().await?;
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use bottle_orm::{Error, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "accounts")]
+struct User {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Post {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "User::id", on_delete = "cascade", on_update = "restrict")]
+    user_id: i32,
+    title: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Review {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "User::id")]
+    user_id: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Comment {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "User::id", on_delete = "explode")]
+    user_id: i32,
+}
+
+#[tokio::test]
+async fn test_on_delete_cascade_removes_children() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+    db.migrator().register::<User>().register::<Post>().run().await?;
+
+    let fk = Post::columns().into_iter().find(|c| c.name == "user_id").unwrap();
+    assert_eq!(fk.on_delete, Some("cascade"));
+    assert_eq!(fk.on_update, Some("restrict"));
+
+    db.model::<User>().insert(&User { id: 1, name: "alice".to_string() }).await?;
+    db.model::<User>().insert(&User { id: 2, name: "bob".to_string() }).await?;
+    db.model::<Post>().insert(&Post { id: 1, user_id: 1, title: "a1".to_string() }).await?;
+    db.model::<Post>().insert(&Post { id: 2, user_id: 1, title: "a2".to_string() }).await?;
+    db.model::<Post>().insert(&Post { id: 3, user_id: 2, title: "b1".to_string() }).await?;
+
+    db.model::<User>().filter("id", Op::Eq, 1).delete().await?;
+
+    let remaining: Vec<Post> = db.model::<Post>().scan().await?;
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].user_id, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_referential_action_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<User>().run().await?;
+
+    let err = db.create_table::<Comment>().await.unwrap_err();
+    assert!(matches!(err, Error::InvalidArgument(ref msg) if msg.contains("explode")), "unexpected error: {}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_plain_foreign_key_is_enforced_on_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<User>().register::<Review>().run().await?;
+
+    assert!(db.create_table_sql::<Review>()?[0].contains("\"user_id\" INTEGER NOT NULL REFERENCES \"accounts\"(\"id\")"));
+    db.model::<User>().insert(&User { id: 1, name: "alice".to_string() }).await?;
+    db.model::<Review>().insert(&Review { id: 1, user_id: 1 }).await?;
+    assert!(db.model::<Review>().insert(&Review { id: 2, user_id: 9 }).await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_foreign_key_errors_are_returned() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS review").execute().await?;
+    db.raw("DROP TABLE IF EXISTS accounts CASCADE").execute().await?;
+
+    db.migrator().register::<User>().register::<Review>().run().await?;
+    // Adding the same constraints again is a no-op
+    db.assign_foreign_keys::<Review>().await?;
+
+    db.drop_foreign_keys::<Review>().await?;
+    db.raw("INSERT INTO review (id, user_id) VALUES (1, 9)").execute().await?;
+    let err = db.assign_foreign_keys::<Review>().await.unwrap_err();
+    assert!(matches!(err, Error::DatabaseError(_)), "unexpected error: {}", err);
+
+    db.raw("DROP TABLE review").execute().await?;
+    db.raw("DROP TABLE accounts").execute().await?;
+    Ok(())
+}
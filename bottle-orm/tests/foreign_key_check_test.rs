@@ -0,0 +1,84 @@
+use bottle_orm::{Error, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "authors")]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Article {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Author::id")]
+    author_id: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct BrokenArticle {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Author::uuid")]
+    author_id: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Publisher {
+    #[orm(primary_key)]
+    id: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct OrphanArticle {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Publisher::id")]
+    publisher_id: i32,
+}
+
+#[tokio::test]
+async fn test_valid_foreign_key_passes_check() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Author>().register::<Article>().run().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_foreign_key_to_missing_column_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Author>().run().await?;
+    db.sync_table::<BrokenArticle>().await?;
+
+    match db.assign_foreign_keys::<BrokenArticle>().await {
+        Err(Error::InvalidForeignKey { table, column }) => {
+            assert_eq!(table, "authors");
+            assert_eq!(column, "uuid");
+        }
+        other => panic!("expected InvalidForeignKey, got {:?}", other),
+    }
+
+    let err = db.migrator().register::<Author>().register::<BrokenArticle>().run().await.unwrap_err();
+    assert!(err.to_string().contains("\"authors\".\"uuid\" does not exist"), "unexpected error: {}", err);
+
+    // The migrator keeps the typed error behind sqlx::Error::Configuration.
+    let sqlx::Error::Configuration(source) = err else { panic!("expected a configuration error, got {:?}", err) };
+    match source.downcast_ref::<Error>() {
+        Some(Error::InvalidForeignKey { table, column }) => assert_eq!((table.as_str(), column.as_str()), ("authors", "uuid")),
+        other => panic!("expected InvalidForeignKey, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_foreign_key_to_missing_table_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.sync_table::<OrphanArticle>().await?;
+
+    let err = db.assign_foreign_keys::<OrphanArticle>().await.unwrap_err();
+    assert!(matches!(err, Error::InvalidForeignKey { ref table, ref column } if table == "publisher" && column == "id"));
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use bottle_orm::{Error, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Tag {
+    #[orm(primary_key, auto_increment)]
+    id: i32,
+    #[orm(unique)]
+    name: String,
+}
+
+fn tag(name: &str) -> Tag {
+    Tag { id: 0, name: name.to_string() }
+}
+
+#[tokio::test]
+async fn test_get_or_create_inserts_once() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Tag>().run().await?;
+
+    let (first, created) = db.get_or_create("name", "rust".to_string(), &tag("rust")).await?;
+    assert!(created);
+    assert!(first.id > 0, "generated key should be read back");
+
+    let (second, created) = db.get_or_create("name", "rust".to_string(), &tag("rust")).await?;
+    assert!(!created);
+    assert_eq!(second, first);
+
+    assert_eq!(db.model::<Tag>().filter("name", Op::Eq, "rust".to_string()).count().await?, 1);
+
+    let (other, created) = db.get_or_create("name", "sql".to_string(), &tag("sql")).await?;
+    assert!(created);
+    assert_ne!(other.id, first.id);
+    assert_eq!(db.model::<Tag>().count().await?, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_or_create_in_transaction() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Tag>().run().await?;
+
+    let tx = db.begin().await?;
+    let (_, created) = tx.get_or_create("name", "orm".to_string(), &tag("orm")).await?;
+    assert!(created);
+    let (_, created) = tx.get_or_create("name", "orm".to_string(), &tag("orm")).await?;
+    assert!(!created);
+    // Same error type as Database::get_or_create
+    let missing: Result<_, Error> = tx.get_or_create("nope", "orm".to_string(), &tag("orm")).await;
+    assert!(missing.is_err());
+    tx.rollback().await?;
+
+    assert_eq!(db.model::<Tag>().count().await?, 0);
+    Ok(())
+}
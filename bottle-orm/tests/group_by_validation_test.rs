@@ -0,0 +1,89 @@
+use bottle_orm::{Database, FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Post {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    views: i64,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct UserViews {
+    user_id: i32,
+    total: i64,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Post>().run().await?;
+    db.model::<Post>()
+        .batch_insert(&[
+            Post { id: 1, user_id: 1, views: 10 },
+            Post { id: 2, user_id: 1, views: 5 },
+            Post { id: 3, user_id: 2, views: 7 },
+        ])
+        .await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_ungrouped_aggregate_mix_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = db.model::<Post>().select("user_id").select("COUNT(*)").scan::<(i32, i64)>().await.unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("'user_id'") && msg.contains("group_by"), "unexpected error: {}", msg);
+
+    // Commas inside function calls don't confuse the check.
+    let err = db.model::<Post>().select("user_id, COALESCE(SUM(views), 0)").scalar::<i32>().await.unwrap_err();
+    assert!(err.to_string().contains("'user_id'"));
+
+    // Pure aggregates need no grouping.
+    let total: i64 = db.model::<Post>().select("SUM(views)").scalar().await?;
+    assert_eq!(total, 22);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grouped_and_auto_grouped_aggregates() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let grouped: Vec<(i32, i64)> =
+        db.model::<Post>().select("user_id, COUNT(*)").group_by("user_id").order("user_id ASC").scan().await?;
+    assert_eq!(grouped, vec![(1, 2), (2, 1)]);
+
+    let auto: Vec<(i32, i64)> =
+        db.model::<Post>().select("user_id").select("SUM(views) AS total").auto_group_by().order("user_id ASC").scan().await?;
+    assert_eq!(auto, vec![(1, 15), (2, 7)]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_full_model_scan_after_group_by_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = db.model::<Post>().group_by("user_id").scan::<Post>().await.unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("neither grouped nor aggregated") && msg.contains("scan_as"), "unexpected error: {}", msg);
+
+    let err = db.model::<Post>().group_by("user_id").first::<Post>().await.unwrap_err();
+    assert!(err.to_string().contains("neither grouped nor aggregated"));
+
+    // A DTO holding only grouped and aggregate columns is fine.
+    let stats: Vec<UserViews> = db
+        .model::<Post>()
+        .select("user_id, SUM(views) AS total")
+        .group_by("user_id")
+        .order("user_id ASC")
+        .scan_as()
+        .await?;
+    assert_eq!(stats.iter().map(|s| (s.user_id, s.total)).collect::<Vec<_>>(), vec![(1, 15), (2, 7)]);
+
+    // Grouping by the primary key leaves one value per column.
+    let posts: Vec<Post> = db.model::<Post>().group_by("id").order("id ASC").scan().await?;
+    assert_eq!(posts.len(), 3);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use bottle_orm::{Aggregate, Database, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Post {
+    #[orm(primary_key)]
+    id: i32,
+    user_id: i32,
+    views: i64,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Post>().run().await?;
+    let posts: Vec<Post> = [(1, 1, 10), (2, 1, 5), (3, 1, 1), (4, 2, 40), (5, 3, 2), (6, 3, 3)]
+        .into_iter()
+        .map(|(id, user_id, views)| Post { id, user_id, views })
+        .collect();
+    db.model::<Post>().batch_insert(&posts).await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_having_count_filters_groups() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let query = db.model::<Post>().select("user_id, COUNT(*)").group_by_column("user_id").having_count(Op::Gt, 1);
+    let sql = query.to_sql();
+    assert!(sql.contains("GROUP BY \"user_id\" HAVING 1=1 AND COUNT(*) > ?"), "{}", sql);
+
+    let prolific: Vec<(i32, i64)> = query.order("user_id ASC").scan().await?;
+    assert_eq!(prolific, vec![(1, 3), (3, 2)]);
+
+    let prolific: Vec<(i32, i64)> =
+        db.model::<Post>().select("user_id, COUNT(*)").group_by_column("user_id").having_count(Op::Gt, 2).scan().await?;
+    assert_eq!(prolific, vec![(1, 3)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_having_agg_binds_the_value() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let popular: Vec<(i32, i64)> = db
+        .model::<Post>()
+        .select("user_id, SUM(views)")
+        .group_by_column("post.user_id")
+        .having_agg(Aggregate::Sum, "views", Op::Gte, 16)
+        .having_agg(Aggregate::Count, "*", Op::Lt, 3)
+        .scan()
+        .await?;
+    assert_eq!(popular, vec![(2, 40)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unknown_columns_are_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = db.model::<Post>().select("COUNT(*)").group_by_column("user_id; DROP TABLE post").scan::<(i64,)>().await.unwrap_err();
+    assert!(err.to_string().contains("Cannot group by unknown column"), "{}", err);
+
+    let err = db
+        .model::<Post>()
+        .select("user_id, COUNT(*)")
+        .group_by_column("user_id")
+        .having_agg(Aggregate::Max, "views) OR (1", Op::Gt, 0)
+        .scan::<(i32, i64)>()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Cannot aggregate unknown column"), "{}", err);
+
+    Ok(())
+}
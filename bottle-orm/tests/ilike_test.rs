@@ -0,0 +1,65 @@
+use bottle_orm::{Database, Drivers, Model, Op, QueryBuilder};
+use heck::ToSnakeCase;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Profile {
+    #[orm(primary_key)]
+    id: i32,
+    username: String,
+}
+
+fn query_for(db: &Database, driver: Drivers) -> QueryBuilder<Profile, Database> {
+    let columns = Profile::active_columns().iter().map(|c| c.to_snake_case()).collect();
+    QueryBuilder::new(db.clone(), driver, Profile::table_name(), <Profile as Model>::columns(), columns)
+}
+
+#[tokio::test]
+async fn test_ilike_matches_regardless_of_case() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let sql = query_for(&db, Drivers::Postgres).filter("username", Op::ILike, "%ALICE%").to_sql();
+    assert!(sql.contains("\"profile\".\"username\" ILIKE $1"), "{}", sql);
+    let sql = query_for(&db, Drivers::MySQL).filter("username", Op::ILike, "%ALICE%").to_sql();
+    assert!(sql.contains("LOWER(\"profile\".\"username\") LIKE LOWER(?)"), "{}", sql);
+    let sql = query_for(&db, Drivers::SQLite).filter("username", Op::ILike, "%ALICE%").to_sql();
+    assert!(sql.contains("LOWER(\"profile\".\"username\") LIKE LOWER(?)"), "{}", sql);
+
+    db.migrator().register::<Profile>().run().await?;
+    for (id, username) in [(1, "alice"), (2, "Bob"), (3, "malice_42")] {
+        db.model::<Profile>().insert(&Profile { id, username: username.to_string() }).await?;
+    }
+
+    let found: Vec<Profile> = db.model::<Profile>().filter("username", Op::ILike, "%ALICE%").order("id ASC").scan().await?;
+    assert_eq!(found.iter().map(|p| p.id).collect::<Vec<_>>(), [1, 3]);
+    let found: Vec<Profile> = db.model::<Profile>().filter("username", Op::ILike, "bob").scan().await?;
+    assert_eq!(found.iter().map(|p| p.id).collect::<Vec<_>>(), [2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_subquery_bitand_and_now_filters_render_operators() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Profile>().run().await?;
+    for (id, username) in [(1, "alice"), (2, "Bob")] {
+        db.model::<Profile>().insert(&Profile { id, username: username.to_string() }).await?;
+    }
+
+    let names = db.model::<Profile>().select("username").filter("id", Op::Eq, 2);
+    let found: Vec<Profile> = db.model::<Profile>().filter_subquery("username", Op::ILike, names).scan().await?;
+    assert_eq!(found.iter().map(|p| p.id).collect::<Vec<_>>(), [2]);
+
+    let names = db.model::<Profile>().select("username");
+    let sql = db.model::<Profile>().filter_subquery("username", Op::IsNotNull, names).to_sql();
+    assert!(sql.contains("\"profile\".\"username\" IS NOT NULL") && !sql.contains("NOT NULL ("), "{}", sql);
+
+    let found: Vec<Profile> = db.model::<Profile>().filter_bitand("id", 1, Op::IsNotNull, 0).order("id ASC").scan().await?;
+    assert_eq!(found.len(), 2);
+    let sql = query_for(&db, Drivers::Postgres).filter_bitand("id", 1, Op::Eq, 1).to_sql();
+    assert!(sql.contains("(\"profile\".\"id\" & $1) = $2"), "{}", sql);
+
+    let sql = query_for(&db, Drivers::MySQL).filter_now("username", Op::IsNull).to_sql();
+    assert!(sql.ends_with("\"profile\".\"username\" IS NULL"), "{}", sql);
+    Ok(())
+}
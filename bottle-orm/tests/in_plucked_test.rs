@@ -0,0 +1,51 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Player {
+    #[orm(primary_key)]
+    id: i32,
+    nick: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ban {
+    #[orm(primary_key)]
+    id: i32,
+    player_id: i32,
+    active: i32,
+}
+
+#[tokio::test]
+async fn test_filter_by_plucked_ids() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Player>().register::<Ban>().run().await?;
+
+    for (id, nick) in [(1, "ann"), (2, "bob"), (3, "cid"), (4, "dee")] {
+        db.model::<Player>().insert(&Player { id, nick: nick.to_string() }).await?;
+    }
+    for (id, player_id, active) in [(1, 2, 1), (2, 4, 1), (3, 1, 0)] {
+        db.model::<Ban>().insert(&Ban { id, player_id, active }).await?;
+    }
+
+    let banned: Vec<Player> = db
+        .model::<Player>()
+        .in_plucked::<Ban, _, i32>("id", db.model::<Ban>().filter("active", Op::Eq, 1), "player_id")
+        .await?
+        .order("id ASC")
+        .scan()
+        .await?;
+    let nicks: Vec<&str> = banned.iter().map(|p| p.nick.as_str()).collect();
+    assert_eq!(nicks, ["bob", "dee"]);
+
+    // An empty plucked set matches nothing.
+    let none: Vec<Player> = db
+        .model::<Player>()
+        .in_plucked::<Ban, _, i32>("id", db.model::<Ban>().filter("active", Op::Eq, 5), "player_id")
+        .await?
+        .scan()
+        .await?;
+    assert!(none.is_empty());
+    Ok(())
+}
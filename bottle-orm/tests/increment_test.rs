@@ -0,0 +1,40 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Article {
+    #[orm(primary_key)]
+    id: i32,
+    views: i64,
+    stock: i32,
+}
+
+#[tokio::test]
+async fn test_increment_and_decrement() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Article>().run().await?;
+    db.model::<Article>()
+        .batch_insert(&[Article { id: 1, views: 0, stock: 3 }, Article { id: 2, views: 7, stock: 3 }])
+        .await?;
+
+    for _ in 0..2 {
+        let affected = db.model::<Article>().filter("id", Op::Eq, 1).increment("views", 5i64).await?;
+        assert_eq!(affected, 1);
+    }
+    let article: Article = db.model::<Article>().filter("id", Op::Eq, 1).first().await?;
+    assert_eq!(article.views, 10);
+
+    // Only rows matching the filters change
+    assert_eq!(db.model::<Article>().filter("stock", Op::Gte, 2).decrement("stock", 2).await?, 2);
+    assert_eq!(db.model::<Article>().filter("stock", Op::Gte, 2).decrement("stock", 2).await?, 0);
+    let stock: Vec<i32> = db.model::<Article>().order("id ASC").pluck("stock").await?;
+    assert_eq!(stock, vec![1, 1]);
+    let other: Article = db.model::<Article>().filter("id", Op::Eq, 2).first().await?;
+    assert_eq!(other.views, 7);
+
+    let err = db.model::<Article>().increment("views = 0, stock", 1).await.unwrap_err();
+    assert!(err.to_string().contains("Cannot update unknown column"), "{}", err);
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use bottle_orm::{Database, FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Note {
+    #[orm(primary_key, auto_increment)]
+    id: i64,
+    title: String,
+    #[orm(default = "CURRENT_TIMESTAMP")]
+    created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct NoteId {
+    id: i64,
+}
+
+#[tokio::test]
+async fn test_insert_returning_reads_generated_values() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Note>().run().await?;
+
+    let saved: Note = db
+        .model::<Note>()
+        .use_defaults()
+        .insert_returning(&Note { id: 0, title: "first".to_string(), created_at: None })
+        .await?;
+    assert_eq!(saved.id, 1);
+    assert_eq!(saved.title, "first");
+    assert!(saved.created_at.is_some(), "default should be returned");
+
+    let explicit: Note = db
+        .model::<Note>()
+        .insert_returning(&Note { id: 0, title: "second".to_string(), created_at: Some("yesterday".to_string()) })
+        .await?;
+    assert_eq!(explicit.id, 2);
+    assert_eq!(explicit.created_at.as_deref(), Some("yesterday"));
+
+    let only_id: NoteId = db
+        .model::<Note>()
+        .select("id")
+        .insert_returning(&Note { id: 0, title: "third".to_string(), created_at: None })
+        .await?;
+    assert_eq!(only_id.id, 3);
+
+    // Without use_defaults(), None is stored as NULL even when a default exists
+    let (created_at,): (Option<String>,) = db.raw("SELECT created_at FROM note WHERE id = 3").fetch_one().await?;
+    assert_eq!(created_at, None);
+
+    let stored: Note = db.model::<Note>().order("id DESC").first().await?;
+    assert_eq!(stored.title, "third");
+    Ok(())
+}
+
+/// Inserts through an aliased builder, whose select list is qualified with the alias.
+async fn assert_aliased_insert_returning(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    db.raw("DROP TABLE IF EXISTS note").execute().await?;
+    db.migrator().register::<Note>().run().await?;
+
+    let saved: Note = db
+        .model::<Note>()
+        .alias("n")
+        .insert_returning(&Note { id: 0, title: "aliased".to_string(), created_at: Some("today".to_string()) })
+        .await?;
+    assert_eq!(saved.title, "aliased");
+    assert_eq!(saved.created_at.as_deref(), Some("today"));
+
+    let only_id: NoteId = db
+        .model::<Note>()
+        .alias("n")
+        .select("n.id")
+        .insert_returning(&Note { id: 0, title: "second".to_string(), created_at: None })
+        .await?;
+    assert_eq!(only_id.id, saved.id + 1);
+
+    db.raw("DROP TABLE note").execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_insert_returning_with_alias() -> Result<(), Box<dyn std::error::Error>> {
+    assert_aliased_insert_returning(&common::sqlite().await?).await
+}
+
+#[tokio::test]
+async fn test_postgres_insert_returning_with_alias() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    assert_aliased_insert_returning(&db).await
+}
@@ -0,0 +1,66 @@
+use bottle_orm::{BottleIntEnum, FromAnyRow, Model, Op};
+
+mod common;
+
+#[derive(BottleIntEnum, Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Admin,
+    Moderator,
+    Member,
+    Guest = 10,
+}
+
+#[derive(Model, Debug, Clone, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(int_enum)]
+    role: Role,
+    #[orm(int_enum)]
+    previous_role: Option<Role>,
+}
+
+#[derive(FromAnyRow, Debug)]
+struct AccountRole {
+    id: i32,
+    #[orm(int_enum)]
+    role: Role,
+}
+
+#[test]
+fn test_discriminant_conversions() {
+    assert_eq!(i32::from(Role::Admin), 0);
+    assert_eq!(i32::from(&Role::Member), 2);
+    assert_eq!(i32::from(Role::Guest), 10);
+    assert_eq!(Role::try_from(10), Ok(Role::Guest));
+    assert!(Role::try_from(3).is_err());
+}
+
+#[tokio::test]
+async fn test_int_enum_is_stored_as_integer() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+
+    let accounts = vec![
+        Account { id: 1, role: Role::Admin, previous_role: None },
+        Account { id: 2, role: Role::Moderator, previous_role: Some(Role::Member) },
+        Account { id: 3, role: Role::Member, previous_role: Some(Role::Guest) },
+    ];
+    db.model::<Account>().batch_insert(&accounts).await?;
+
+    let (stored, stored_type): (i32, String) =
+        db.raw("SELECT role, typeof(role) FROM account WHERE id = 1").fetch_one().await?;
+    assert_eq!(stored, 0);
+    assert_eq!(stored_type, "integer");
+
+    let staff: Vec<Account> = db.model::<Account>().filter("role", Op::Lt, 2).order("id ASC").scan().await?;
+    assert_eq!(staff, accounts[..2].to_vec());
+
+    let all: Vec<Account> = db.model::<Account>().order("id ASC").scan().await?;
+    assert_eq!(all, accounts);
+
+    let roles: Vec<AccountRole> = db.model::<Account>().select("id, role").order("id DESC").scan_as().await?;
+    assert_eq!(roles[0].id, 3);
+    assert_eq!(roles[0].role, Role::Member);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use bottle_orm::{Database, Model};
+use serde::{Deserialize, Serialize};
+
+mod common;
+
+#[derive(Model, Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct Permission {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Model, Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct RolePermission {
+    #[orm(primary_key)]
+    id: i32,
+    role_id: i32,
+    permission_id: i32,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Permission>().register::<RolePermission>().run().await?;
+
+    for (id, name) in [(1, "read"), (2, "write"), (3, "admin")] {
+        db.model::<Permission>().insert(&Permission { id, name: name.to_string() }).await?;
+    }
+    for (id, role_id, permission_id) in [(1, 10, 1), (2, 20, 2), (3, 30, 3)] {
+        db.model::<RolePermission>().insert(&RolePermission { id, role_id, permission_id }).await?;
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_join_raw_many_binds_every_placeholder() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let permissions: Vec<Permission> = db
+        .model::<Permission>()
+        .join_raw_many("role_permission rp", "rp.permission_id = permission.id AND (rp.role_id = ? OR rp.role_id = ?)", &[10, 30])
+        .order("permission.id ASC")
+        .scan()
+        .await?;
+
+    let names: Vec<_> = permissions.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["read", "admin"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_join_raw_many_rejects_placeholder_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let result: Result<Vec<Permission>, _> = db
+        .model::<Permission>()
+        .join_raw_many("role_permission rp", "rp.permission_id = permission.id AND rp.role_id = ?", &[10, 30])
+        .scan()
+        .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
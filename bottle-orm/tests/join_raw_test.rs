@@ -1,4 +1,4 @@
-use bottle_orm::{Database, Model};
+use bottle_orm::{pagination::Paginated, Database, Model, Op, Pagination};
 use serde::{Deserialize, Serialize};
 
 #[derive(Model, Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -52,3 +52,30 @@ async fn test_join_raw_with_placeholder() -> Result<(), Box<dyn std::error::Erro
     println!("Join raw test passed!");
     Ok(())
 }
+
+#[tokio::test]
+async fn test_paginate_over_join_raw_binds_join_values() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    db.migrator().register::<Permission>().register::<RolePermission>().run().await?;
+
+    for (id, name) in [(1, "read"), (2, "write"), (3, "delete")] {
+        db.model::<Permission>().insert(&Permission { id, name: name.to_string() }).await?;
+    }
+    for (id, role_id, permission_id) in [(1, 10, 1), (2, 10, 2), (3, 10, 3), (4, 20, 1)] {
+        db.model::<RolePermission>().insert(&RolePermission { id, role_id, permission_id }).await?;
+    }
+
+    let page: Paginated<Permission> = Pagination::new(0, 2)
+        .paginate(
+            db.model::<Permission>()
+                .join_raw("role_permission rp", "rp.permission_id = permission.id AND rp.role_id = ?", 10)
+                .filter("permission.id", Op::Gt, 1)
+                .order("permission.id ASC"),
+        )
+        .await?;
+
+    assert_eq!(page.total, 2);
+    assert_eq!(page.total_pages, 1);
+    assert_eq!(page.data.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), ["write", "delete"]);
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use bottle_orm::{Database, Model, ColumnInfo};
+use bottle_orm::{Model, ColumnInfo};
 use uuid::Uuid;
 use std::collections::HashMap;
 
+mod common;
+
 // Version 1 of the Model
 #[derive(Debug, Clone, PartialEq)]
 struct UserV1 {
@@ -13,8 +15,8 @@ impl Model for UserV1 {
     fn table_name() -> &'static str { "users_evolution" }
     fn columns() -> Vec<ColumnInfo> {
         vec![
-            ColumnInfo { name: "id", sql_type: "UUID", is_primary_key: true, is_nullable: false, create_time: false, update_time: false, unique: false, index: false, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
-            ColumnInfo { name: "name", sql_type: "TEXT", is_primary_key: false, is_nullable: false, create_time: false, update_time: false, unique: false, index: false, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
+            ColumnInfo::builder("id", "UUID").primary_key().build(),
+            ColumnInfo::builder("name", "TEXT").build(),
         ]
     }
     fn column_names() -> Vec<String> { vec!["id".to_string(), "name".to_string()] }
@@ -40,10 +42,10 @@ impl Model for UserV2 {
     fn table_name() -> &'static str { "users_evolution" }
     fn columns() -> Vec<ColumnInfo> {
         vec![
-            ColumnInfo { name: "id", sql_type: "UUID", is_primary_key: true, is_nullable: false, create_time: false, update_time: false, unique: false, index: false, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
-            ColumnInfo { name: "name", sql_type: "TEXT", is_primary_key: false, is_nullable: false, create_time: false, update_time: false, unique: false, index: false, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
-            ColumnInfo { name: "age", sql_type: "INTEGER", is_primary_key: false, is_nullable: false, create_time: false, update_time: false, unique: false, index: false, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
-            ColumnInfo { name: "email", sql_type: "TEXT", is_primary_key: false, is_nullable: false, create_time: false, update_time: false, unique: false, index: true, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
+            ColumnInfo::builder("id", "UUID").primary_key().build(),
+            ColumnInfo::builder("name", "TEXT").build(),
+            ColumnInfo::builder("age", "INTEGER").build(),
+            ColumnInfo::builder("email", "TEXT").index().build(),
         ]
     }
     fn column_names() -> Vec<String> { vec!["id".to_string(), "name".to_string(), "age".to_string(), "email".to_string()] }
@@ -60,7 +62,7 @@ impl Model for UserV2 {
 
 #[tokio::test]
 async fn test_migration_diffing_evolution() -> Result<(), Box<dyn std::error::Error>> {
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
 
     // 1. Create V1
     db.sync_table::<UserV1>().await?;
@@ -95,7 +97,7 @@ async fn test_migration_diffing_evolution() -> Result<(), Box<dyn std::error::Er
 
 #[tokio::test]
 async fn test_migration_index_diffing() -> Result<(), Box<dyn std::error::Error>> {
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
 
     // 1. Create V1 (without index on name)
     db.sync_table::<UserV1>().await?;
@@ -115,8 +117,8 @@ async fn test_migration_index_diffing() -> Result<(), Box<dyn std::error::Error>
         fn table_name() -> &'static str { "users_evolution" }
         fn columns() -> Vec<ColumnInfo> {
             vec![
-                ColumnInfo { name: "id", sql_type: "UUID", is_primary_key: true, is_nullable: false, create_time: false, update_time: false, unique: false, index: false, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
-                ColumnInfo { name: "name", sql_type: "TEXT", is_primary_key: false, is_nullable: false, create_time: false, update_time: false, unique: false, index: true, foreign_table: None, foreign_key: None, omit: false, soft_delete: false },
+                ColumnInfo::builder("id", "UUID").primary_key().build(),
+                ColumnInfo::builder("name", "TEXT").index().build(),
             ]
         }
         fn column_names() -> Vec<String> { vec!["id".to_string(), "name".to_string()] }
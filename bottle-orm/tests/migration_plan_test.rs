@@ -0,0 +1,65 @@
+use bottle_orm::Model;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    name: String,
+    #[orm(index)]
+    country: Option<String>,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "author")]
+struct AuthorV2 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    name: String,
+    #[orm(index)]
+    country: Option<String>,
+    #[orm(default = "0")]
+    followers: i32,
+}
+
+#[tokio::test]
+async fn test_create_table_sql_snapshot() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    assert_eq!(
+        db.create_table_sql::<Author>()?,
+        vec![
+            "CREATE TABLE IF NOT EXISTS \"author\" (\"id\" INTEGER PRIMARY KEY, \"name\" TEXT NOT NULL, \"country\" TEXT)"
+                .to_string(),
+            "CREATE UNIQUE INDEX IF NOT EXISTS \"unique_author_name\" ON \"author\" (\"name\")".to_string(),
+            "CREATE INDEX IF NOT EXISTS \"idx_author_country\" ON \"author\" (\"country\")".to_string(),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_plan_does_not_touch_the_database() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let plan = db.migrator().register::<Author>().register_migration(1, "UPDATE author SET name = 'x'", "").plan().await?;
+    assert_eq!(plan.len(), 4);
+    assert!(plan[0].starts_with("CREATE TABLE IF NOT EXISTS \"author\""));
+    assert_eq!(plan[3], "UPDATE author SET name = 'x'");
+    assert!(!db.table_exists("author").await?);
+    assert!(!db.table_exists("_bottle_migrations").await?);
+
+    // Once applied, only the schema difference is planned
+    db.migrator().register::<Author>().register_migration(1, "UPDATE author SET name = 'x'", "").run().await?;
+    assert!(db.migrator().register::<Author>().register_migration(1, "", "").plan().await?.is_empty());
+    assert_eq!(
+        db.sync_table_sql::<AuthorV2>().await?,
+        vec!["ALTER TABLE \"author\" ADD COLUMN \"followers\" INTEGER DEFAULT 0".to_string()]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use bottle_orm::{Database, Model};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Book {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Author::id", on_delete = "cascade")]
+    author_id: i32,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_rollback_drops_tables_in_reverse_order() -> Result<(), Box<dyn std::error::Error>> {
+    let logged: Arc<Mutex<Vec<String>>> = Arc::default();
+    let sink = logged.clone();
+    let db = Database::builder()
+        .max_connections(1)
+        .on_query(move |sql, _| sink.lock().unwrap().push(sql.to_string()))
+        .connect("sqlite::memory:")
+        .await?;
+    db.raw("PRAGMA foreign_keys = ON").execute().await?;
+
+    db.migrator().register::<Author>().register::<Book>().run().await?;
+    db.model::<Author>().insert(&Author { id: 1, name: "Ursula".to_string() }).await?;
+    db.model::<Book>().insert(&Book { id: 1, author_id: 1, title: "The Dispossessed".to_string() }).await?;
+
+    logged.lock().unwrap().clear();
+    db.migrator().register::<Author>().register::<Book>().rollback().await?;
+    let drops: Vec<String> = logged.lock().unwrap().iter().filter(|sql| sql.starts_with("DROP TABLE")).cloned().collect();
+    assert_eq!(drops, ["DROP TABLE IF EXISTS \"book\"", "DROP TABLE IF EXISTS \"author\""]);
+    assert!(!db.table_exists("author").await?);
+    assert!(!db.table_exists("book").await?);
+
+    // Rolling back again is a no-op, and the schema can be rebuilt
+    db.migrator().register::<Author>().register::<Book>().rollback().await?;
+    db.migrator().register::<Author>().register::<Book>().run().await?;
+    assert_eq!(db.model::<Book>().count().await?, 0);
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use bottle_orm::Model;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Shelf {
+    #[orm(primary_key)]
+    id: i32,
+    label: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Library {
+    #[orm(primary_key)]
+    id: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Book {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Library::id")]
+    library_id: i32,
+}
+
+#[tokio::test]
+async fn test_failed_migration_rolls_back_earlier_models() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    // Book's foreign key targets a table that was never migrated, failing the second model
+    let err = db.migrator().register::<Shelf>().register::<Book>().run().await.unwrap_err();
+    assert!(err.to_string().contains("\"library\".\"id\" does not exist"), "unexpected error: {}", err);
+    assert!(!db.table_exists("shelf").await?);
+    assert!(!db.table_exists("book").await?);
+
+    // The connection is usable afterwards and a valid run commits
+    db.migrator().register::<Shelf>().run().await?;
+    assert!(db.table_exists("shelf").await?);
+    Ok(())
+}
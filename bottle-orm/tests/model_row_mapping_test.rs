@@ -0,0 +1,40 @@
+use bottle_orm::{AnyImpl, Model, Op};
+
+mod common;
+
+// Derives only `Model`; the row mapping comes with it.
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Gadget {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    price: Option<f64>,
+}
+
+#[tokio::test]
+async fn test_model_only_derive_scans_without_from_any_row() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Gadget>().run().await?;
+
+    let gadgets = [
+        Gadget { id: 1, name: "lamp".to_string(), price: Some(9.5) },
+        Gadget { id: 2, name: "fan".to_string(), price: None },
+    ];
+    db.model::<Gadget>().batch_insert(&gadgets).await?;
+
+    let all: Vec<Gadget> = db.model::<Gadget>().order("id ASC").scan().await?;
+    assert_eq!(all, gadgets);
+
+    let fan: Gadget = db.model::<Gadget>().filter("name", Op::Eq, "fan".to_string()).first().await?;
+    assert_eq!(fan, gadgets[1]);
+
+    let as_dto: Vec<Gadget> = db.model::<Gadget>().order("id ASC").scan_as().await?;
+    assert_eq!(as_dto, gadgets);
+
+    // sqlx::FromRow is generated too, so raw queries map onto the model.
+    let raw: Vec<Gadget> = db.raw("SELECT id, name, price FROM gadget ORDER BY id").fetch_all().await?;
+    assert_eq!(raw, gadgets);
+
+    assert_eq!(<Gadget as AnyImpl>::columns().len(), 3);
+    Ok(())
+}
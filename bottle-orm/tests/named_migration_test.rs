@@ -0,0 +1,68 @@
+use bottle_orm::{Database, Model};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    email: String,
+}
+
+async fn migrate(db: &Database, first: Arc<AtomicUsize>, second: Arc<AtomicUsize>) -> Result<(), sqlx::Error> {
+    db.migrator()
+        .register::<Member>()
+        .add("0001_seed_member", move |db| {
+            let first = first.clone();
+            async move {
+                first.fetch_add(1, Ordering::SeqCst);
+                sqlx::query("INSERT INTO member (id, email) VALUES (1, '')").execute(&db.get_pool()).await?;
+                Ok(())
+            }
+        })
+        .add("0002_backfill_emails", move |db| {
+            let second = second.clone();
+            async move {
+                second.fetch_add(1, Ordering::SeqCst);
+                sqlx::query("UPDATE member SET email = 'member' || id || '@example.com' WHERE email = ''")
+                    .execute(&db.get_pool())
+                    .await?;
+                Ok(())
+            }
+        })
+        .run()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_named_migrations_run_once_in_order() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    let first = Arc::new(AtomicUsize::new(0));
+    let second = Arc::new(AtomicUsize::new(0));
+
+    migrate(&db, first.clone(), second.clone()).await?;
+    migrate(&db, first.clone(), second.clone()).await?;
+
+    assert_eq!(first.load(Ordering::SeqCst), 1);
+    assert_eq!(second.load(Ordering::SeqCst), 1);
+
+    let members: Vec<Member> = db.model::<Member>().scan().await?;
+    assert_eq!(members, [Member { id: 1, email: "member1@example.com".to_string() }]);
+
+    let (recorded,): (i64,) = db.raw("SELECT COUNT(*) FROM _bottle_migrations").fetch_one().await?;
+    assert_eq!(recorded, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_duplicate_migration_name_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    let result = db.migrator().add("0001", |_| async { Ok(()) }).add("0001", |_| async { Ok(()) }).run().await;
+    assert!(matches!(result, Err(sqlx::Error::Configuration(_))));
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Player {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn test_not_in_list_returns_complement() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Player>().run().await?;
+    let names = ["ana", "bruno", "carla", "diego", "eva"];
+    let players: Vec<Player> = names.iter().zip(1..).map(|(n, id)| Player { id, name: n.to_string() }).collect();
+    db.model::<Player>().batch_insert(&players).await?;
+
+    let excluded = vec!["bruno".to_string(), "eva".to_string()];
+    let rest: Vec<Player> = db.model::<Player>().not_in_list("name", excluded).order("id ASC").scan().await?;
+    assert_eq!(rest.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(), ["ana", "carla", "diego"]);
+
+    // An empty list excludes nothing.
+    assert_eq!(db.model::<Player>().not_in_list("name", Vec::<String>::new()).count().await?, 5);
+
+    let picked: Vec<Player> = db
+        .model::<Player>()
+        .filter("id", Op::Eq, 1)
+        .or_not_in_list("id", vec![1, 2, 3, 4])
+        .order("id ASC")
+        .scan()
+        .await?;
+    assert_eq!(picked.iter().map(|p| p.id).collect::<Vec<_>>(), [1, 5]);
+    assert_eq!(db.model::<Player>().filter("id", Op::Eq, 1).or_not_in_list("id", Vec::<i32>::new()).count().await?, 5);
+
+    let err = db.model::<Player>().not_in_list("name\" OR 1=1 --", vec![1]).scan::<Player>().await.unwrap_err();
+    assert!(err.to_string().contains("unknown column"), "unexpected error: {}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_not_in_list_unknown_column_fails_every_terminal() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Player>().run().await?;
+    let players: Vec<Player> = (1..=3).map(|id| Player { id, name: format!("p{}", id) }).collect();
+    db.model::<Player>().batch_insert(&players).await?;
+
+    // A dropped filter would otherwise touch every row.
+    assert!(db.model::<Player>().not_in_list("nmae", vec!["p1".to_string()]).delete().await.is_err());
+    assert!(db.model::<Player>().not_in_list("nmae", vec!["p1".to_string()]).hard_delete().await.is_err());
+    assert!(db.model::<Player>().not_in_list("nmae", vec!["p1".to_string()]).update("name", "x".to_string()).await.is_err());
+    assert!(db.model::<Player>().not_in_list("nmae", vec!["p1".to_string()]).exists().await.is_err());
+    assert!(db.model::<Player>().not_in_list("nmae", vec!["p1".to_string()]).count().await.is_err());
+
+    let names: Vec<String> = db.model::<Player>().order("id ASC").pluck("name").await?;
+    assert_eq!(names, ["p1", "p2", "p3"]);
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use bottle_orm::{Database, FromAnyRow, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Contact {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    nickname: Option<String>,
+    age: Option<i32>,
+    score: Option<f64>,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct ContactView {
+    id: i32,
+    nickname: Option<String>,
+    age: Option<i32>,
+    score: Option<f64>,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct ContactNote {
+    name: String,
+    note: Option<String>,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Contact>().run().await?;
+    db.model::<Contact>()
+        .insert(&Contact { id: 1, name: "ann".to_string(), nickname: None, age: None, score: None })
+        .await?;
+    db.model::<Contact>()
+        .insert(&Contact {
+            id: 2,
+            name: "bob".to_string(),
+            nickname: Some("bobby".to_string()),
+            age: Some(41),
+            score: Some(9.5),
+        })
+        .await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_null_columns_decode_as_none() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let views: Vec<ContactView> = db.model::<Contact>().order("id ASC").scan_as().await?;
+    assert_eq!(views.len(), 2);
+    assert_eq!(views[0].nickname, None);
+    assert_eq!(views[0].age, None);
+    assert_eq!(views[0].score, None);
+    assert_eq!(views[1].nickname.as_deref(), Some("bobby"));
+    assert_eq!(views[1].age, Some(41));
+    assert_eq!(views[1].score, Some(9.5));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_null_expression_decodes_as_none() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    // A bare NULL carries no column type; it still maps to None.
+    let notes: Vec<ContactNote> = db
+        .model::<Contact>()
+        .select("name, NULL AS note")
+        .filter("id", Op::Eq, 1)
+        .scan_as()
+        .await?;
+    assert_eq!(notes.len(), 1);
+    assert_eq!(notes[0].name, "ann");
+    assert_eq!(notes[0].note, None);
+
+    let notes: Vec<ContactNote> =
+        db.model::<Contact>().select("name, nickname AS note").order("id ASC").scan_as().await?;
+    assert_eq!(notes[0].note, None);
+    assert_eq!(notes[1].note.as_deref(), Some("bobby"));
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use bottle_orm::{FromAnyRow, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    login: String,
+    #[orm(omit)]
+    password_hash: String,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct Credentials {
+    login: String,
+    password_hash: String,
+}
+
+#[tokio::test]
+async fn test_omit_attribute_excludes_column_from_default_select() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+
+    let omit_col = Member::columns().into_iter().find(|c| c.name == "password_hash").unwrap();
+    assert!(omit_col.omit);
+    assert!(!Member::columns()[1].omit);
+
+    db.model::<Member>()
+        .insert(&Member { id: 1, login: "root".to_string(), password_hash: "s3cret".to_string() })
+        .await?;
+
+    let sql = db.model::<Member>().to_sql();
+    assert!(!sql.contains("password_hash"), "omitted column selected: {}", sql);
+
+    let members: Vec<Member> = db.model::<Member>().scan().await?;
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].login, "root");
+    assert_eq!(members[0].password_hash, "");
+
+    // A full-model update does not overwrite the hash with the default value
+    let mut member = members[0].clone();
+    member.login = "admin".to_string();
+    db.model::<Member>().filter("id", Op::Eq, 1).updates(&member).await?;
+
+    let (hash,): (String,) = db.raw("SELECT password_hash FROM member WHERE id = 1").fetch_one().await?;
+    assert_eq!(hash, "s3cret");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_omit_attribute_column_reachable_through_select() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+    db.model::<Member>()
+        .insert(&Member { id: 1, login: "root".to_string(), password_hash: "s3cret".to_string() })
+        .await?;
+
+    let member: Member = db.model::<Member>().select("id, login, password_hash").first().await?;
+    assert_eq!(member.password_hash, "s3cret");
+
+    let member: Member = db.model::<Member>().select("*").first().await?;
+    assert_eq!(member.password_hash, "s3cret");
+
+    let creds: Credentials = db.model::<Member>().select("login, password_hash").first().await?;
+    assert_eq!(creds.login, "root");
+    assert_eq!(creds.password_hash, "s3cret");
+
+    // A DTO that declares the field asks for the column by name
+    let creds: Credentials = db.model::<Member>().first().await?;
+    assert_eq!(creds.password_hash, "s3cret");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_include_brings_back_omitted_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+    db.model::<Member>()
+        .insert(&Member { id: 1, login: "root".to_string(), password_hash: "s3cret".to_string() })
+        .await?;
+
+    let member: Member = db.model::<Member>().include(member_fields::PASSWORD_HASH).first().await?;
+    assert_eq!(member.password_hash, "s3cret");
+
+    let members: Vec<Member> = db.model::<Member>().with_omitted().scan().await?;
+    assert_eq!(members[0].password_hash, "s3cret");
+
+    // include() also reverses a per-query omit()
+    let sql = db.model::<Member>().omit("login").include("login").to_sql();
+    assert!(sql.contains("\"login\""), "unexpected SQL: {}", sql);
+    assert!(!sql.contains("password_hash"));
+    Ok(())
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Badge {
+    #[orm(primary_key)]
+    id: i32,
+    member_id: i32,
+    label: String,
+}
+
+#[tokio::test]
+async fn test_positional_decoding_skips_unselected_omitted_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().register::<Badge>().run().await?;
+    db.model::<Member>()
+        .insert(&Member { id: 1, login: "root".to_string(), password_hash: "s3cret".to_string() })
+        .await?;
+    db.model::<Badge>().insert(&Badge { id: 7, member_id: 1, label: "founder".to_string() }).await?;
+
+    let rows: Vec<(Member, Badge)> =
+        db.model::<Member>().join("badge", "badge.member_id = member.id").scan().await?;
+    assert_eq!(rows.len(), 1);
+    assert_eq!((rows[0].0.login.as_str(), rows[0].0.password_hash.as_str()), ("root", ""));
+    assert_eq!(rows[0].1, Badge { id: 7, member_id: 1, label: "founder".to_string() });
+
+    let rows: Vec<(Member, Badge)> = db
+        .model::<Member>()
+        .join("badge", "badge.member_id = member.id")
+        .include("password_hash")
+        .scan()
+        .await?;
+    assert_eq!(rows[0].0.password_hash, "s3cret");
+    assert_eq!(rows[0].1.label, "founder");
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use bottle_orm::{Database, Model, Op};
+use futures::StreamExt;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Widget {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn test_on_query_reports_every_statement() -> Result<(), Box<dyn std::error::Error>> {
+    let logged: Arc<Mutex<Vec<(String, Duration)>>> = Arc::default();
+    let sink = logged.clone();
+    let db = Database::builder()
+        .max_connections(1)
+        .on_query(move |sql, elapsed| sink.lock().unwrap().push((sql.to_string(), elapsed)))
+        .connect("sqlite::memory:")
+        .await?;
+    db.migrator().register::<Widget>().run().await?;
+    // Migrations report their schema reads as well as their DDL
+    let migration: Vec<String> = logged.lock().unwrap().drain(..).map(|(sql, _)| sql).collect();
+    assert!(migration.iter().any(|sql| sql.starts_with("CREATE TABLE")), "{:?}", migration);
+    assert!(migration.iter().any(|sql| sql.contains("sqlite_master") || sql.starts_with("PRAGMA")), "{:?}", migration);
+
+    db.model::<Widget>().insert(&Widget { id: 1, name: "gear".to_string() }).await?;
+    let found: Vec<Widget> = db.model::<Widget>().filter("name", Op::Eq, "gear").scan().await?;
+    assert_eq!(found.len(), 1);
+
+    let tx = db.begin().await?;
+    tx.raw("DELETE FROM widget WHERE id = 99").execute().await?;
+    tx.commit().await?;
+
+    let streamed: Vec<_> = db.model::<Widget>().scan_stream::<Widget>().collect().await;
+    assert_eq!(streamed.len(), 1);
+
+    let statements: Vec<String> = logged.lock().unwrap().iter().map(|(sql, _)| sql.clone()).collect();
+    assert_eq!(statements.len(), 4, "{:?}", statements);
+    assert!(statements[0].starts_with("INSERT INTO"));
+    assert!(statements[1].starts_with("SELECT") && statements[1].contains("WHERE"));
+    assert_eq!(statements[2], "DELETE FROM widget WHERE id = 99");
+    assert!(statements[3].starts_with("SELECT"));
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use bottle_orm::{Database, Model, OrderDir};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Player {
+    #[orm(primary_key)]
+    id: i32,
+    nick: String,
+    score: i64,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Player>().run().await?;
+    for (id, nick, score) in [(1, "ana", 30), (2, "bo", 50), (3, "cy", 30)] {
+        db.model::<Player>().insert(&Player { id, nick: nick.to_string(), score }).await?;
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_order_by_sorts_by_validated_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let players: Vec<Player> = db
+        .model::<Player>()
+        .order_by(player_fields::SCORE, OrderDir::Desc)
+        .order_by("id", OrderDir::Desc)
+        .scan()
+        .await?;
+    assert_eq!(players.iter().map(|p| p.id).collect::<Vec<_>>(), vec![2, 3, 1]);
+
+    let sql = db.model::<Player>().order_by("player.nick", OrderDir::Asc).to_sql();
+    assert!(sql.ends_with("ORDER BY \"player\".\"nick\" ASC"), "unexpected SQL: {}", sql);
+
+    let totals: Vec<(i64, i64)> = db
+        .model::<Player>()
+        .select("score, COUNT(*) AS players")
+        .group_by("score")
+        .order_by("players", OrderDir::Desc)
+        .scan()
+        .await?;
+    assert_eq!(totals[0], (30, 2));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_order_by_rejects_injection() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = db.model::<Player>().order_by("id; DROP TABLE player", OrderDir::Asc).scan::<Player>().await.unwrap_err();
+    assert!(err.to_string().contains("unknown column"), "unexpected error: {}", err);
+
+    let err = db.model::<Player>().order_by("password", OrderDir::Asc).first::<Player>().await.unwrap_err();
+    assert!(err.to_string().contains("'password'"));
+
+    let err = db.model::<Player>().order("id; DROP TABLE player").scan::<Player>().await.unwrap_err();
+    assert!(err.to_string().contains("Invalid ORDER BY"), "unexpected error: {}", err);
+
+    // The table is still there
+    assert_eq!(db.model::<Player>().count().await?, 3);
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use bottle_orm::{ColumnInfo, Model, Op};
+use std::collections::HashMap;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Shipment {
+    #[orm(primary_key)]
+    id: i32,
+    zone: String,
+    weight: f64,
+    #[orm(column = "carrier_code")]
+    carrier: String,
+    notes: Option<String>,
+    attempts: i32,
+}
+
+struct Parcel {
+    id: i32,
+    label: String,
+}
+
+impl Model for Parcel {
+    fn table_name() -> &'static str {
+        "parcel"
+    }
+
+    fn columns() -> Vec<ColumnInfo> {
+        vec![ColumnInfo::builder("id", "INTEGER").primary_key().build(), ColumnInfo::builder("label", "TEXT").build()]
+    }
+
+    fn column_names() -> Vec<String> {
+        vec!["id".to_string(), "label".to_string()]
+    }
+
+    fn active_columns() -> Vec<&'static str> {
+        vec!["id", "label"]
+    }
+
+    fn to_map(&self) -> HashMap<String, Option<String>> {
+        let mut map = HashMap::new();
+        map.insert("label".to_string(), Some(self.label.clone()));
+        map.insert("id".to_string(), Some(self.id.to_string()));
+        map.insert("extra".to_string(), None);
+        map
+    }
+}
+
+#[test]
+fn test_derived_pairs_follow_declaration_order() {
+    let shipment = Shipment {
+        id: 7,
+        zone: "north".to_string(),
+        weight: 2.5,
+        carrier: "ups".to_string(),
+        notes: None,
+        attempts: 1,
+    };
+
+    let pairs = shipment.to_ordered_pairs();
+    let names: Vec<&str> = pairs.iter().map(|(k, _)| k.as_str()).collect();
+    assert_eq!(names, ["id", "zone", "weight", "carrier_code", "notes", "attempts"]);
+    assert_eq!(pairs[3].1.as_deref(), Some("ups"));
+    assert_eq!(pairs[4].1, None);
+
+    // Same content as the map, only ordered.
+    let map = shipment.to_map();
+    assert_eq!(pairs.len(), map.len());
+    assert!(pairs.iter().all(|(k, v)| map.get(k) == Some(v)));
+}
+
+#[test]
+fn test_default_pairs_follow_columns_then_name() {
+    let parcel = Parcel { id: 3, label: "fragile".to_string() };
+    let pairs = parcel.to_ordered_pairs();
+    assert_eq!(
+        pairs,
+        vec![
+            ("id".to_string(), Some("3".to_string())),
+            ("label".to_string(), Some("fragile".to_string())),
+            ("extra".to_string(), None),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_insert_and_upsert_bind_values_to_their_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Shipment>().run().await?;
+
+    let mut shipment = Shipment {
+        id: 1,
+        zone: "north".to_string(),
+        weight: 2.5,
+        carrier: "ups".to_string(),
+        notes: Some("leave at door".to_string()),
+        attempts: 2,
+    };
+    db.model::<Shipment>().insert(&shipment).await?;
+    let stored: Shipment = db.model::<Shipment>().filter("id", Op::Eq, 1).first().await?;
+    assert_eq!(stored, shipment);
+
+    shipment.zone = "south".to_string();
+    shipment.attempts = 3;
+    db.model::<Shipment>().upsert(&shipment, &["id"], &[]).await?;
+    let stored: Shipment = db.model::<Shipment>().filter("id", Op::Eq, 1).first().await?;
+    assert_eq!(stored, shipment);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use bottle_orm::{Database, FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Customer {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Profile {
+    #[orm(primary_key)]
+    id: i32,
+    customer_id: i32,
+    bio: String,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct CustomerProfile {
+    name: Option<String>,
+    bio: Option<String>,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Customer>().register::<Profile>().run().await?;
+
+    db.model::<Customer>().insert(&Customer { id: 1, name: "ana".to_string() }).await?;
+    db.model::<Customer>().insert(&Customer { id: 2, name: "bo".to_string() }).await?;
+    db.model::<Profile>().insert(&Profile { id: 1, customer_id: 1, bio: "hello".to_string() }).await?;
+    // Profile of a customer that does not exist
+    db.model::<Profile>().insert(&Profile { id: 2, customer_id: 9, bio: "orphan".to_string() }).await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_left_join_keeps_parents_without_children() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let rows: Vec<CustomerProfile> = db
+        .model::<Customer>()
+        .left_join("profile", "profile.customer_id = customer.id")
+        .select("customer.name, profile.bio")
+        .order("customer.id ASC")
+        .scan_as()
+        .await?;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].bio.as_deref(), Some("hello"));
+    assert_eq!(rows[1].name.as_deref(), Some("bo"));
+    assert_eq!(rows[1].bio, None);
+
+    let inner: Vec<CustomerProfile> = db
+        .model::<Customer>()
+        .inner_join("profile", "profile.customer_id = customer.id")
+        .select("customer.name, profile.bio")
+        .scan_as()
+        .await?;
+    assert_eq!(inner.len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_right_and_full_join_keep_unmatched_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let right: Vec<CustomerProfile> = db
+        .model::<Customer>()
+        .right_join("profile", "profile.customer_id = customer.id")
+        .select("customer.name, profile.bio")
+        .order("profile.id ASC")
+        .scan_as()
+        .await?;
+    assert_eq!(right.len(), 2);
+    assert_eq!(right[1].name, None);
+    assert_eq!(right[1].bio.as_deref(), Some("orphan"));
+
+    let full: Vec<CustomerProfile> = db
+        .model::<Customer>()
+        .full_join("profile", "profile.customer_id = customer.id")
+        .select("customer.name, profile.bio")
+        .scan_as()
+        .await?;
+    assert_eq!(full.len(), 3);
+    assert!(full.iter().any(|r| r.name.as_deref() == Some("bo") && r.bio.is_none()));
+    assert!(full.iter().any(|r| r.name.is_none() && r.bio.as_deref() == Some("orphan")));
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use bottle_orm::{Database, Model, Pagination, ScrollPage};
+use serde::{Deserialize, Serialize};
+
+#[derive(Model, Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct Post {
+    #[orm(primary_key)]
+    id: i32,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_paginate_no_count_skips_count_query() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).count_queries(true).connect("sqlite::memory:").await?;
+    db.migrator().register::<Post>().run().await?;
+
+    let posts: Vec<Post> = (1..=5).map(|id| Post { id, title: format!("post {}", id) }).collect();
+    db.model::<Post>().batch_insert(&posts).await?;
+
+    let before = db.query_count();
+    let first: ScrollPage<Post> = Pagination::new(0, 2).order_by_primary_key().paginate_no_count(db.model::<Post>()).await?;
+    assert_eq!(db.query_count() - before, 1, "only the data query should run");
+    assert_eq!(first.data.iter().map(|p| p.id).collect::<Vec<_>>(), [1, 2]);
+    assert!(first.has_next);
+
+    let last: ScrollPage<Post> = Pagination::new(2, 2).order_by_primary_key().paginate_no_count(db.model::<Post>()).await?;
+    assert_eq!(last.data.iter().map(|p| p.id).collect::<Vec<_>>(), [5]);
+    assert!(!last.has_next);
+
+    // A page that is exactly full still knows it is the last one
+    let exact: ScrollPage<Post> = Pagination::new(0, 5).order_by_primary_key().paginate_no_count(db.model::<Post>()).await?;
+    assert_eq!(exact.data.len(), 5);
+    assert!(!exact.has_next);
+    Ok(())
+}
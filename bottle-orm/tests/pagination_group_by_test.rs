@@ -0,0 +1,60 @@
+use bottle_orm::{pagination::Pagination, FromAnyRow, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Sale {
+    #[orm(primary_key)]
+    id: i32,
+    region: String,
+    amount: i64,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct RegionTotal {
+    region: String,
+    total: i64,
+}
+
+#[tokio::test]
+async fn test_paginate_grouped_query_counts_groups() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Sale>().run().await?;
+
+    let sales = [("north", 10), ("north", 20), ("south", 5), ("east", 7), ("east", 3), ("east", 1), ("west", 2)];
+    for (i, (region, amount)) in sales.iter().enumerate() {
+        db.model::<Sale>()
+            .insert(&Sale { id: i as i32 + 1, region: region.to_string(), amount: *amount })
+            .await?;
+    }
+
+    let page = Pagination::new(0, 3)
+        .paginate_as::<Sale, _, RegionTotal>(
+            db.model::<Sale>()
+                .select("region, SUM(amount) AS total")
+                .group_by("region")
+                .order("region ASC"),
+        )
+        .await?;
+    assert_eq!(page.total, 4);
+    assert_eq!(page.total_pages, 2);
+    assert_eq!(page.data.len(), 3);
+    assert_eq!(page.data[0].region, "east");
+    assert_eq!(page.data[0].total, 11);
+
+    // Filters still bind correctly inside the wrapped count
+    let page = Pagination::new(0, 10)
+        .paginate_as::<Sale, _, RegionTotal>(
+            db.model::<Sale>()
+                .filter("amount", Op::Gte, 5)
+                .select("region, SUM(amount) AS total")
+                .group_by("region"),
+        )
+        .await?;
+    assert_eq!(page.total, 3);
+
+    // Ungrouped queries keep counting rows
+    let page = Pagination::new(0, 5).paginate::<Sale, _, Sale>(db.model::<Sale>()).await?;
+    assert_eq!(page.total, 7);
+    Ok(())
+}
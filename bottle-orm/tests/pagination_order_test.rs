@@ -0,0 +1,57 @@
+use bottle_orm::{pagination::Pagination, Database, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    code: String,
+    subject: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Ticket>().run().await?;
+    // Inserted out of key order, so an unordered scan does not follow the key.
+    for code in ["t4", "t1", "t5", "t2", "t3"] {
+        db.model::<Ticket>().insert(&Ticket { code: code.to_string(), subject: format!("subject {}", code) }).await?;
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_pages_are_ordered_by_primary_key() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let mut codes = Vec::new();
+    for page in 0..3 {
+        let result = Pagination::new(page, 2).order_by_primary_key().paginate::<Ticket, _, Ticket>(db.model::<Ticket>()).await?;
+        assert_eq!(result.total, 5);
+        codes.extend(result.data.into_iter().map(|t| t.code));
+    }
+    assert_eq!(codes, ["t1", "t2", "t3", "t4", "t5"]);
+
+    // The same page is identical on every request.
+    for _ in 0..3 {
+        let again = Pagination::new(1, 2).order_by_primary_key().paginate::<Ticket, _, Ticket>(db.model::<Ticket>()).await?;
+        let again: Vec<String> = again.data.into_iter().map(|t| t.code).collect();
+        assert_eq!(again, ["t3", "t4"]);
+    }
+
+    let rows: Vec<Ticket> = Pagination::new(0, 2).order_by_primary_key().apply(db.model::<Ticket>()).scan().await?;
+    assert_eq!(rows[0].code, "t1");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_explicit_order_is_kept() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let page = Pagination::new(0, 2)
+        .order_by_primary_key()
+        .paginate::<Ticket, _, Ticket>(db.model::<Ticket>().order("code DESC"))
+        .await?;
+    let codes: Vec<String> = page.data.into_iter().map(|t| t.code).collect();
+    assert_eq!(codes, ["t5", "t4"]);
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use bottle_orm::{BottleEnum, Model, Op, PgEnum};
+
+mod common;
+
+#[derive(BottleEnum, Debug, Clone, PartialEq)]
+#[bottle(pg_enum)]
+enum TicketStatus {
+    Open,
+    InProgress,
+    Closed,
+}
+
+#[derive(BottleEnum, Debug, Clone, PartialEq)]
+#[bottle(pg_enum = "ticket_priority_level")]
+enum TicketPriority {
+    Low,
+    High,
+}
+
+#[derive(Model, Debug, Clone, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(pg_enum)]
+    status: TicketStatus,
+    #[orm(pg_enum)]
+    priority: Option<TicketPriority>,
+}
+
+#[derive(BottleEnum, Debug, Clone, PartialEq)]
+#[bottle(pg_enum = "ticket_status")]
+enum TicketStatusV2 {
+    Open,
+    InProgress,
+    OnHold,
+    Closed,
+    Archived,
+}
+
+#[derive(Model, Debug, Clone, PartialEq)]
+#[orm(table = "ticket")]
+struct TicketV2 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(pg_enum)]
+    status: TicketStatusV2,
+    #[orm(pg_enum)]
+    priority: Option<TicketPriority>,
+}
+
+#[test]
+fn test_pg_enum_exposes_type_and_variants() {
+    assert_eq!(TicketStatus::SQL_TYPE, "\"ticket_status\"");
+    assert_eq!(TicketStatus::VARIANTS, ["open", "in_progress", "closed"]);
+    assert_eq!(TicketPriority::SQL_TYPE, "\"ticket_priority_level\"");
+    assert_eq!(Ticket::columns()[1].pg_enum, Some(TicketStatus::VARIANTS));
+}
+
+#[tokio::test]
+async fn test_pg_enum_is_text_on_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    assert_eq!(
+        db.create_table_sql::<Ticket>()?,
+        vec!["CREATE TABLE IF NOT EXISTS \"ticket\" (\"id\" INTEGER PRIMARY KEY, \"status\" TEXT NOT NULL, \"priority\" TEXT)".to_string()]
+    );
+
+    db.migrator().register::<Ticket>().run().await?;
+    db.model::<Ticket>().insert(&Ticket { id: 1, status: TicketStatus::InProgress, priority: None }).await?;
+
+    let found: Ticket = db.model::<Ticket>().filter("status", Op::Eq, "in_progress").first().await?;
+    assert_eq!(found.status, TicketStatus::InProgress);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_native_enum_column() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS \"ticket\"").execute().await?;
+    db.raw("DROP TYPE IF EXISTS \"ticket_status\"").execute().await?;
+    db.raw("DROP TYPE IF EXISTS \"ticket_priority_level\"").execute().await?;
+
+    db.migrator().register::<Ticket>().run().await?;
+    // Creating the type again is a no-op
+    db.create_table::<Ticket>().await?;
+
+    let (udt_name,): (String,) = db
+        .raw("SELECT udt_name::TEXT FROM information_schema.columns WHERE table_name = 'ticket' AND column_name = 'status'")
+        .fetch_one()
+        .await?;
+    assert_eq!(udt_name, "ticket_status");
+
+    db.model::<Ticket>().insert(&Ticket { id: 1, status: TicketStatus::Open, priority: Some(TicketPriority::High) }).await?;
+    db.model::<Ticket>().insert(&Ticket { id: 2, status: TicketStatus::Closed, priority: None }).await?;
+
+    let found: Vec<Ticket> = db.model::<Ticket>().filter("status", Op::In, vec!["open", "in_progress"]).scan().await?;
+    assert_eq!(found, vec![Ticket { id: 1, status: TicketStatus::Open, priority: Some(TicketPriority::High) }]);
+
+    // Labels outside the type are rejected by the database
+    assert!(db.raw("INSERT INTO ticket (id, status) VALUES (3, 'archived')").execute().await.is_err());
+
+    // New variants are added to the existing type, in declaration order
+    assert_eq!(
+        db.sync_table_sql::<TicketV2>().await?,
+        vec![
+            "ALTER TYPE \"ticket_status\" ADD VALUE IF NOT EXISTS 'on_hold' AFTER 'in_progress'".to_string(),
+            "ALTER TYPE \"ticket_status\" ADD VALUE IF NOT EXISTS 'archived' AFTER 'closed'".to_string(),
+        ]
+    );
+    db.migrator().register::<TicketV2>().run().await?;
+    assert!(db.sync_table_sql::<TicketV2>().await?.is_empty());
+    let (labels,): (String,) = db
+        .raw("SELECT string_agg(enumlabel::TEXT, ',' ORDER BY enumsortorder) FROM pg_enum JOIN pg_type t ON t.oid = enumtypid WHERE t.typname = 'ticket_status'")
+        .fetch_one()
+        .await?;
+    assert_eq!(labels, "open,in_progress,on_hold,closed,archived");
+    db.model::<TicketV2>().insert(&TicketV2 { id: 3, status: TicketStatusV2::Archived, priority: None }).await?;
+
+    // A table-qualified column is cast to its enum type as well
+    let found: Vec<TicketV2> = db.model::<TicketV2>().filter("ticket.status", Op::Eq, "archived").scan().await?;
+    assert_eq!(found.iter().map(|t| t.id).collect::<Vec<_>>(), [3]);
+
+    db.raw("DROP TABLE \"ticket\"").execute().await?;
+    db.raw("DROP TYPE \"ticket_status\"").execute().await?;
+    db.raw("DROP TYPE \"ticket_priority_level\"").execute().await?;
+    Ok(())
+}
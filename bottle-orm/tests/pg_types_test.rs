@@ -0,0 +1,81 @@
+#![cfg(feature = "postgres-types")]
+
+use bottle_orm::{
+    pg_types::{Point, TsRange},
+    Model,
+};
+use chrono::{TimeZone, Utc};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Booking {
+    #[orm(primary_key)]
+    id: i32,
+    during: TsRange,
+    spot: Option<Point>,
+}
+
+#[test]
+fn test_range_and_point_text_roundtrip() {
+    let range: TsRange = r#"["2024-01-01 00:00:00+00","2024-02-01 00:00:00+00")"#.parse().unwrap();
+    assert_eq!(
+        range,
+        TsRange::new(
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()
+        )
+    );
+    assert_eq!(range.to_string().parse::<TsRange>().unwrap(), range);
+
+    let open: TsRange = r#"(,"2024-02-01 00:00:00+00"]"#.parse().unwrap();
+    assert_eq!((open.start, open.start_inclusive, open.end_inclusive), (None, false, true));
+    assert!("empty".parse::<TsRange>().is_err());
+
+    assert_eq!("(1.5,-2)".parse::<Point>().unwrap(), Point::new(1.5, -2.0));
+    assert_eq!(Point::new(3.0, 4.5).to_string(), "(3,4.5)");
+    assert_eq!(Booking::columns().iter().map(|c| c.sql_type).collect::<Vec<_>>(), ["INTEGER", "TSTZRANGE", "POINT"]);
+}
+
+#[tokio::test]
+async fn test_range_contains_is_rejected_outside_postgres() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    let err = db.model::<Booking>().range_contains("during", Utc::now()).scan::<Booking>().await.unwrap_err();
+    assert!(err.to_string().contains("requires PostgreSQL"), "unexpected error: {}", err);
+
+    db.migrator().register::<Booking>().run().await?;
+    let err = db.model::<Booking>().range_contains("during", Utc::now()).delete().await.unwrap_err();
+    assert!(err.to_string().contains("requires PostgreSQL"), "unexpected error: {}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_range_contains_timestamp() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS \"booking\"").execute().await?;
+    db.migrator().register::<Booking>().run().await?;
+
+    let day = |d| Utc.with_ymd_and_hms(2024, 1, d, 0, 0, 0).unwrap();
+    db.model::<Booking>()
+        .insert(&Booking { id: 1, during: TsRange::new(day(1), day(10)), spot: Some(Point::new(1.0, 2.0)) })
+        .await?;
+    db.model::<Booking>().insert(&Booking { id: 2, during: TsRange::new(day(10), day(20)), spot: None }).await?;
+
+    let hits: Vec<Booking> = db.model::<Booking>().range_contains("during", day(5)).scan().await?;
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].during, TsRange::new(day(1), day(10)));
+    assert_eq!(hits[0].spot, Some(Point::new(1.0, 2.0)));
+
+    // Upper bounds are exclusive, so the boundary belongs to the second booking only.
+    let hits: Vec<Booking> = db.model::<Booking>().range_contains("during", day(10)).scan().await?;
+    assert_eq!(hits.iter().map(|b| b.id).collect::<Vec<_>>(), [2]);
+
+    // A misspelled column fails the delete instead of dropping the filter.
+    assert!(db.model::<Booking>().range_contains("durng", day(5)).delete().await.is_err());
+    assert_eq!(db.model::<Booking>().count().await?, 2);
+
+    db.raw("DROP TABLE \"booking\"").execute().await?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+mod common;
+
+
+#[tokio::test]
+async fn test_ping_open_and_closed_pool() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    db.ping().await?;
+    assert!(db.is_healthy().await);
+
+    db.close().await;
+    assert!(db.ping().await.is_err());
+    assert!(!db.is_healthy().await);
+    Ok(())
+}
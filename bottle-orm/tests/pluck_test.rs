@@ -0,0 +1,43 @@
+use bottle_orm::{Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    username: String,
+    karma: i64,
+    bio: Option<String>,
+}
+
+#[tokio::test]
+async fn test_pluck_returns_single_column_in_order() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+
+    for (id, username, karma, bio) in [(1, "carol", 5, None), (2, "alice", 12, Some("hi")), (3, "bob", 7, None)] {
+        db.model::<Account>()
+            .insert(&Account { id, username: username.to_string(), karma, bio: bio.map(str::to_string) })
+            .await?;
+    }
+
+    let names = db.model::<Account>().order("username ASC").pluck::<String>("username").await?;
+    assert_eq!(names, vec!["alice", "bob", "carol"]);
+
+    let karma: Vec<i64> =
+        db.model::<Account>().filter("karma", Op::Gt, 6).order("karma DESC").pluck(account_fields::KARMA).await?;
+    assert_eq!(karma, vec![12, 7]);
+
+    let bios: Vec<Option<String>> = db.model::<Account>().order("id ASC").pluck("bio").await?;
+    assert_eq!(bios, vec![None, Some("hi".to_string()), None]);
+
+    let none: Vec<String> = db.model::<Account>().filter("id", Op::Gt, 10).pluck("username").await?;
+    assert!(none.is_empty());
+
+    let karma: Vec<i32> = db.model::<Account>().order("id ASC").pluck("account.karma").await?;
+    assert_eq!(karma.len(), 3);
+    let err = db.model::<Account>().pluck::<String>("username FROM account; --").await.unwrap_err();
+    assert!(err.to_string().contains("unknown column"), "unexpected error: {}", err);
+    Ok(())
+}
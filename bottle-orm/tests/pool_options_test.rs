@@ -0,0 +1,31 @@
+use bottle_orm::{Database, Error};
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_acquire_timeout_fails_fast_on_saturated_pool() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder()
+        .max_connections(1)
+        .acquire_timeout(Duration::from_millis(100))
+        .idle_timeout(Duration::from_secs(60))
+        .connect("sqlite::memory:")
+        .await?;
+
+    // The open transaction holds the only connection
+    let tx = db.begin().await?;
+
+    let started = Instant::now();
+    let result = db.raw("SELECT 1").execute().await;
+    assert!(matches!(result, Err(Error::DatabaseError(sqlx::Error::PoolTimedOut))), "got {:?}", result);
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    tx.rollback().await?;
+    db.raw("SELECT 1").execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_min_connections_connects() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().min_connections(1).max_connections(2).connect("sqlite::memory:").await?;
+    db.raw("SELECT 1").execute().await?;
+    Ok(())
+}
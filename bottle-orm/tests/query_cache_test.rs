@@ -0,0 +1,139 @@
+use bottle_orm::{database::Connection, Database, Model, Op};
+use std::time::Duration;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Role {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Role>().run().await?;
+    db.model::<Role>().insert(&Role { id: 1, name: "admin".to_string() }).await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_cached_read_skips_database_within_ttl() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let ttl = Duration::from_secs(60);
+
+    let roles: Vec<Role> = db.model::<Role>().cache(ttl).scan().await?;
+    assert_eq!(roles.len(), 1);
+
+    // Raw SQL bypasses invalidation, so a cached read still sees the old rows
+    db.raw("INSERT INTO role (id, name) VALUES (2, 'editor')").execute().await?;
+    let cached: Vec<Role> = db.model::<Role>().cache(ttl).scan().await?;
+    assert_eq!(cached, roles);
+    assert_eq!(db.model::<Role>().cache(ttl).count().await?, 2, "different SQL is a different entry");
+
+    // Uncached reads always hit the database
+    let fresh: Vec<Role> = db.model::<Role>().scan().await?;
+    assert_eq!(fresh.len(), 2);
+
+    // Different arguments are different entries
+    let admin: Role = db.model::<Role>().filter("id", Op::Eq, 1).cache(ttl).first().await?;
+    let editor: Role = db.model::<Role>().filter("id", Op::Eq, 2).cache(ttl).first().await?;
+    assert_eq!((admin.name.as_str(), editor.name.as_str()), ("admin", "editor"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_invalidated_by_writes_and_expiry() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let ttl = Duration::from_secs(60);
+
+    let _: Vec<Role> = db.model::<Role>().cache(ttl).scan().await?;
+    db.model::<Role>().insert(&Role { id: 2, name: "editor".to_string() }).await?;
+    let roles: Vec<Role> = db.model::<Role>().cache(ttl).scan().await?;
+    assert_eq!(roles.len(), 2);
+
+    db.model::<Role>().filter("id", Op::Eq, 2).update("name", "writer").await?;
+    let roles: Vec<Role> = db.model::<Role>().cache(ttl).order("id ASC").scan().await?;
+    assert_eq!(roles[1].name, "writer");
+
+    let short = Duration::from_millis(20);
+    let _: Vec<Role> = db.model::<Role>().cache(short).scan().await?;
+    db.raw("DELETE FROM role WHERE id = 2").execute().await?;
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    let roles: Vec<Role> = db.model::<Role>().cache(short).scan().await?;
+    assert_eq!(roles.len(), 1, "expired entries are read again");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transactions_do_not_leak_into_the_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let ttl = Duration::from_secs(60);
+
+    // Rolled back rows are never cached, and the cached entry stays valid
+    let tx = db.begin().await?;
+    tx.model::<Role>().insert(&Role { id: 2, name: "editor".to_string() }).await?;
+    assert_eq!(tx.model::<Role>().cache(ttl).count().await?, 2);
+    tx.rollback().await?;
+    assert_eq!(db.model::<Role>().cache(ttl).count().await?, 1);
+
+    // Writes drop cached reads only once they are committed
+    let tx = db.begin().await?;
+    tx.model::<Role>().insert(&Role { id: 3, name: "viewer".to_string() }).await?;
+    tx.commit().await?;
+    assert_eq!(db.model::<Role>().cache(ttl).count().await?, 2);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Permission {
+    #[orm(primary_key)]
+    id: i32,
+    role_id: i32,
+}
+
+#[tokio::test]
+async fn test_write_to_joined_table_invalidates() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    db.migrator().register::<Permission>().run().await?;
+    let ttl = Duration::from_secs(60);
+
+    let joined = || db.model::<Role>().join("permission", "permission.role_id = role.id").cache(ttl);
+    assert_eq!(joined().count().await?, 0);
+    db.model::<Permission>().insert(&Permission { id: 1, role_id: 1 }).await?;
+    assert_eq!(joined().count().await?, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_write_to_subquery_table_invalidates() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    db.migrator().register::<Permission>().run().await?;
+    let ttl = Duration::from_secs(60);
+
+    let granted = || {
+        let role_ids = db.model::<Permission>().select("role_id");
+        db.model::<Role>().where_in_subquery("id", role_ids).cache(ttl)
+    };
+    assert_eq!(granted().count().await?, 0);
+    db.model::<Permission>().insert(&Permission { id: 1, role_id: 1 }).await?;
+    assert_eq!(granted().count().await?, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_expired_entries_are_swept_on_insert() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let cache = db.query_cache().expect("databases have a cache");
+    let short = Duration::from_millis(20);
+
+    for id in 1..=5 {
+        let _: Option<Role> = db.model::<Role>().filter("id", Op::Eq, id).cache(short).first_optional().await?;
+    }
+    assert_eq!(cache.len(), 5);
+
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    let _: Vec<Role> = db.model::<Role>().cache(Duration::from_secs(60)).scan().await?;
+    assert_eq!(cache.len(), 1, "expired keys that are never read again are dropped");
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use bottle_orm::{Database, Model, Op};
+use std::time::Duration;
+
+mod common;
+
+#[derive(Model, Debug, Clone, PartialEq)]
+struct Owner {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+
+    #[orm(has_many = "Pet", foreign_key = "owner_id")]
+    pets: Vec<Pet>,
+}
+
+#[derive(Model, Debug, Clone, PartialEq)]
+struct Pet {
+    #[orm(primary_key)]
+    id: i32,
+    owner_id: i32,
+    name: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).count_queries(true).connect("sqlite::memory:").await?;
+    db.create_table::<Owner>().await?;
+    db.create_table::<Pet>().await?;
+
+    for id in 1..=3 {
+        db.model::<Owner>().insert(&Owner { id, name: format!("owner{}", id), pets: vec![] }).await?;
+        for n in 0..2 {
+            let pet_id = id * 10 + n;
+            db.model::<Pet>().insert(&Pet { id: pet_id, owner_id: id, name: format!("pet{}", pet_id) }).await?;
+        }
+    }
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_eager_loading_runs_two_queries() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    db.reset_query_count();
+
+    let owners = db.model::<Owner>().with("pets").order("id ASC").scan_with().await?;
+    assert_eq!(owners.len(), 3);
+    assert!(owners.iter().all(|o| o.pets.len() == 2));
+    assert_eq!(db.query_count(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_count_covers_transactions_and_skips_cache_hits() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    db.reset_query_count();
+
+    let _: Vec<Pet> = db.model::<Pet>().filter("owner_id", Op::Eq, 1).cache(Duration::from_secs(60)).scan().await?;
+    let _: Vec<Pet> = db.model::<Pet>().filter("owner_id", Op::Eq, 1).cache(Duration::from_secs(60)).scan().await?;
+    assert_eq!(db.query_count(), 1);
+
+    let tx = db.begin().await?;
+    tx.model::<Pet>().filter("id", Op::Eq, 10).update("name", "rex".to_string()).await?;
+    tx.commit().await?;
+    assert_eq!(db.query_count(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_count_is_zero_when_disabled() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.create_table::<Pet>().await?;
+    let _: Vec<Pet> = db.model::<Pet>().scan().await?;
+    assert_eq!(db.query_count(), 0);
+    Ok(())
+}
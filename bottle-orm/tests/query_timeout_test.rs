@@ -0,0 +1,56 @@
+use bottle_orm::{Database, Error, Model};
+use std::time::{Duration, Instant};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Job {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+// Streams tens of millions of rows, far more than a short timeout allows.
+const SLOW_SQL: &str = "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 50000000) SELECT x FROM c";
+
+fn is_timeout(err: &Error) -> bool {
+    matches!(err, Error::DatabaseError(sqlx::Error::Io(io)) if io.kind() == std::io::ErrorKind::TimedOut)
+}
+
+#[tokio::test]
+async fn test_default_timeout_cancels_slow_query() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder()
+        .max_connections(1)
+        .default_query_timeout(Duration::from_millis(200))
+        .connect("sqlite::memory:")
+        .await?;
+    db.migrator().register::<Job>().run().await?;
+
+    // Fast statements are unaffected.
+    db.model::<Job>().insert(&Job { id: 1, name: "nightly".to_string() }).await?;
+    assert_eq!(db.model::<Job>().count().await?, 1);
+
+    let started = Instant::now();
+    let err = db.raw(SLOW_SQL).fetch_all::<(i64,)>().await.unwrap_err();
+    assert!(is_timeout(&err), "expected a timeout, got {:?}", err);
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    let tx = db.begin().await?;
+    let err = tx.raw(SLOW_SQL).fetch_all::<(i64,)>().await.unwrap_err();
+    assert!(is_timeout(&err), "transactions inherit the default, got {:?}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_per_query_timeout_overrides_default() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Job>().run().await?;
+
+    let err = db.raw(SLOW_SQL).timeout(Duration::from_millis(200)).fetch_all::<(i64,)>().await.unwrap_err();
+    assert!(is_timeout(&err), "expected a timeout, got {:?}", err);
+
+    // The override applies to that query only.
+    let jobs: Vec<Job> = db.model::<Job>().timeout(Duration::from_secs(30)).scan().await?;
+    assert!(jobs.is_empty());
+    Ok(())
+}
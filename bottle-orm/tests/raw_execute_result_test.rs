@@ -0,0 +1,54 @@
+use bottle_orm::{Database, ExecuteResult};
+
+mod common;
+
+async fn insert_two(db: &Database) -> Result<(ExecuteResult, ExecuteResult), Box<dyn std::error::Error>> {
+    let first = db.raw("INSERT INTO note (body) VALUES (?)").bind("first").execute_result().await?;
+    let second = db.raw("INSERT INTO note (body) VALUES (?)").bind("second").execute_result().await?;
+    Ok((first, second))
+}
+
+#[tokio::test]
+async fn test_sqlite_raw_insert_reports_last_insert_id() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.raw("CREATE TABLE note (id INTEGER PRIMARY KEY AUTOINCREMENT, body TEXT NOT NULL)").execute().await?;
+
+    let (first, second) = insert_two(&db).await?;
+    assert_eq!(first, ExecuteResult { rows_affected: 1, last_insert_id: Some(1) });
+    assert_eq!(second.last_insert_id, Some(2));
+
+    // The rowid of an earlier insert is not reported for other statements
+    let updated = db.raw("UPDATE note SET body = 'edited'").execute_result().await?;
+    assert_eq!(updated, ExecuteResult { rows_affected: 2, last_insert_id: None });
+    let skipped = db.raw("INSERT INTO note (body) SELECT body FROM note WHERE id = 0").execute_result().await?;
+    assert_eq!(skipped, ExecuteResult { rows_affected: 0, last_insert_id: None });
+
+    // Inserts that do not start with INSERT are reported too
+    let cte = db
+        .raw("WITH src (body) AS (SELECT 'cte') INSERT INTO note (body) SELECT body FROM src")
+        .execute_result()
+        .await?;
+    assert_eq!(cte, ExecuteResult { rows_affected: 1, last_insert_id: Some(3) });
+    let replaced = db.raw("REPLACE INTO note (id, body) VALUES (1, 'replaced')").execute_result().await?;
+    assert_eq!(replaced.last_insert_id, Some(1));
+
+    let tx = db.begin().await?;
+    let fourth = tx.raw("INSERT INTO note (body) VALUES (?)").bind("fourth").execute_result().await?;
+    assert_eq!(fourth.last_insert_id, Some(4));
+    tx.commit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_raw_insert_reports_last_insert_id() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::mysql().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS note").execute().await?;
+    db.raw("CREATE TABLE note (id BIGINT AUTO_INCREMENT PRIMARY KEY, body VARCHAR(64) NOT NULL)").execute().await?;
+
+    let (first, second) = insert_two(&db).await?;
+    assert_eq!(first.rows_affected, 1);
+    assert_eq!(second.last_insert_id, first.last_insert_id.map(|id| id + 1));
+    Ok(())
+}
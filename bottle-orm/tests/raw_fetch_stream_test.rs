@@ -0,0 +1,49 @@
+use futures::{StreamExt, TryStreamExt};
+
+mod common;
+
+#[tokio::test]
+async fn test_raw_fetch_stream_counts_generated_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let mut rows = db
+        .raw("WITH RECURSIVE series(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM series WHERE n < ?) SELECT n FROM series")
+        .bind(20_000)
+        .fetch_stream::<(i64,)>();
+    let (mut seen, mut total) = (0usize, 0i64);
+    while let Some((n,)) = rows.try_next().await? {
+        seen += 1;
+        total += n;
+    }
+    drop(rows);
+    assert_eq!(seen, 20_000);
+    assert_eq!(total, 20_000 * 20_001 / 2);
+
+    // Dropping early frees the single connection; statement errors arrive through the stream
+    let first: Vec<(i64,)> = db.raw("SELECT 1 UNION ALL SELECT 2").fetch_stream::<(i64,)>().take(1).try_collect().await?;
+    assert_eq!(first, vec![(1,)]);
+    assert!(db.raw("SELECT * FROM missing_table").fetch_stream::<(i64,)>().try_next().await.is_err());
+    assert!(db.raw("SELECT :n").fetch_stream::<(i64,)>().try_next().await.is_err());
+
+    let tx = db.begin().await?;
+    let (sum,): (i64,) = tx.raw("SELECT :n + :n").bind_named("n", 2).fetch_one().await?;
+    let streamed: Vec<(i64,)> = tx.raw("SELECT :n").bind_named("n", sum).fetch_stream::<(i64,)>().try_collect().await?;
+    assert_eq!(streamed, vec![(4,)]);
+    tx.commit().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_raw_fetch_stream() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+
+    let count = db
+        .raw("SELECT n::BIGINT FROM generate_series(1, 50000) AS n")
+        .fetch_stream::<(i64,)>()
+        .try_fold(0usize, |seen, _| async move { Ok(seen + 1) })
+        .await?;
+    assert_eq!(count, 50_000);
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use bottle_orm::{Database, Error};
+
+mod common;
+
+async fn check_named_params(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    db.raw("DROP TABLE IF EXISTS review").execute().await?;
+    db.raw("CREATE TABLE review (id INTEGER PRIMARY KEY, author_id INTEGER NOT NULL, reviewer_id INTEGER NOT NULL, note TEXT NOT NULL)")
+        .execute()
+        .await?;
+    db.raw("INSERT INTO review (id, author_id, reviewer_id, note) VALUES (1, 7, 2, 'a:b'), (2, 3, 7, 'x'), (3, 3, 4, 'y')")
+        .execute()
+        .await?;
+
+    // `:id` appears twice but is bound once; the quoted ':b' is not a placeholder
+    let ids: Vec<(i32,)> = db
+        .raw("SELECT id FROM review WHERE (author_id = :id OR reviewer_id = :id) AND note <> ':b' AND note <> :skip ORDER BY id")
+        .bind_named("id", 7)
+        .bind_named(":skip", "x")
+        .fetch_all()
+        .await?;
+    assert_eq!(ids, vec![(1,)]);
+
+    let updated = db.raw("UPDATE review SET note = :note WHERE author_id = :author").bind_named("author", 3).bind_named("note", "z").execute().await?;
+    assert_eq!(updated, 2);
+
+    let missing = db.raw("SELECT id FROM review WHERE author_id = :author").fetch_all::<(i32,)>().await;
+    assert!(missing.is_err());
+    let err = db.raw("SELECT id FROM review WHERE author_id = :author AND id = :id").bind_named("author", 3).fetch_all::<(i32,)>().await.unwrap_err();
+    assert!(matches!(err, Error::InvalidArgument(ref msg) if msg.contains(":id")), "unexpected error: {}", err);
+    let err = db.raw("SELECT id FROM review WHERE author_id = ?").bind(3).bind_named("id", 1).fetch_all::<(i32,)>().await.unwrap_err();
+    assert!(matches!(err, Error::InvalidArgument(_)), "unexpected error: {}", err);
+
+    // Names inside comments are not placeholders
+    let ids: Vec<(i32,)> = db
+        .raw("SELECT id FROM review -- filter on :author\nWHERE author_id = :author /* not :id */ ORDER BY id")
+        .bind_named("author", 3)
+        .fetch_all()
+        .await?;
+    assert_eq!(ids, vec![(2,), (3,)]);
+
+    db.raw("DROP TABLE review").execute().await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sqlite_named_params() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    check_named_params(&db).await
+}
+
+#[tokio::test]
+async fn test_postgres_named_params() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    check_named_params(&db).await?;
+
+    // `::` casts are left alone
+    let (value,): (String,) = db.raw("SELECT :v::TEXT").bind_named("v", 5).fetch_one().await?;
+    assert_eq!(value, "5");
+
+    // Dollar-quoted bodies are copied as written
+    let (value,): (String,) = db.raw("SELECT $$:v$$ || $tag$ :w $tag$ || :v::TEXT").bind_named("v", 5).fetch_one().await?;
+    assert_eq!(value, ":v :w 5");
+    Ok(())
+}
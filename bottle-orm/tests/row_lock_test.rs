@@ -0,0 +1,77 @@
+use bottle_orm::{Database, Drivers, Model, Pagination, QueryBuilder};
+use heck::ToSnakeCase;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    balance: i64,
+}
+
+fn postgres_query(db: &Database) -> QueryBuilder<Account, Database> {
+    let columns = Account::active_columns().iter().map(|c| c.to_snake_case()).collect();
+    QueryBuilder::new(db.clone(), Drivers::Postgres, Account::table_name(), Account::columns(), columns)
+}
+
+#[tokio::test]
+async fn test_row_lock_clauses() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let sql = postgres_query(&db).equals("id", 1).limit(1).for_update().to_sql();
+    assert!(sql.ends_with("LIMIT $2 FOR UPDATE"), "{}", sql);
+    let sql = postgres_query(&db).for_share().to_sql();
+    assert!(sql.ends_with(" FOR SHARE"), "{}", sql);
+    let sql = postgres_query(&db).for_update().skip_locked().to_sql();
+    assert!(sql.ends_with(" FOR UPDATE SKIP LOCKED"), "{}", sql);
+    assert!(!postgres_query(&db).skip_locked().to_sql().contains("SKIP LOCKED"));
+
+    // SQLite leaves the clause out, so the locking read still runs
+    db.migrator().register::<Account>().run().await?;
+    db.model::<Account>().insert(&Account { id: 1, balance: 100 }).await?;
+    assert!(!db.model::<Account>().for_update().to_sql().contains("FOR UPDATE"));
+
+    let tx = db.begin().await?;
+    let account: Account = tx.model::<Account>().equals("id", 1).for_update().first().await?;
+    tx.model::<Account>().equals("id", 1).update("balance", account.balance - 30).await?;
+    tx.commit().await?;
+
+    let account: Account = db.model::<Account>().equals("id", 1).first().await?;
+    assert_eq!(account.balance, 70);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_row_lock_rejects_unions() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+
+    let err = db.model::<Account>().for_update().union(db.model::<Account>()).scan::<Account>().await.unwrap_err();
+    assert!(err.to_string().contains("cannot be combined with UNION"), "unexpected error: {}", err);
+    let err = db.model::<Account>().union_all(db.model::<Account>().for_share()).scan::<Account>().await.unwrap_err();
+    assert!(err.to_string().contains("cannot be combined with UNION ALL"), "unexpected error: {}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_postgres_locked_counts_and_pages() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::postgres().await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS \"account\"").execute().await?;
+    db.migrator().register::<Account>().run().await?;
+    db.model::<Account>().batch_insert(&[Account { id: 1, balance: 100 }, Account { id: 2, balance: 50 }]).await?;
+
+    // Aggregates leave the lock out; the page query keeps it
+    let tx = db.begin().await?;
+    assert_eq!(tx.model::<Account>().for_update().count().await?, 2);
+    assert_eq!(tx.model::<Account>().for_update().max::<i64>("balance").await?, 100);
+    assert!(tx.model::<Account>().for_update().exists().await?);
+    let page: bottle_orm::pagination::Paginated<Account> = Pagination::new(0, 1).paginate(tx.model::<Account>().order("id ASC").for_update()).await?;
+    assert_eq!((page.total, page.data.len()), (2, 1));
+    tx.commit().await?;
+
+    db.raw("DROP TABLE \"account\"").execute().await?;
+    Ok(())
+}
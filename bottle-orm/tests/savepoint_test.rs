@@ -0,0 +1,54 @@
+use bottle_orm::{Database, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    email: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_failed_savepoint_is_rolled_back_and_rest_committed() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let tx = db.begin().await?;
+    tx.model::<Member>().insert(&Member { id: 1, email: "a@example.com".to_string() }).await?;
+
+    // The second step inserts a row, then fails on a duplicate email.
+    tx.savepoint("second_step").await?;
+    tx.model::<Member>().insert(&Member { id: 2, email: "b@example.com".to_string() }).await?;
+    let duplicate = tx.model::<Member>().insert(&Member { id: 3, email: "a@example.com".to_string() }).await;
+    assert!(duplicate.is_err());
+    tx.rollback_to("second_step").await?;
+    tx.release_savepoint("second_step").await?;
+
+    tx.savepoint("third_step").await?;
+    tx.model::<Member>().insert(&Member { id: 4, email: "d@example.com".to_string() }).await?;
+    tx.release_savepoint("third_step").await?;
+    tx.commit().await?;
+
+    let ids: Vec<i32> = db.model::<Member>().order("id ASC").scan::<Member>().await?.into_iter().map(|m| m.id).collect();
+    assert_eq!(ids, [1, 4]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_invalid_savepoint_name_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let tx = db.begin().await?;
+
+    let err = tx.savepoint("x; DROP TABLE member").await.unwrap_err();
+    assert!(err.to_string().contains("invalid savepoint name"), "unexpected error: {}", err);
+    assert!(tx.rollback_to("missing").await.is_err());
+    tx.rollback().await?;
+    Ok(())
+}
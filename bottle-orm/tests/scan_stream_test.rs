@@ -0,0 +1,54 @@
+use bottle_orm::{Model, Op};
+use futures::{StreamExt, TryStreamExt};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Sale {
+    #[orm(primary_key)]
+    id: i32,
+    amount: i64,
+}
+
+#[tokio::test]
+async fn test_scan_stream_sums_without_collecting() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Sale>().run().await?;
+    let sales: Vec<Sale> = (1..=10_000).map(|id| Sale { id, amount: i64::from(id) }).collect();
+    db.model::<Sale>().batch_insert(&sales).await?;
+
+    let mut rows = db.model::<Sale>().order("id ASC").scan_stream::<Sale>();
+    let (mut total, mut seen, mut last_id) = (0i64, 0usize, 0);
+    while let Some(sale) = rows.try_next().await? {
+        assert!(sale.id > last_id);
+        last_id = sale.id;
+        total += sale.amount;
+        seen += 1;
+    }
+    drop(rows);
+    assert_eq!(seen, 10_000);
+    assert_eq!(total, 10_000 * 10_001 / 2);
+
+    // The single connection is free again once the stream is gone.
+    assert_eq!(db.model::<Sale>().count().await?, 10_000);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_stream_in_transaction_and_early_drop() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Sale>().run().await?;
+
+    let tx = db.begin().await?;
+    tx.model::<Sale>().batch_insert(&(1..=50).map(|id| Sale { id, amount: 2 }).collect::<Vec<_>>()).await?;
+    let first: Vec<Sale> = tx.model::<Sale>().filter("id", Op::Gt, 10).order("id ASC").scan_stream().take(3).try_collect().await?;
+    assert_eq!(first.iter().map(|s| s.id).collect::<Vec<_>>(), [11, 12, 13]);
+
+    // Dropping the stream early releases the transaction for further queries.
+    assert_eq!(tx.model::<Sale>().count().await?, 50);
+    tx.commit().await?;
+
+    let err = db.model::<Sale>().order("id; DROP TABLE sale").scan_stream::<Sale>().try_next().await.unwrap_err();
+    assert!(err.to_string().contains("Invalid ORDER BY"), "unexpected error: {}", err);
+    Ok(())
+}
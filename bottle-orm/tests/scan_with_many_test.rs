@@ -0,0 +1,72 @@
+use bottle_orm::{Database, Loaded, Model};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Article {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(foreign_key = "Author::id")]
+    author_id: i32,
+    title: String,
+}
+
+#[tokio::test]
+async fn test_scan_with_many_runs_two_queries() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).count_queries(true).connect("sqlite::memory:").await?;
+    db.migrator().register::<Author>().register::<Article>().run().await?;
+
+    let authors: Vec<Author> = (1..=100).map(|id| Author { id, name: format!("author {}", id) }).collect();
+    db.model::<Author>().batch_insert(&authors).await?;
+    // Every author but the last gets two articles.
+    let articles: Vec<Article> = (1..=198)
+        .map(|id| Article { id, author_id: (id + 1) / 2, title: format!("article {}", id) })
+        .collect();
+    db.model::<Article>().batch_insert(&articles).await?;
+
+    let before = db.query_count();
+    let loaded: Vec<Loaded<Author, Vec<Article>>> =
+        db.model::<Author>().order("id ASC").scan_with_many::<Article>("author_id").await?;
+    assert_eq!(db.query_count() - before, 2);
+
+    assert_eq!(loaded.len(), 100);
+    assert_eq!(loaded[0].parent, authors[0]);
+    assert_eq!(loaded[0].children.iter().map(|a| a.id).collect::<Vec<_>>(), [1, 2]);
+    assert!(loaded[..99].iter().all(|l| l.children.len() == 2 && l.children.iter().all(|a| a.author_id == l.parent.id)));
+    assert!(loaded[99].children.is_empty());
+
+    // No parents means no second query.
+    let before = db.query_count();
+    let none = db.model::<Author>().equals("id", 0).scan_with_many::<Article>("author_id").await?;
+    assert!(none.is_empty());
+    assert_eq!(db.query_count() - before, 1);
+
+    let err = db.model::<Author>().scan_with_many::<Article>("writer_id").await.unwrap_err();
+    assert!(err.to_string().contains("has no column \"writer_id\""), "unexpected error: {}", err);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_with_many_splits_keys_over_the_bind_limit() -> Result<(), Box<dyn std::error::Error>> {
+    let db = Database::builder().max_connections(1).count_queries(true).connect("sqlite::memory:").await?;
+    db.migrator().register::<Author>().register::<Article>().run().await?;
+
+    let authors: Vec<Author> = (1..=1500).map(|id| Author { id, name: format!("author {}", id) }).collect();
+    db.model::<Author>().batch_insert(&authors).await?;
+    let articles: Vec<Article> =
+        (1..=1500).map(|id| Article { id, author_id: id, title: format!("article {}", id) }).collect();
+    db.model::<Article>().batch_insert(&articles).await?;
+
+    // 1500 keys exceed SQLite's 999 parameters, so the children take two queries
+    let before = db.query_count();
+    let loaded = db.model::<Author>().order("id ASC").scan_with_many::<Article>("author_id").await?;
+    assert_eq!(db.query_count() - before, 3);
+    assert_eq!(loaded.len(), 1500);
+    assert!(loaded.iter().all(|l| l.children.len() == 1 && l.children[0].author_id == l.parent.id));
+    Ok(())
+}
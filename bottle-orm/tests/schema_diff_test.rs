@@ -0,0 +1,58 @@
+use bottle_orm::{Model, SchemaDiff};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "member")]
+struct MemberV1 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    name: String,
+    #[orm(index)]
+    nickname: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "member")]
+struct MemberV2 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    name: String,
+    #[orm(index)]
+    email: Option<String>,
+}
+
+#[tokio::test]
+async fn test_diff_table_reports_missing_and_extra() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let diff = db.diff_table::<MemberV2>().await?;
+    assert!(!diff.table_exists);
+    assert_eq!(diff.missing_columns, ["id", "name", "email"]);
+    assert_eq!(diff.missing_indexes, ["unique_member_name", "idx_member_email"]);
+
+    // A freshly created table matches its model, unique columns included
+    db.sync_table::<MemberV1>().await?;
+    assert!(db.diff_table::<MemberV1>().await?.is_empty());
+    let diff = db.diff_table::<MemberV2>().await?;
+    assert_eq!(
+        diff,
+        SchemaDiff {
+            table_exists: true,
+            missing_columns: vec!["email".to_string()],
+            extra_columns: vec!["nickname".to_string()],
+            missing_indexes: vec!["idx_member_email".to_string()],
+            extra_indexes: vec!["idx_member_nickname".to_string()],
+        }
+    );
+
+    // Diffing is read-only
+    assert_eq!(db.get_table_columns("member").await?, ["id", "name", "nickname"]);
+
+    db.sync_table_strict::<MemberV2>().await?;
+    assert!(db.diff_table::<MemberV2>().await?.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use bottle_orm::{Database, FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Account {
+    #[orm(primary_key)]
+    id: i32,
+    email: String,
+    password_hash: String,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct PublicAccount {
+    id: i32,
+    email: String,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Account>().run().await?;
+    db.model::<Account>()
+        .insert(&Account { id: 1, email: "a@example.com".to_string(), password_hash: "x".to_string() })
+        .await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_scan_full_model_after_omit_reports_missing_field() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = db.model::<Account>().omit("password_hash").scan::<Account>().await.unwrap_err();
+    let msg = err.to_string();
+    assert!(msg.contains("password_hash"), "error should name the field: {}", msg);
+    assert!(msg.contains("not in the select list"), "unexpected error: {}", msg);
+
+    let err = db.model::<Account>().omit("password_hash").first::<Account>().await.unwrap_err();
+    assert!(err.to_string().contains("password_hash"));
+
+    // A DTO without the omitted field is fine.
+    let public: Vec<PublicAccount> = db.model::<Account>().omit("password_hash").scan_as().await?;
+    assert_eq!(public.len(), 1);
+    assert_eq!(public[0].email, "a@example.com");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_scan_full_model_with_partial_select_reports_missing_field() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let err = db.model::<Account>().select("id, email").scan::<Account>().await.unwrap_err();
+    assert!(err.to_string().contains("password_hash"));
+
+    let all: Vec<Account> = db.model::<Account>().select("id, email, password_hash").scan().await?;
+    assert_eq!(all.len(), 1);
+
+    let all: Vec<Account> = db.model::<Account>().select("*").scan().await?;
+    assert_eq!(all[0].password_hash, "x");
+    Ok(())
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Profile {
+    #[orm(primary_key)]
+    id: i32,
+    nickname: Option<String>,
+    age: Option<i32>,
+}
+
+#[tokio::test]
+async fn test_scan_full_model_after_omitting_optional_field() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Profile>().run().await?;
+    db.model::<Profile>().insert(&Profile { id: 1, nickname: Some("ace".to_string()), age: Some(30) }).await?;
+
+    let profiles: Vec<Profile> = db.model::<Profile>().omit("nickname").scan().await?;
+    assert_eq!(profiles, [Profile { id: 1, nickname: None, age: Some(30) }]);
+
+    // Tuples decode by position, so the NULL placeholder keeps the later columns aligned.
+    let pairs: Vec<(Profile, Profile)> =
+        db.model::<Profile>().omit("nickname").join("profile p2", "p2.id = profile.id").scan().await?;
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].0.age, Some(30));
+    Ok(())
+}
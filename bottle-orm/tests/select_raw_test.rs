@@ -0,0 +1,53 @@
+use bottle_orm::{FromAnyRow, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Author {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+    bio: String,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct AuthorBio {
+    name: String,
+    bio_len: i64,
+    total: i64,
+}
+
+#[tokio::test]
+async fn test_select_raw_computed_columns_into_dto() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Author>().run().await?;
+    db.model::<Author>()
+        .batch_insert(&[
+            Author { id: 1, name: "Ana".to_string(), bio: "poet".to_string() },
+            Author { id: 2, name: "Bruno".to_string(), bio: "novelist".to_string() },
+        ])
+        .await?;
+
+    let rows: Vec<AuthorBio> = db
+        .model::<Author>()
+        .select("name")
+        .select_raw("LENGTH(bio) AS bio_len")
+        .select_raw("COUNT(*) OVER () AS total")
+        .order("id ASC")
+        .scan_as()
+        .await?;
+    assert_eq!(rows.iter().map(|r| (r.name.as_str(), r.bio_len, r.total)).collect::<Vec<_>>(), [("Ana", 4, 2), ("Bruno", 8, 2)]);
+
+    // Commas inside the expression are not treated as separate columns
+    let rows: Vec<AuthorBio> = db
+        .model::<Author>()
+        .select("name")
+        .select_as("COALESCE(LENGTH(bio), 0)", "bio_len")
+        .select_as("ABS(id - 4)", "total")
+        .filter("id", bottle_orm::Op::Eq, 2)
+        .scan_as()
+        .await?;
+    assert_eq!((rows[0].bio_len, rows[0].total), (8, 2));
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
-use bottle_orm::{Database, Model, Op};
+use bottle_orm::{Model, Op};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+mod common;
+
 #[derive(Debug, Clone, Model, PartialEq)]
 struct SoftUser {
     #[orm(primary_key)]
@@ -14,7 +16,7 @@ struct SoftUser {
 
 #[tokio::test]
 async fn test_soft_delete_lifecycle() -> Result<(), Box<dyn std::error::Error>> {
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
 
     // 1. Create Table
     db.migrator().register::<SoftUser>().run().await?;
@@ -58,7 +60,7 @@ async fn test_soft_delete_lifecycle() -> Result<(), Box<dyn std::error::Error>>
 
 #[tokio::test]
 async fn test_queries_with_soft_delete() -> Result<(), Box<dyn std::error::Error>> {
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
     db.migrator().register::<SoftUser>().run().await?;
 
     let u1 = SoftUser { id: Uuid::new_v4(), name: "Active".to_string(), deleted_at: None };
@@ -101,7 +103,7 @@ async fn test_queries_with_soft_delete() -> Result<(), Box<dyn std::error::Error
 
 #[tokio::test]
 async fn test_hard_delete() -> Result<(), Box<dyn std::error::Error>> {
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
     db.migrator().register::<SoftUser>().run().await?;
 
     // Insert 3 users
@@ -140,3 +142,101 @@ async fn test_hard_delete() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_soft_deleted_row_stays_in_table() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<SoftUser>().run().await?;
+
+    let user = SoftUser { id: Uuid::new_v4(), name: "Carol".to_string(), deleted_at: None };
+    db.model::<SoftUser>().insert(&user).await?;
+
+    let deleted = db.model::<SoftUser>().filter(soft_user_fields::ID, Op::Eq, user.id.to_string()).delete().await?;
+    assert_eq!(deleted, 1);
+
+    // Hidden from normal queries...
+    assert!(db.model::<SoftUser>().scan::<SoftUser>().await?.is_empty());
+    assert!(db.model::<SoftUser>().filter(soft_user_fields::ID, Op::Eq, user.id.to_string()).first::<SoftUser>().await.is_err());
+
+    // ...but the row is still there with its deletion time set.
+    let (rows, stamped): (i64, i64) = db
+        .raw("SELECT COUNT(*), COUNT(deleted_at) FROM soft_user WHERE name = 'Carol'")
+        .fetch_one()
+        .await?;
+    assert_eq!((rows, stamped), (1, 1));
+
+    // Deleting again does not touch the already deleted row.
+    let stamp: SoftUser = db.model::<SoftUser>().with_deleted().first().await?;
+    let again = db.model::<SoftUser>().filter(soft_user_fields::ID, Op::Eq, user.id.to_string()).delete().await?;
+    assert_eq!(again, 0);
+    let after: SoftUser = db.model::<SoftUser>().with_deleted().first().await?;
+    assert_eq!(after.deleted_at, stamp.deleted_at);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_trashed_scopes_and_restore() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<SoftUser>().run().await?;
+
+    let keep = SoftUser { id: Uuid::new_v4(), name: "Keep".to_string(), deleted_at: None };
+    let gone = SoftUser { id: Uuid::new_v4(), name: "Gone".to_string(), deleted_at: None };
+    db.model::<SoftUser>().insert(&keep).await?;
+    db.model::<SoftUser>().insert(&gone).await?;
+    db.model::<SoftUser>().filter(soft_user_fields::ID, Op::Eq, gone.id.to_string()).delete().await?;
+
+    assert_eq!(db.model::<SoftUser>().count().await?, 1);
+    assert_eq!(db.model::<SoftUser>().with_trashed().count().await?, 2);
+
+    let trashed: Vec<SoftUser> = db.model::<SoftUser>().only_trashed().scan().await?;
+    assert_eq!(trashed.len(), 1);
+    assert_eq!(trashed[0].name, "Gone");
+
+    // Restoring a live row is a no-op.
+    let restored = db.model::<SoftUser>().filter(soft_user_fields::ID, Op::Eq, keep.id.to_string()).restore().await?;
+    assert_eq!(restored, 0);
+
+    let restored = db.model::<SoftUser>().filter(soft_user_fields::ID, Op::Eq, gone.id.to_string()).restore().await?;
+    assert_eq!(restored, 1);
+
+    let users: Vec<SoftUser> = db.model::<SoftUser>().order("name ASC").scan().await?;
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].name, "Gone");
+    assert!(users[0].deleted_at.is_none());
+    assert!(db.model::<SoftUser>().only_trashed().scan::<SoftUser>().await?.is_empty());
+    Ok(())
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct SoftNote {
+    #[orm(primary_key)]
+    id: Uuid,
+    user_id: Uuid,
+
+    #[orm(soft_delete)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[tokio::test]
+async fn test_trashed_scopes_with_a_soft_deleted_join() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<SoftUser>().register::<SoftNote>().run().await?;
+
+    let live = SoftUser { id: Uuid::new_v4(), name: "Live".to_string(), deleted_at: None };
+    let gone = SoftUser { id: Uuid::new_v4(), name: "Gone".to_string(), deleted_at: Some(Utc::now()) };
+    db.model::<SoftUser>().insert(&live).await?;
+    db.model::<SoftUser>().insert(&gone).await?;
+    for user in [&live, &gone] {
+        db.model::<SoftNote>().insert(&SoftNote { id: Uuid::new_v4(), user_id: user.id, deleted_at: None }).await?;
+    }
+
+    // Both tables have "deleted_at", so the implicit filters must name their table.
+    let joined = || db.model::<SoftUser>().join("soft_note", "soft_note.user_id = soft_user.id");
+    let users: Vec<SoftUser> = joined().scan().await?;
+    assert_eq!(users.iter().map(|u| u.name.as_str()).collect::<Vec<_>>(), ["Live"]);
+    assert_eq!(joined().count().await?, 1);
+
+    let trashed: Vec<SoftUser> = joined().only_trashed().scan().await?;
+    assert_eq!(trashed.iter().map(|u| u.name.as_str()).collect::<Vec<_>>(), ["Gone"]);
+    Ok(())
+}
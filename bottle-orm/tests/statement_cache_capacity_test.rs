@@ -0,0 +1,42 @@
+use bottle_orm::{Database, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Metric {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+/// Runs a few more distinct statements than the cache holds, so entries get evicted.
+async fn run_distinct_queries(db: &Database) -> Result<(), Box<dyn std::error::Error>> {
+    db.migrator().register::<Metric>().run().await?;
+    let metrics: Vec<Metric> = (1..=5).map(|id| Metric { id, name: format!("m{}", id) }).collect();
+    db.model::<Metric>().batch_insert(&metrics).await?;
+
+    for id in 1..=5 {
+        let rows: Vec<Metric> = db.model::<Metric>().filter("id", Op::Lte, id).limit(id as usize).scan().await?;
+        assert_eq!(rows.len(), id as usize);
+    }
+    assert_eq!(db.model::<Metric>().count().await?, 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_cache_capacity_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+    // Not applied on SQLite, but must not break connecting.
+    let db = Database::builder().max_connections(1).statement_cache_capacity(2).connect("sqlite::memory:").await?;
+    run_distinct_queries(&db).await
+}
+
+#[tokio::test]
+async fn test_statement_cache_capacity_postgres() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::from_env("POSTGRES_URL", Database::builder().max_connections(1).statement_cache_capacity(2)).await? else {
+        return Ok(());
+    };
+    db.raw("DROP TABLE IF EXISTS \"metric\"").execute().await?;
+    run_distinct_queries(&db).await?;
+    db.raw("DROP TABLE \"metric\"").execute().await?;
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use bottle_orm::Model;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "account")]
+struct AccountV1 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    email: String,
+    #[orm(index)]
+    legacy_flag: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+#[orm(table = "account")]
+struct AccountV2 {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    email: String,
+}
+
+#[tokio::test]
+async fn test_sync_table_strict_drops_removed_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    db.sync_table::<AccountV1>().await?;
+    db.raw("INSERT INTO account (id, email, legacy_flag) VALUES (1, 'a@example.com', 'x')").execute().await?;
+
+    // The plain sync leaves the stale column in place
+    db.sync_table::<AccountV2>().await?;
+    assert!(db.get_table_columns("account").await?.contains(&"legacy_flag".to_string()));
+
+    db.sync_table_strict::<AccountV2>().await?;
+    assert_eq!(db.get_table_columns("account").await?, vec!["id".to_string(), "email".to_string()]);
+
+    // Kept data survives the rebuild, and the unique constraint is still enforced
+    let (email,): (String,) = db.raw("SELECT email FROM account WHERE id = 1").fetch_one().await?;
+    assert_eq!(email, "a@example.com");
+    assert!(db.raw("INSERT INTO account (id, email) VALUES (2, 'a@example.com')").execute().await.is_err());
+
+    // Nothing left to drop
+    db.sync_table_strict::<AccountV2>().await?;
+    assert_eq!(db.model::<AccountV2>().count().await?, 1);
+
+    Ok(())
+}
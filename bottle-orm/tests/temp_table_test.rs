@@ -0,0 +1,34 @@
+use bottle_orm::{Database, Model, Op};
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct ImportRow {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(index)]
+    sku: String,
+}
+
+#[tokio::test]
+async fn test_temp_table_is_scoped_to_its_connection() -> Result<(), Box<dyn std::error::Error>> {
+    // A file database, so the pool's connections share the regular tables
+    let path = std::env::temp_dir().join(format!("bottle_temp_table_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let db = Database::builder().max_connections(2).connect(&format!("sqlite://{}?mode=rwc", path.display())).await?;
+
+    let tx = db.create_temp_table::<ImportRow>().await?;
+    let rows = vec![ImportRow { id: 1, sku: "A-1".to_string() }, ImportRow { id: 2, sku: "B-2".to_string() }];
+    tx.model::<ImportRow>().batch_insert(&rows).await?;
+
+    let found: Vec<ImportRow> = tx.model::<ImportRow>().filter("sku", Op::Eq, "B-2".to_string()).scan().await?;
+    assert_eq!(found, [rows[1].clone()]);
+
+    // The transaction pins one connection, so this query runs on the other
+    let elsewhere: Result<Vec<ImportRow>, _> = db.model::<ImportRow>().scan().await;
+    assert!(elsewhere.is_err(), "temporary table leaked to another connection");
+    assert!(!db.table_exists("import_row").await?);
+
+    tx.rollback().await?;
+    db.close().await;
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
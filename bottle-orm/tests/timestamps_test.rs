@@ -0,0 +1,83 @@
+use bottle_orm::{Database, Model, Op};
+use chrono::{DateTime, TimeZone, Utc};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Document {
+    #[orm(primary_key)]
+    id: i32,
+    title: String,
+    #[orm(create_time)]
+    created_at: Option<DateTime<Utc>>,
+    #[orm(update_time)]
+    updated_at: DateTime<Utc>,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Document>().run().await?;
+    Ok(db)
+}
+
+async fn find(db: &Database, id: i32) -> Result<Document, sqlx::Error> {
+    db.model::<Document>().filter("id", Op::Eq, id).first().await
+}
+
+#[tokio::test]
+async fn test_insert_fills_unset_timestamps() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let before = Utc::now();
+
+    db.model::<Document>()
+        .insert(&Document { id: 1, title: "draft".to_string(), created_at: None, updated_at: DateTime::default() })
+        .await?;
+    db.model::<Document>()
+        .batch_insert(&[Document { id: 2, title: "notes".to_string(), created_at: None, updated_at: DateTime::default() }])
+        .await?;
+
+    for id in [1, 2] {
+        let doc = find(&db, id).await?;
+        assert!(doc.created_at.is_some_and(|t| t >= before), "created_at not set for {}", id);
+        assert!(doc.updated_at >= before, "updated_at not set for {}", id);
+    }
+
+    // Explicit values are kept.
+    let fixed = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    db.model::<Document>()
+        .insert(&Document { id: 3, title: "old".to_string(), created_at: Some(fixed), updated_at: fixed })
+        .await?;
+    let doc = find(&db, 3).await?;
+    assert_eq!(doc.created_at, Some(fixed));
+    assert_eq!(doc.updated_at, fixed);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_advances_updated_at() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let old = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    db.model::<Document>()
+        .insert(&Document { id: 1, title: "draft".to_string(), created_at: Some(old), updated_at: old })
+        .await?;
+
+    db.model::<Document>().filter("id", Op::Eq, 1).update("title", "final".to_string()).await?;
+    let doc = find(&db, 1).await?;
+    assert_eq!(doc.title, "final");
+    assert!(doc.updated_at > old);
+    assert_eq!(doc.created_at, Some(old));
+
+    // A full-model update refreshes the stamp instead of writing the stale value back.
+    let mut stale = doc.clone();
+    stale.updated_at = old;
+    stale.title = "edited".to_string();
+    db.model::<Document>().filter("id", Op::Eq, 1).updates(&stale).await?;
+    let doc = find(&db, 1).await?;
+    assert_eq!(doc.title, "edited");
+    assert!(doc.updated_at > old);
+
+    // Assigning the column explicitly wins.
+    db.model::<Document>().filter("id", Op::Eq, 1).update("updated_at", old).await?;
+    assert_eq!(find(&db, 1).await?.updated_at, old);
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use bottle_orm::{Database, Error, Model};
+use futures::FutureExt;
+use std::panic::AssertUnwindSafe;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ledger {
+    #[orm(primary_key)]
+    id: i32,
+    amount: i64,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Ledger>().run().await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_transaction_commits_on_ok() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let inserted = db
+        .transaction(async |tx| {
+            tx.model::<Ledger>().insert(&Ledger { id: 1, amount: 100 }).await?;
+            tx.model::<Ledger>().insert(&Ledger { id: 2, amount: -40 }).await?;
+            Ok::<_, Error>(2)
+        })
+        .await?;
+
+    assert_eq!(inserted, 2);
+    assert_eq!(db.model::<Ledger>().count().await?, 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_rolls_back_on_err() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    db.model::<Ledger>().insert(&Ledger { id: 1, amount: 100 }).await?;
+
+    let result = db
+        .transaction(async |tx| {
+            tx.model::<Ledger>().insert(&Ledger { id: 2, amount: -40 }).await?;
+            tx.model::<Ledger>().filter("id", bottle_orm::Op::Eq, 1).update("amount", 60i64).await?;
+            // Fails half-way: the id is already taken.
+            tx.model::<Ledger>().insert(&Ledger { id: 1, amount: 1 }).await?;
+            Ok::<_, Error>(())
+        })
+        .await;
+    assert!(result.is_err());
+
+    let rows: Vec<Ledger> = db.model::<Ledger>().scan().await?;
+    assert_eq!(rows, vec![Ledger { id: 1, amount: 100 }]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transaction_rolls_back_on_panic() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let result = AssertUnwindSafe(db.transaction(async |tx| {
+        tx.model::<Ledger>().insert(&Ledger { id: 1, amount: 100 }).await?;
+        if tx.model::<Ledger>().count().await? == 1 {
+            panic!("boom");
+        }
+        Ok::<_, Error>(())
+    }))
+    .catch_unwind()
+    .await;
+    assert!(result.is_err(), "the panic should propagate to the caller");
+
+    // The single pooled connection was released and nothing was persisted.
+    assert_eq!(db.model::<Ledger>().count().await?, 0);
+    db.model::<Ledger>().insert(&Ledger { id: 3, amount: 5 }).await?;
+    assert_eq!(db.model::<Ledger>().count().await?, 1);
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use bottle_orm::Model;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Order {
+    #[orm(primary_key)]
+    id: i32,
+    total: i64,
+}
+
+#[tokio::test]
+async fn test_dropped_transaction_rolls_back() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = common::sqlite().await?;
+    db.migrator().register::<Order>().run().await?;
+
+    {
+        let tx = db.begin().await?;
+        tx.model::<Order>().insert(&Order { id: 1, total: 10 }).await?;
+        let mut builder = tx.model::<Order>();
+        drop(tx);
+        // A query builder still holds the transaction open.
+        builder.insert(&Order { id: 2, total: 20 }).await?;
+    }
+
+    // The single pooled connection is usable again and nothing was committed.
+    assert_eq!(db.model::<Order>().count().await?, 0);
+
+    let tx = db.begin().await?;
+    tx.model::<Order>().insert(&Order { id: 3, total: 30 }).await?;
+    tx.commit().await?;
+    assert_eq!(db.model::<Order>().count().await?, 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_clones_dropped_on_other_threads_roll_back_once() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Order>().run().await?;
+
+    let tx = db.begin().await?;
+    tx.model::<Order>().insert(&Order { id: 1, total: 10 }).await?;
+    let clones: Vec<_> = (0..8).map(|_| tx.clone()).collect();
+    drop(tx);
+    let runtime = tokio::runtime::Handle::current();
+    std::thread::scope(|scope| {
+        for clone in clones {
+            let runtime = runtime.clone();
+            scope.spawn(move || {
+                let _context = runtime.enter();
+                drop(clone);
+            });
+        }
+    });
+
+    assert_eq!(db.model::<Order>().count().await?, 0);
+    Ok(())
+}
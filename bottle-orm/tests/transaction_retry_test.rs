@@ -0,0 +1,110 @@
+use bottle_orm::{Database, Error, Model};
+use std::{
+    borrow::Cow,
+    fmt,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Seat {
+    #[orm(primary_key)]
+    id: i32,
+    holder: String,
+}
+
+/// A database error carrying a fixed SQLSTATE, standing in for a real serialization failure.
+#[derive(Debug)]
+struct FakeDbError(&'static str);
+
+impl fmt::Display for FakeDbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "simulated error {}", self.0)
+    }
+}
+
+impl std::error::Error for FakeDbError {}
+
+impl sqlx::error::DatabaseError for FakeDbError {
+    fn message(&self) -> &str {
+        "simulated"
+    }
+    fn code(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self.0))
+    }
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self
+    }
+    fn kind(&self) -> sqlx::error::ErrorKind {
+        sqlx::error::ErrorKind::Other
+    }
+}
+
+fn db_error(code: &'static str) -> Error {
+    Error::DatabaseError(sqlx::Error::Database(Box::new(FakeDbError(code))))
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Seat>().run().await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_retryable_failures_are_retried_until_success() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let attempts = AtomicU32::new(0);
+
+    let id = db
+        .transaction_with_retry(3, async |tx| {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            tx.model::<Seat>().insert(&Seat { id: 1, holder: format!("attempt {}", attempt) }).await?;
+            if attempt < 2 {
+                return Err(db_error(if attempt == 0 { "40001" } else { "40P01" }));
+            }
+            Ok(1)
+        })
+        .await?;
+
+    assert_eq!(id, 1);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    // Aborted attempts were rolled back; only the last one persisted.
+    let seats: Vec<Seat> = db.model::<Seat>().scan().await?;
+    assert_eq!(seats, vec![Seat { id: 1, holder: "attempt 2".to_string() }]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retries_stop_at_limit_and_skip_other_errors() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let attempts = AtomicU32::new(0);
+    let err = db
+        .transaction_with_retry(2, async |_tx| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(db_error("40001"))
+        })
+        .await
+        .unwrap_err();
+    assert!(err.is_retryable());
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    let attempts = AtomicU32::new(0);
+    let err = db
+        .transaction_with_retry(5, async |_tx| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(db_error("23505"))
+        })
+        .await
+        .unwrap_err();
+    assert!(!err.is_retryable());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use bottle_orm::{Database, Drivers, FromAnyRow, Model, Op, QueryBuilder};
+use heck::ToSnakeCase;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Ticket {
+    #[orm(primary_key)]
+    id: i32,
+    title: String,
+    status: String,
+    priority: i32,
+}
+
+#[derive(Debug, Clone, FromAnyRow)]
+struct TicketSummary {
+    id: i32,
+    title: String,
+}
+
+fn query_for(db: &Database, driver: Drivers) -> QueryBuilder<Ticket, Database> {
+    let columns = Ticket::active_columns().iter().map(|c| c.to_snake_case()).collect();
+    QueryBuilder::new(db.clone(), driver, Ticket::table_name(), <Ticket as Model>::columns(), columns)
+}
+
+#[tokio::test]
+async fn test_union_renders_each_side_in_parentheses() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    let active = query_for(&db, Drivers::Postgres).filter("status", Op::Eq, "open").limit(5);
+    let archived = query_for(&db, Drivers::Postgres).filter("priority", Op::Gte, 3);
+    let sql = active.union_all(archived).to_sql();
+    assert!(sql.starts_with("(SELECT "), "{}", sql);
+    assert!(sql.contains("\"ticket\".\"status\" = $1 LIMIT $2) UNION ALL (SELECT "), "{}", sql);
+    assert!(sql.ends_with("\"ticket\".\"priority\" >= $3)"), "{}", sql);
+
+    let sql = query_for(&db, Drivers::SQLite).union(query_for(&db, Drivers::SQLite)).to_sql();
+    assert!(sql.starts_with("SELECT * FROM (SELECT "), "{}", sql);
+    assert!(sql.contains(") UNION SELECT * FROM (SELECT "), "{}", sql);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_union_scans_into_dto() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Ticket>().run().await?;
+    for (id, status, priority) in [(1, "open", 1), (2, "closed", 5), (3, "closed", 1), (4, "open", 4)] {
+        let title = format!("ticket {}", id);
+        db.model::<Ticket>().insert(&Ticket { id, title, status: status.to_string(), priority }).await?;
+    }
+
+    // Values bind in query order: "open" for the first side, 3 for the second
+    let open = db.model::<Ticket>().filter("status", Op::Eq, "open");
+    let urgent = db.model::<Ticket>().filter("priority", Op::Gte, 3);
+    let mut found: Vec<TicketSummary> = open.union(urgent).scan().await?;
+    found.sort_by_key(|t| t.id);
+    assert_eq!(found.iter().map(|t| t.id).collect::<Vec<_>>(), [1, 2, 4]);
+    assert_eq!(found[1].title, "ticket 2");
+
+    // UNION ALL keeps the row matched by both sides
+    let open = db.model::<Ticket>().filter("status", Op::Eq, "open");
+    let urgent = db.model::<Ticket>().filter("priority", Op::Gte, 3);
+    assert_eq!(open.union_all(urgent).scan::<TicketSummary>().await?.len(), 4);
+
+    // Each side keeps its own ORDER BY and LIMIT
+    let newest_open = db.model::<Ticket>().filter("status", Op::Eq, "open").order("id DESC").limit(1);
+    let newest_closed = db.model::<Ticket>().filter("status", Op::Eq, "closed").order("id DESC").limit(1);
+    let mut found: Vec<TicketSummary> = newest_open.union_all(newest_closed).scan().await?;
+    found.sort_by_key(|t| t.id);
+    assert_eq!(found.iter().map(|t| t.id).collect::<Vec<_>>(), [3, 4]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_union_rejects_mismatched_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Ticket>().run().await?;
+
+    let ids = db.model::<Ticket>().select("id");
+    let pairs = db.model::<Ticket>().select("id, title");
+    let err = ids.union(pairs).scan::<(i32,)>().await.unwrap_err();
+    assert!(err.to_string().contains("UNION of \"ticket\" selects 2 column(s)"), "{}", err);
+
+    Ok(())
+}
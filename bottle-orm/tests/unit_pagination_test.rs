@@ -1,4 +1,7 @@
-use bottle_orm::pagination::Pagination;
+use axum::{extract::Query, http::Uri};
+use bottle_orm::{pagination::Pagination, Model};
+
+mod common;
 
 // ============================================================================
 // Default
@@ -8,8 +11,63 @@ use bottle_orm::pagination::Pagination;
 fn test_pagination_default() {
     let p = Pagination::default();
     assert_eq!(p.page, 0);
-    assert_eq!(p.limit, 10);
+    assert_eq!(p.limit, 20);
+    assert_eq!(p.max_limit, 100);
+}
+
+// ============================================================================
+// Query String Deserialization
+// ============================================================================
+
+fn from_query(uri: &str) -> Pagination {
+    let uri: Uri = uri.parse().unwrap();
+    Query::<Pagination>::try_from_uri(&uri).unwrap().0
+}
+
+#[test]
+fn test_partial_query_string_uses_defaults() {
+    let p = from_query("/users?page=1");
+    assert_eq!(p.page, 1);
+    assert_eq!(p.limit, 20);
     assert_eq!(p.max_limit, 100);
+
+    let p = from_query("/users");
+    assert_eq!((p.page, p.limit), (0, 20));
+
+    let p = from_query("/users?limit=5");
+    assert_eq!((p.page, p.limit), (0, 5));
+}
+
+#[test]
+fn test_max_limit_is_not_taken_from_query_string() {
+    let p = from_query("/users?limit=500&max_limit=1000");
+    assert_eq!(p.max_limit, 100);
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Item {
+    #[orm(primary_key)]
+    id: i32,
+}
+
+#[tokio::test]
+async fn test_zero_and_oversized_limits_are_guarded() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Item>().run().await?;
+    for id in 1..=30 {
+        db.model::<Item>().insert(&Item { id }).await?;
+    }
+
+    let page = from_query("/items?limit=0").paginate::<Item, _, Item>(db.model::<Item>().order("id ASC")).await?;
+    assert_eq!(page.limit, 20);
+    assert_eq!(page.data.len(), 20);
+    assert_eq!(page.total_pages, 2);
+
+    let page = from_query("/items?page=1&limit=500").paginate::<Item, _, Item>(db.model::<Item>().order("id ASC")).await?;
+    assert_eq!(page.limit, 100);
+    assert_eq!(page.total_pages, 1);
+    assert!(page.data.is_empty());
+    Ok(())
 }
 
 // ============================================================================
@@ -0,0 +1,65 @@
+use bottle_orm::{pagination::Pagination, Model};
+use std::sync::{Mutex, Once};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Tag {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+/// Collects warnings so the test can assert on them; one logger per test binary.
+struct CaptureLogger;
+
+static WARNINGS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+static INIT: Once = Once::new();
+
+impl log::Log for CaptureLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            WARNINGS.lock().unwrap().push(record.args().to_string());
+        }
+    }
+    fn flush(&self) {}
+}
+
+fn take_warnings() -> Vec<String> {
+    std::mem::take(&mut *WARNINGS.lock().unwrap())
+}
+
+#[tokio::test]
+async fn test_unordered_scan_warns_in_debug_mode() -> Result<(), Box<dyn std::error::Error>> {
+    INIT.call_once(|| {
+        log::set_logger(&CaptureLogger).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+    });
+    let db = common::sqlite().await?;
+    db.migrator().register::<Tag>().run().await?;
+    db.model::<Tag>().insert(&Tag { id: 1, name: "rust".to_string() }).await?;
+    take_warnings();
+
+    let _: Vec<Tag> = db.model::<Tag>().debug().scan().await?;
+    let warnings = take_warnings();
+    assert!(warnings.iter().any(|w| w.contains("\"tag\" without ORDER BY")), "missing warning: {:?}", warnings);
+
+    let _: Vec<Tag> = db.model::<Tag>().debug().scan_as().await?;
+    assert_eq!(take_warnings().len(), 1);
+
+    // Ordered scans, and scans outside debug mode, stay quiet.
+    let _: Vec<Tag> = db.model::<Tag>().debug().order("id ASC").scan().await?;
+    let _: Vec<Tag> = db.model::<Tag>().scan().await?;
+    assert!(take_warnings().is_empty());
+
+    // Unordered pagination follows the same rule
+    Pagination::new(0, 1).paginate::<Tag, _, Tag>(db.model::<Tag>()).await?;
+    assert!(take_warnings().is_empty());
+    Pagination::new(0, 1).paginate::<Tag, _, Tag>(db.model::<Tag>().debug()).await?;
+    let warnings = take_warnings();
+    assert!(warnings.iter().any(|w| w.contains("Paginating \"tag\"")), "missing warning: {:?}", warnings);
+    Ok(())
+}
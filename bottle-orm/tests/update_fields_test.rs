@@ -0,0 +1,71 @@
+use bottle_orm::{Database, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Profile {
+    #[orm(primary_key)]
+    id: i32,
+    username: String,
+    #[orm(column = "bio_text")]
+    bio: String,
+    followers: i32,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Profile>().run().await?;
+    db.model::<Profile>()
+        .insert(&Profile { id: 1, username: "ann".to_string(), bio: "hello".to_string(), followers: 10 })
+        .await?;
+    Ok(db)
+}
+
+#[test]
+fn test_update_map_contains_only_changed_columns() {
+    let profile = Profile { id: 1, username: "ann".to_string(), bio: "hi".to_string(), followers: 3 };
+
+    let map = profile.update_map(&["username"]);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map.get("username"), Some(&Some("ann".to_string())));
+
+    // Renamed columns are reachable through the field or the column name.
+    assert_eq!(profile.update_map(&["bio"]).get("bio_text"), Some(&Some("hi".to_string())));
+    assert_eq!(profile.update_map(&["bio_text"]).len(), 1);
+    assert!(profile.update_map(&["nope"]).is_empty());
+}
+
+#[tokio::test]
+async fn test_update_fields_leaves_other_columns_alone() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    // Another writer bumps the follower count meanwhile.
+    db.model::<Profile>().filter("id", Op::Eq, 1).update("followers", 42).await?;
+
+    // Our copy is stale except for the field we changed.
+    let stale = Profile { id: 1, username: "annie".to_string(), bio: "stale".to_string(), followers: 0 };
+    let affected = db.model::<Profile>().filter("id", Op::Eq, 1).update_fields(&stale, &["username"]).await?;
+    assert_eq!(affected, 1);
+
+    let stored: Profile = db.model::<Profile>().find(1).await?;
+    assert_eq!(stored, Profile { id: 1, username: "annie".to_string(), bio: "hello".to_string(), followers: 42 });
+
+    let edited = Profile { bio: "updated".to_string(), ..stored.clone() };
+    db.model::<Profile>().filter("id", Op::Eq, 1).update_fields(&edited, &["bio"]).await?;
+    let stored: Profile = db.model::<Profile>().find(1).await?;
+    assert_eq!(stored.bio, "updated");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_fields_rejects_unknown_field() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+    let profile: Profile = db.model::<Profile>().find(1).await?;
+
+    let err = db.model::<Profile>().filter("id", Op::Eq, 1).update_fields(&profile, &["username", "karma"]).await.unwrap_err();
+    assert!(err.to_string().contains("karma"), "unexpected error: {}", err);
+
+    let stored: Profile = db.model::<Profile>().find(1).await?;
+    assert_eq!(stored, profile);
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use bottle_orm::{Database, Model, Op};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    username: String,
+    age: i32,
+    level: i64,
+    nickname: Option<String>,
+}
+
+async fn setup() -> Result<Database, Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().run().await?;
+    db.model::<Member>()
+        .batch_insert(&[
+            Member { id: 1, username: "alice".to_string(), age: 30, level: 1, nickname: None },
+            Member { id: 2, username: "bob".to_string(), age: 25, level: 1, nickname: Some("bobby".to_string()) },
+            Member { id: 3, username: "carol".to_string(), age: 41, level: 0, nickname: None },
+        ])
+        .await?;
+    Ok(db)
+}
+
+#[tokio::test]
+async fn test_updates_full_model_skips_primary_key_and_omitted() -> Result<(), Box<dyn std::error::Error>> {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let db = setup().await?;
+
+    let changed = Member { id: 99, username: "alice2".to_string(), age: 31, level: 0, nickname: Some("al".to_string()) };
+    let affected = db.model::<Member>().filter("id", Op::Eq, 1).omit("nickname").updates(&changed).await?;
+    assert_eq!(affected, 1);
+
+    let alice: Member = db.model::<Member>().filter("id", Op::Eq, 1).first().await?;
+    assert_eq!(alice, Member { id: 1, username: "alice2".to_string(), age: 31, level: 0, nickname: None });
+    assert_eq!(db.model::<Member>().filter("id", Op::Eq, 99).count().await?, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_and_update_set() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    let affected = db.model::<Member>().filter("id", Op::Eq, 2).set("age", 26).set("nickname", None::<String>).update_set().await?;
+    assert_eq!(affected, 1);
+
+    let bob: Member = db.model::<Member>().filter("id", Op::Eq, 2).first().await?;
+    assert_eq!(bob.age, 26);
+    assert_eq!(bob.nickname, None);
+    assert_eq!(bob.username, "bob");
+
+    let affected = db.model::<Member>().filter("age", Op::Gt, 26).set("level", 0i64).update_set().await?;
+    assert_eq!(affected, 2);
+
+    let nothing = db.model::<Member>().filter("id", Op::Eq, 2).update_set().await?;
+    assert_eq!(nothing, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_set_on_matched_rows() -> Result<(), Box<dyn std::error::Error>> {
+    let db = setup().await?;
+
+    // SET values bind before the WHERE values
+    let affected = db
+        .model::<Member>()
+        .filter("age", Op::Lt, 35)
+        .filter("level", Op::Eq, 1i64)
+        .set("level", 5i64)
+        .set("nickname", "regular")
+        .update_set()
+        .await?;
+    assert_eq!(affected, 2);
+
+    let members: Vec<Member> = db.model::<Member>().order("id ASC").scan().await?;
+    let summary: Vec<(i64, Option<&str>)> = members.iter().map(|m| (m.level, m.nickname.as_deref())).collect();
+    assert_eq!(summary, vec![(5, Some("regular")), (5, Some("regular")), (0, None)]);
+
+    let err = db.model::<Member>().set("levle", 1i64).update_set().await.unwrap_err();
+    assert!(err.to_string().contains("Cannot update unknown column 'levle'"), "{}", err);
+    assert_eq!(db.model::<Member>().filter("level", Op::Eq, 5i64).count().await?, 2);
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use bottle_orm::{Database, Model};
+use bottle_orm::Model;
+
+mod common;
 
 #[derive(Debug, Clone, Model, PartialEq)]
 struct User {
@@ -11,7 +13,7 @@ struct User {
 #[tokio::test]
 async fn test_upsert_lifecycle() -> Result<(), Box<dyn std::error::Error>> {
     let _ = env_logger::builder().is_test(true).try_init();
-    let db = Database::builder().max_connections(1).connect("sqlite::memory:").await?;
+    let db = common::sqlite().await?;
 
     db.migrator().register::<User>().run().await?;
 
@@ -61,3 +63,56 @@ async fn test_upsert_lifecycle() -> Result<(), Box<dyn std::error::Error>> {
     println!("Upsert test passed!");
     Ok(())
 }
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Profile {
+    #[orm(primary_key)]
+    id: i32,
+    #[orm(unique)]
+    username: String,
+    age: i32,
+    bio: Option<String>,
+}
+
+#[tokio::test]
+async fn test_upsert_defaults_to_all_non_conflict_columns() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Profile>().run().await?;
+
+    let first = Profile { id: 1, username: "alice".to_string(), age: 30, bio: None };
+    db.model::<Profile>().upsert(&first, &["username"], &[]).await?;
+
+    // Same username, different id: the conflict on username updates the other columns but keeps the key.
+    let second = Profile { id: 2, username: "alice".to_string(), age: 31, bio: Some("hi".to_string()) };
+    db.model::<Profile>().upsert(&second, &["username"], &[]).await?;
+
+    let all: Vec<Profile> = db.model::<Profile>().scan().await?;
+    assert_eq!(all, vec![Profile { id: 1, username: "alice".to_string(), age: 31, bio: Some("hi".to_string()) }]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mysql_upsert_updates_conflicting_row() -> Result<(), Box<dyn std::error::Error>> {
+    let Some(db) = common::mysql().await? else {
+        return Ok(());
+    };
+
+    db.raw("DROP TABLE IF EXISTS `user`").execute().await?;
+    db.raw("CREATE TABLE `user` (`id` INT PRIMARY KEY, `username` VARCHAR(64) NOT NULL, `age` INT NOT NULL)")
+        .execute()
+        .await?;
+
+    let user = User { id: 1, username: "alice".to_string(), age: 20 };
+    db.model::<User>().upsert(&user, &["id"], &["username", "age"]).await?;
+
+    // Same key: ON DUPLICATE KEY UPDATE `col` = VALUES(`col`) takes the new values.
+    let updated = User { id: 1, username: "alice_v2".to_string(), age: 21 };
+    db.model::<User>().upsert(&updated, &["id"], &["username", "age"]).await?;
+
+    let (count, username, age): (i64, String, i32) =
+        db.raw("SELECT COUNT(*), MAX(`username`), MAX(`age`) FROM `user`").fetch_one().await?;
+    assert_eq!((count, username.as_str(), age), (1, "alice_v2", 21));
+
+    db.raw("DROP TABLE `user`").execute().await?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use bottle_orm::{Database, Model};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Setting {
+    #[orm(primary_key)]
+    id: i32,
+    key: String,
+}
+
+fn migrator(db: &Database) -> bottle_orm::Migrator<'_> {
+    db.migrator()
+        .register::<Setting>()
+        .register_migration(
+            1,
+            "INSERT INTO setting (id, key) VALUES (1, 'theme'); INSERT INTO setting (id, key) VALUES (2, 'locale')",
+            "DELETE FROM setting WHERE id IN (1, 2)",
+        )
+        .register_migration(2, "CREATE TABLE setting_audit (id INTEGER PRIMARY KEY)", "DROP TABLE setting_audit")
+}
+
+#[tokio::test]
+async fn test_versioned_migration_applies_once() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::temp_dir().join(format!("bottle_versioned_migration_{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let url = format!("sqlite://{}?mode=rwc", path.display());
+
+    let db = Database::builder().max_connections(1).connect(&url).await?;
+    migrator(&db).run().await?;
+    assert_eq!(db.model::<Setting>().count().await?, 2);
+    db.close().await;
+
+    // A restart sees both versions recorded and applies neither again
+    let db = Database::builder().max_connections(1).connect(&url).await?;
+    migrator(&db).run().await?;
+    assert_eq!(db.model::<Setting>().count().await?, 2);
+    let (versions,): (i64,) = db.raw("SELECT COUNT(*) FROM _bottle_migrations").fetch_one().await?;
+    assert_eq!(versions, 2);
+
+    // Rollback runs the down scripts and forgets the versions
+    migrator(&db).rollback().await?;
+    assert!(!db.table_exists("setting_audit").await?);
+    assert!(!db.table_exists("setting").await?);
+    let (versions,): (i64,) = db.raw("SELECT COUNT(*) FROM _bottle_migrations").fetch_one().await?;
+    assert_eq!(versions, 0);
+
+    db.close().await;
+    let _ = std::fs::remove_file(&path);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_versions_run_in_order_apart_from_named_steps() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+
+    // Registered out of order, and a step named like a version
+    db.migrator()
+        .register_migration(2, "INSERT INTO setting_log (step) VALUES ('v2')", "DELETE FROM setting_log WHERE step = 'v2'")
+        .register_migration(1, "CREATE TABLE setting_log (step TEXT NOT NULL)", "DROP TABLE setting_log")
+        .add("1", |db| async move {
+            sqlx::query("INSERT INTO setting_log (step) VALUES ('named')").execute(&db.get_pool()).await?;
+            Ok(())
+        })
+        .run()
+        .await?;
+
+    let steps: Vec<(String,)> = db.raw("SELECT step FROM setting_log ORDER BY rowid").fetch_all().await?;
+    assert_eq!(steps, [("v2".to_string(),), ("named".to_string(),)]);
+    let names: Vec<(String,)> = db.raw("SELECT name FROM _bottle_migrations ORDER BY name").fetch_all().await?;
+    assert_eq!(names, [("1".to_string(),), ("version_1".to_string(),), ("version_2".to_string(),)]);
+
+    let err = db.migrator().register_migration(3, "", "").register_migration(3, "", "").run().await.unwrap_err();
+    assert!(err.to_string().contains("version 3"), "unexpected error: {}", err);
+    Ok(())
+}
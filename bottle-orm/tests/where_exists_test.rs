@@ -0,0 +1,67 @@
+use bottle_orm::{Model, Op, OrderDir};
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Writer {
+    #[orm(primary_key)]
+    id: i32,
+    name: String,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Story {
+    #[orm(primary_key)]
+    id: i32,
+    writer_id: i32,
+    likes: i32,
+}
+
+#[tokio::test]
+async fn test_where_exists_correlates_with_outer_table() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Writer>().register::<Story>().run().await?;
+
+    for (id, name) in [(1, "ann"), (2, "bob"), (3, "cid")] {
+        db.model::<Writer>().insert(&Writer { id, name: name.to_string() }).await?;
+    }
+    for (id, writer_id, likes) in [(1, 1, 3), (2, 1, 40), (3, 3, 7)] {
+        db.model::<Story>().insert(&Story { id, writer_id, likes }).await?;
+    }
+
+    let with_stories: Vec<Writer> = db
+        .model::<Writer>()
+        .where_exists(db.model::<Story>().where_column("writer_id", Op::Eq, "writer.id"))
+        .order("id ASC")
+        .scan()
+        .await?;
+    assert_eq!(with_stories.iter().map(|w| w.name.as_str()).collect::<Vec<_>>(), ["ann", "cid"]);
+
+    let without: Vec<Writer> = db
+        .model::<Writer>()
+        .where_not_exists(db.model::<Story>().where_column("writer_id", Op::Eq, "writer.id"))
+        .scan()
+        .await?;
+    assert_eq!(without.iter().map(|w| w.name.as_str()).collect::<Vec<_>>(), ["bob"]);
+
+    // Bound values on both sides, with the outer table aliased
+    let popular: Vec<Writer> = db
+        .model::<Writer>()
+        .alias("w")
+        .filter("name", Op::Ne, "zed")
+        .where_exists(db.model::<Story>().where_column("writer_id", Op::Eq, "w.id").filter("likes", Op::Gt, 10))
+        .filter("id", Op::Lt, 3)
+        .scan()
+        .await?;
+    assert_eq!(popular.iter().map(|w| w.name.as_str()).collect::<Vec<_>>(), ["ann"]);
+
+    // An invalid subquery fails the outer query instead of being dropped
+    let err = db
+        .model::<Writer>()
+        .where_exists(db.model::<Story>().order_by("missing", OrderDir::Asc))
+        .scan::<Writer>()
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("missing"), "unexpected error: {}", err);
+    Ok(())
+}
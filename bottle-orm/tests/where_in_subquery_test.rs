@@ -0,0 +1,65 @@
+use bottle_orm::{Database, Drivers, Model, Op, QueryBuilder};
+use heck::ToSnakeCase;
+
+mod common;
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Member {
+    #[orm(primary_key)]
+    id: i32,
+    karma: i32,
+}
+
+#[derive(Debug, Clone, Model, PartialEq)]
+struct Entry {
+    #[orm(primary_key)]
+    id: i32,
+    member_id: i32,
+    views: i32,
+}
+
+fn postgres_query<T: Model + bottle_orm::AnyImpl + Send + Sync + Unpin>(db: &Database) -> QueryBuilder<T, Database> {
+    let columns = T::active_columns().iter().map(|c| c.to_snake_case()).collect();
+    QueryBuilder::new(db.clone(), Drivers::Postgres, T::table_name(), <T as Model>::columns(), columns)
+}
+
+#[tokio::test]
+async fn test_where_in_subquery_orders_arguments() -> Result<(), Box<dyn std::error::Error>> {
+    let db = common::sqlite().await?;
+    db.migrator().register::<Member>().register::<Entry>().run().await?;
+
+    for (id, karma) in [(1, 5), (2, 50), (3, 80)] {
+        db.model::<Member>().insert(&Member { id, karma }).await?;
+    }
+    for (id, member_id, views) in [(1, 1, 10), (2, 2, 10), (3, 2, 900), (4, 3, 10), (5, 3, 20)] {
+        db.model::<Entry>().insert(&Entry { id, member_id, views }).await?;
+    }
+
+    // Placeholders continue from the parent into the subquery and back out
+    let sql = postgres_query::<Entry>(&db)
+        .filter("views", Op::Lt, 100)
+        .where_in_subquery("member_id", postgres_query::<Member>(&db).select("id").filter("karma", Op::Gt, 10))
+        .filter("id", Op::Ne, 4)
+        .to_sql();
+    assert!(sql.contains("\"views\" < $1"), "{}", sql);
+    assert!(sql.contains("IN (SELECT") && sql.contains("\"karma\" > $2"), "{}", sql);
+    assert!(sql.contains("\"id\" != $3"), "{}", sql);
+
+    let entries: Vec<Entry> = db
+        .model::<Entry>()
+        .filter("views", Op::Lt, 100)
+        .where_in_subquery("member_id", db.model::<Member>().select("id").filter("karma", Op::Gt, 10))
+        .filter("id", Op::Ne, 4)
+        .order("id ASC")
+        .scan()
+        .await?;
+    assert_eq!(entries.iter().map(|e| e.id).collect::<Vec<_>>(), [2, 5]);
+
+    let entries: Vec<Entry> = db
+        .model::<Entry>()
+        .where_not_in_subquery("member_id", db.model::<Member>().select("id").filter("karma", Op::Gt, 10))
+        .scan()
+        .await?;
+    assert_eq!(entries.iter().map(|e| e.id).collect::<Vec<_>>(), [1]);
+    Ok(())
+}